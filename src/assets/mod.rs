@@ -2,13 +2,11 @@ use std::rc::Rc;
 
 use tetra::Context;
 
+pub use crate::sprite::Sprite;
+
 pub use self::{
-    alert::Alert,
-    button::Button,
-    fonts::Fonts,
-    images::Images,
-    prepared_font::PreparedFont,
-    tileset::{Sprite, Tileset},
+    alert::Alert, button::Button, fonts::Fonts, images::Images, prepared_font::PreparedFont,
+    tileset::Tileset,
 };
 
 mod alert;
@@ -1,167 +1,10 @@
 use roguemetry::Vec2;
-use serde::{Deserialize, Serialize};
 use tetra::{
     graphics::{DrawParams, Rectangle, Texture},
     Context,
 };
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
-#[serde(rename_all = "snake_case")]
-pub enum Sprite {
-    #[default]
-    Empty,
-    Highlight,
-    #[serde(rename = "mt")]
-    MoreThan,
-    #[serde(rename = "lt")]
-    LessThan,
-    Minus,
-    Plus,
-    Cursor,
-    Fill,
-    Dirt1,
-    Dirt2,
-    Dirt3,
-    Dirt4,
-    Dirt5,
-    Dirt6,
-    Dirt7,
-    Dirt8,
-    Dirt9,
-    Dirt10,
-    Grass1,
-    Grass2,
-    Grass3,
-    Grass4,
-    Grass5,
-    Grass6,
-    Grass7,
-    Grass8,
-    Grass9,
-    Grass10,
-    Grass11,
-    Grass12,
-    Grass13,
-    Grass14,
-    Grass15,
-    Grass16,
-    Grass17,
-    Grass18,
-    Grass19,
-    Grass20,
-    Chest,
-    ChestOpen,
-    BoulderHuge,
-    Boulder,
-    BoulderSmall,
-    Pit,
-    TreeDead,
-    PineDead,
-    HickoryDead,
-    WillowDead,
-    BirchDead,
-    Tree,
-    Pine,
-    Hickory,
-    Willow,
-    Birch,
-    Gazan,
-    Nyarnik,
-    Totik,
-    Lagnam,
-    GiantBug,
-    Cloak,
-    Hat,
-    Rags,
-    Backpack,
-    ArmGuard,
-    GloveRight,
-    GloveLeft,
-    KnifeWood,
-    KnifeStone,
-    KnifeMetal,
-    KnifeDemonic,
-    AxeWood,
-    AxeStone,
-    AxeMetal,
-    AxeDemonic,
-    Buckler,
-    KnifeBone,
-    SapWood,
-    SapStone,
-    SapMetal,
-    SapDemonic,
-    SwordWood,
-    SwordStone,
-    SwordMetal,
-    SwordDemonic,
-    SwordObsidian,
-    KnifeObsidian,
-    ClubWood,
-    ClubStone,
-    ClubMetal,
-    ClubDemonic,
-    MaceWood,
-    MaceStone,
-    MaceMetal,
-    MaceDemonic,
-    KnuckleObsidian,
-    KnuckleBone,
-    KnuckleWood,
-    KnuckleStone,
-    KnuckleMetal,
-    KnuckleDemonic,
-    HammerWood,
-    HammerStone,
-    HammerMetal,
-    HammerDemonic,
-    SpearWood,
-    SpearStone,
-    SpearMetal,
-    SpearDemonic,
-    PikeWood,
-    PikeStone,
-    PikeMetal,
-    PikeDemonic,
-    BowWood,
-    BowDemonic,
-    CrossbowWood,
-    CrossbowMetal,
-    CrossbowDemonic,
-    Sling,
-    Pipe,
-    Pistol,
-    Rifle,
-    Slingshot,
-    ArrowWood,
-    ArrowStone,
-    ArrowMetal,
-    ArrowDemonic,
-    ArrowObsidian,
-    ArrowExplosive,
-    ArrowBone,
-    BoomgranateFruit,
-    Rock,
-    SharpRock,
-    Pebble,
-    MetalChunk,
-    SharpMetalChunk,
-    MetalShot,
-    DemonicChunk,
-    SharpDemonicChunk,
-    DemonicShot,
-    ObsidianChunk,
-    Corpse,
-    Book,
-    WoodenSplinter,
-    FleshChunk,
-    Bone,
-    Lazuli,
-    ShovelWood,
-    ShovelStone,
-    ShovelMetal,
-    ShovelDemonic,
-}
+use crate::sprite::Sprite;
 
 #[allow(clippy::too_many_lines)]
 const fn region(sprite: Sprite) -> Rectangle {
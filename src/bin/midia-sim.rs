@@ -0,0 +1,177 @@
+//! Headless simulation: loads a savefile, lets the world tick with scripted or AI-driven player
+//! actions and writes the result back. Useful for balance testing and CI.
+//!
+//! Usage: `midia-sim <savefile> [--turns N] [--ai dummy|basic_monster] [--script <file>]
+//! [--replay] [--out <savefile>]`
+//!
+//! Script is a file with one JSON-serialized `ActionType` per line (empty lines are skipped).
+//! Player uses script actions first and AI after the script is over.
+//!
+//! With `--replay` the world is created from scratch with the same meta as the savefile
+//! and the recorded replay is played instead, `--turns` limits how many player's turns to play.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use midia::game::{AIManager, Action, ActionType, Avatar, World, AI};
+use midia::savefile::{self, Replay};
+
+const DEFAULT_TURNS: usize = 100;
+const USAGE: &str = "Usage: midia-sim <savefile> [--turns N] [--ai dummy|basic_monster] \
+[--script <file>] [--replay] [--out <savefile>]";
+
+struct Args {
+    path: PathBuf,
+    turns: usize,
+    ai: AI,
+    script: Vec<ActionType>,
+    replay: bool,
+    out: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let mut path = None;
+    let mut turns = DEFAULT_TURNS;
+    let mut ai = AI::BasicMonster;
+    let mut script = Vec::new();
+    let mut replay = false;
+    let mut out = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--turns" => {
+                let value = args.next().ok_or("--turns needs a value")?;
+                turns = value
+                    .parse()
+                    .map_err(|e| format!("Invalid --turns {value}: {e}"))?;
+            }
+            "--ai" => {
+                let value = args.next().ok_or("--ai needs a value")?;
+                ai = serde_json::from_value(serde_json::Value::String(value.clone()))
+                    .map_err(|_| format!("Unknown AI: {value}"))?;
+            }
+            "--script" => {
+                let value = args.next().ok_or("--script needs a value")?;
+                let data = fs::read_to_string(&value)
+                    .map_err(|e| format!("Can't read script {value}: {e}"))?;
+                for (i, line) in data.lines().enumerate() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    script.push(
+                        serde_json::from_str(line)
+                            .map_err(|e| format!("{value}:{}: {e}", i + 1))?,
+                    );
+                }
+            }
+            "--replay" => replay = true,
+            "--out" => out = Some(PathBuf::from(args.next().ok_or("--out needs a value")?)),
+            _ if path.is_none() => path = Some(PathBuf::from(arg)),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    Ok(Args {
+        path: path.ok_or("Path to savefile is required")?,
+        turns,
+        ai,
+        script,
+        replay,
+        out,
+    })
+}
+
+fn next_player_action(world: &mut World, script: &mut impl Iterator<Item = ActionType>, ai: AI) {
+    if let Some(typ) = script.next() {
+        match Action::new(0, typ, world) {
+            Ok(action) => world.player_mut().set_action(Some(action)),
+            Err(msg) => eprintln!("Tick {}: {msg}", world.meta.current_tick),
+        }
+    } else {
+        let action = AIManager::instance().plan(ai, 0, world);
+        world.player_mut().set_action(action);
+    }
+}
+
+fn simulate(args: &Args) -> Result<World, String> {
+    let mut world = savefile::load_world(&args.path)
+        .map_err(|e| format!("Can't load {}: {e:?}", args.path.display()))?;
+
+    let mut script = args.script.iter().cloned();
+    for _ in 0..args.turns {
+        if world.player().char_sheet().is_dead() {
+            break;
+        }
+        if world.player().action().is_none() {
+            next_player_action(&mut world, &mut script, args.ai);
+        }
+        world.tick();
+    }
+
+    Ok(world)
+}
+
+fn play_replay(args: &Args) -> Result<World, String> {
+    let meta =
+        savefile::load(&args.path).ok_or_else(|| format!("Can't load {}", args.path.display()))?;
+    let mut replay = Replay::load(&meta).map_err(|e| {
+        format!(
+            "Can't load {}: {e:?}",
+            savefile::replay_path(&args.path).display()
+        )
+    })?;
+    for _ in 0..args.turns {
+        if replay.is_over() {
+            break;
+        }
+        replay.step()?;
+    }
+
+    Ok(replay.world)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = if args.replay {
+        play_replay(&args)
+    } else {
+        simulate(&args)
+    };
+    let mut world = match result {
+        Ok(world) => world,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(out) = args.out {
+        world.meta.path = out;
+    }
+    world.meta.update_before_save();
+    if let Err(e) = savefile::save(&mut world) {
+        eprintln!("Can't save {}: {e:?}", world.meta.path.display());
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "Tick {}, player at {:?}{}",
+        world.meta.current_tick,
+        world.player().pos,
+        if world.player().char_sheet().is_dead() {
+            ", dead"
+        } else {
+            ""
+        }
+    );
+
+    ExitCode::SUCCESS
+}
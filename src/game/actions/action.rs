@@ -1,11 +1,7 @@
 use crate::game::units::Avatar;
 
 use super::{
-    super::{
-        log::{LogCategory, LogEvent},
-        units::Units,
-        World,
-    },
+    super::{units::Units, GameEvent, World},
     ActionImpl, ActionPossibility, ActionType,
 };
 
@@ -18,6 +14,9 @@ pub struct Action {
 }
 
 impl Action {
+    /// # Errors
+    ///
+    /// Returns the reason if the action is not possible
     pub fn new(owner: usize, typ: ActionType, world: &World) -> Result<Self, String> {
         match typ.is_possible(owner, world) {
             ActionPossibility::Yes(length) => {
@@ -34,6 +33,7 @@ impl Action {
     }
 
     #[inline]
+    #[must_use]
     pub fn owner<'a>(&self, world: &'a World) -> &'a dyn Avatar {
         world.units.get_unit(self.owner)
     }
@@ -43,18 +43,32 @@ impl Action {
         world.units.get_unit_mut(self.owner)
     }
 
-    fn cancel_action(&self, world: &mut World, reason: String) {
+    /// Drops the action of the owner and tells why
+    pub fn cancel_action(&self, world: &mut World, reason: String) {
         self.owner_mut(world).set_action(None);
-        if self.owner == 0 {
-            world.log.push(LogEvent::new(
-                reason,
-                self.owner(world).pos(),
-                LogCategory::Warning,
-            ));
+        // the player is told about it by the game log subscribed to the events
+        world.events.emit(GameEvent::ActionCancelled {
+            unit_id: self.owner,
+            typ: self.typ.clone(),
+            reason,
+        });
+    }
+
+    /// Called once when the scheduler picks up the action
+    pub fn start(&self, world: &mut World) {
+        if let ActionPossibility::No(reason) = self.typ.is_possible(self.owner, world) {
+            self.cancel_action(world, reason);
+            return;
         }
+
+        self.typ.on_start(self, world);
     }
 
-    /// called every tick
+    /// Called when the world reaches `finish` tick.
+    ///
+    /// Actions are not checked between `start` and `finish` anymore, so an action that became
+    /// impossible in the middle is cancelled when it's finished, not on the next tick.
+    /// Walks are the exception: they are stopped as soon as somebody steps into the tile.
     pub fn act(&self, world: &mut World) {
         if let ActionPossibility::No(reason) = self.typ.is_possible(self.owner, world) {
             self.cancel_action(world, reason);
@@ -62,15 +76,6 @@ impl Action {
         }
         // TODO: draw stamina
 
-        if self.finish <= world.meta.current_tick {
-            self.typ.on_finish(self, world);
-        } else {
-            let steps = (self.finish - world.meta.current_tick) as u32;
-            if steps == self.length {
-                self.typ.on_start(self, world);
-            } else {
-                self.typ.on_step(self, world);
-            }
-        }
+        self.typ.on_finish(self, world);
     }
 }
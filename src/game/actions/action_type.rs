@@ -4,8 +4,8 @@ use crate::game::{Action, World};
 
 use super::{
     implements::{
-        Close, DropMainHand, Melee, Open, Read, Reload, Shoot, Skip, Throw, Walk, Wear,
-        WieldFromGround,
+        Butcher, ChangeLevel, Chop, Climb, Close, Craft, Dig, DropMainHand, Eat, Melee, Open, Read,
+        Reload, Shoot, Skip, Sneak, Throw, Walk, Wear, WieldFromGround,
     },
     ActionPossibility,
 };
@@ -14,12 +14,15 @@ use super::{
 pub trait ActionImpl {
     fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility;
     fn on_start(&self, _action: &Action, _world: &mut World) {}
-    fn on_step(&self, _action: &Action, _world: &mut World) {}
     fn on_finish(&self, _action: &Action, _world: &mut World) {}
+    /// Hard work makes hungry and thirsty faster, by this many extra ticks for every tick of it
+    fn exertion(&self) -> u32 {
+        0
+    }
 }
 
 #[enum_dispatch(ActionImpl)]
-#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub enum ActionType {
     Skip,
     Walk,
@@ -33,4 +36,12 @@ pub enum ActionType {
     Throw,
     Shoot,
     Reload,
+    Sneak,
+    ChangeLevel,
+    Climb,
+    Dig,
+    Chop,
+    Butcher,
+    Craft,
+    Eat,
 }
@@ -11,6 +11,7 @@ pub enum AttackTarget {
 }
 
 impl AttackTarget {
+    #[must_use]
     pub fn auto(pos: Point, world: &World) -> Self {
         let Some(tile) = world.map.get_tile_opt(pos) else {
             return Self::terrain(pos);
@@ -27,14 +28,17 @@ impl AttackTarget {
         }
     }
 
+    #[must_use]
     pub fn terrain(pos: Point) -> Self {
         Self::Terrain(pos)
     }
 
+    #[must_use]
     pub fn avatar(id: usize) -> Self {
         Self::Avatar(id)
     }
 
+    #[must_use]
     pub fn pos(self, world: &World) -> Point {
         match self {
             Self::Terrain(pos) => pos,
@@ -42,6 +46,7 @@ impl AttackTarget {
         }
     }
 
+    #[must_use]
     pub fn is_avatar(self) -> bool {
         matches!(self, Self::Avatar(_))
     }
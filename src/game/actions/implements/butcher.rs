@@ -0,0 +1,183 @@
+use roguemetry::Direction;
+
+use crate::game::{ActionType, GameData, GameEvent, ItemQuality, LootContext, Skill};
+
+use super::super::{
+    super::{log::LogEvent, traits::Name, Avatar, World},
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Ticks to butcher a corpse with a stone knife
+const BUTCHER_TIME: f32 = 600.0;
+const BUTCHERING_TABLE: &str = "butchering";
+
+/// Cutting a dead body on the ground into meat, bones and hide
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Butcher {
+    dir: Direction,
+}
+
+impl Butcher {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(dir: Direction) -> ActionType {
+        Self { dir }.into()
+    }
+}
+
+impl ActionImpl for Butcher {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        if actor.char_sheet().is_dead() {
+            return No("You are dead".to_string());
+        }
+        let Some(tool) = actor
+            .inventory()
+            .and_then(|inventory| inventory.tool(&ItemQuality::Butch))
+        else {
+            return No("You have nothing to butcher with".to_string());
+        };
+
+        let pos = actor.pos() + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return No("There is nothing to butcher there".to_string());
+        };
+        if !tile.items.iter().any(|item| item.corpse_of().is_some()) {
+            return No("There is nothing to butcher there".to_string());
+        }
+
+        Yes((BUTCHER_TIME / tool.tool_speed()).round() as u32)
+    }
+
+    fn exertion(&self) -> u32 {
+        1
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = world.units.get_unit(action.owner);
+        let pos = owner.pos() + self.dir;
+        let z = owner.z();
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return;
+        };
+        let Some(index) = tile
+            .items
+            .iter()
+            .rposition(|item| item.corpse_of().is_some())
+        else {
+            return;
+        };
+        let corpse = world.map.get_tile_mut(pos).items.remove(index);
+
+        let owner = world.units.get_unit(action.owner);
+        let roll = owner
+            .char_sheet()
+            .roll_skill(Skill::Survival, 0, &mut world.rng);
+        world.log.push(LogEvent::info(
+            format!(
+                "{} {}butcher{} the {}.",
+                owner.name_for_actions(),
+                if roll.successes() == 0 {
+                    "clumsily "
+                } else {
+                    ""
+                },
+                if owner.pronouns().verb_ends_with_s() {
+                    "s"
+                } else {
+                    ""
+                },
+                corpse.name()
+            ),
+            pos,
+        ));
+
+        // a failed roll still gets something, every success and raise adds more
+        let context = LootContext {
+            z,
+            race: corpse.corpse_of(),
+            ..LootContext::default()
+        };
+        let table = GameData::instance().get_loot_table(BUTCHERING_TABLE);
+        let mut items = Vec::new();
+        for _ in 0..=roll.successes() {
+            items.append(&mut table.roll(context, &mut world.rng));
+        }
+        for item in &items {
+            world.events.emit(GameEvent::ItemDropped {
+                unit_id: Some(action.owner),
+                item: Box::new(item.clone()),
+                pos,
+            });
+        }
+        world.map.get_tile_mut(pos).items.append(&mut items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::{Direction, Point};
+    use test_case::test_case;
+
+    use crate::game::map::items::helpers::{
+        dead_body, BONE, CHITIN, GOD_AXE, MEAT, STEEL_KNIFE, STONE_KNIFE,
+    };
+    use crate::game::world::tests::{add_monster, prepare_world_with_tool};
+    use crate::game::{Action, Avatar, Item, World};
+
+    use super::Butcher;
+
+    fn put_bug_corpse(world: &mut World) {
+        let bug = add_monster(world, Point::new(5, 5));
+        let corpse = dead_body(world.units.get_unit(bug).appearance());
+        let tile = world.map.get_tile_mut(Point::new(1, 0));
+        tile.items.clear();
+        tile.items.push(corpse);
+    }
+
+    fn assert_butchered_bug(world: &mut World) {
+        let items = &world.map.get_tile(Point::new(1, 0)).items;
+        assert!(items.iter().all(|item| item.corpse_of().is_none()));
+        assert!(items.iter().any(|item| item.proto().id == MEAT));
+        assert!(items.iter().any(|item| item.proto().id == CHITIN));
+        assert!(items.iter().all(|item| item.proto().id != BONE));
+    }
+
+    #[test]
+    fn test_butchering_a_bug() {
+        let mut world = prepare_world_with_tool(Some(STONE_KNIFE));
+        put_bug_corpse(&mut world);
+
+        let action = Action::new(0, Butcher::new(Direction::East), &world).unwrap();
+        action.act(&mut world);
+
+        assert_butchered_bug(&mut world);
+    }
+
+    #[test]
+    fn test_steel_knife_butchers_faster() {
+        let mut world = prepare_world_with_tool(Some(STONE_KNIFE));
+        put_bug_corpse(&mut world);
+        let stone = Action::new(0, Butcher::new(Direction::East), &world).unwrap();
+        assert_eq!(600, stone.length);
+
+        world.player_mut().inventory.clear();
+        world.player_mut().inventory.wield(Item::new(STEEL_KNIFE));
+        let steel = Action::new(0, Butcher::new(Direction::East), &world).unwrap();
+        assert_eq!(400, steel.length);
+    }
+
+    #[test_case(true, None; "with empty hands")]
+    #[test_case(true, Some(GOD_AXE); "with an axe")]
+    #[test_case(false, Some(STONE_KNIFE); "without a corpse")]
+    fn test_cant_butcher(corpse: bool, tool: Option<&str>) {
+        let mut world = prepare_world_with_tool(tool);
+        world.map.get_tile_mut(Point::new(1, 0)).items.clear();
+        if corpse {
+            put_bug_corpse(&mut world);
+        }
+
+        assert!(Action::new(0, Butcher::new(Direction::East), &world).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use super::super::{
+    super::{Avatar, LogEvent, TerrainInteract, TerrainInteractAction, World},
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+    ActionType,
+};
+
+/// Going down or up the stairs, or climbing down into a pit
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct ChangeLevel {
+    down: bool,
+}
+
+impl ChangeLevel {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(down: bool) -> ActionType {
+        Self { down }.into()
+    }
+}
+
+impl ActionImpl for ChangeLevel {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        if actor.char_sheet().shock {
+            return No("You are in shock".to_string());
+        }
+
+        let (interact, way) = if self.down {
+            (TerrainInteractAction::GoDown, "down")
+        } else {
+            (TerrainInteractAction::GoUp, "up")
+        };
+        let Some(tile) = world.map.get_tile_opt(actor.pos()) else {
+            return No(format!("There is no way {way} here"));
+        };
+        if !tile.terrain.supports_action(interact) {
+            return No(format!("There is no way {way} here"));
+        }
+
+        Yes(20)
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        world.change_level(action.owner, if self.down { -1 } else { 1 });
+        if action.owner == 0 {
+            let msg = if self.down {
+                "You go down."
+            } else {
+                "You go up."
+            };
+            world
+                .log
+                .push(LogEvent::info(msg, world.units.player().pos()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::Point;
+
+    use crate::game::map::terrains::{Pit, Stairs};
+    use crate::game::world::tests::{add_dummy, boulder, prepare_world};
+    use crate::game::{Action, Avatar, TerrainInteract, TerrainInteractAction};
+
+    use super::ChangeLevel;
+
+    #[test]
+    fn test_go_down_and_up() {
+        let mut world = prepare_world();
+        assert!(Action::new(0, ChangeLevel::new(true), &world).is_err());
+        world.map.get_tile_mut(Point::new(0, 0)).terrain = Stairs::down().into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = boulder();
+        let surface_unit = add_dummy(&mut world, Point::new(2, 0));
+
+        let action = Action::new(0, ChangeLevel::new(true), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert_eq!(-1, world.player().z());
+        assert_eq!(-1, world.map.level);
+        assert!(!world.units.is_loaded(surface_unit));
+        let tile = world.map.get_tile(Point::new(0, 0));
+        assert!(tile.terrain.supports_action(TerrainInteractAction::GoUp));
+        assert!(tile.units.contains(&0));
+
+        let action = Action::new(0, ChangeLevel::new(false), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert_eq!(0, world.player().z());
+        assert!(world.units.is_loaded(surface_unit));
+        assert!(!world.map.get_tile(Point::new(1, 0)).terrain.is_passable());
+    }
+
+    #[test]
+    fn test_fall_into_pit() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(0, 0)).terrain = Pit::new().into();
+
+        let action = Action::new(0, ChangeLevel::new(true), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert_eq!(-1, world.player().z());
+        let tile = world.map.get_tile(Point::new(0, 0));
+        assert!(tile.terrain.is_passable());
+        assert!(Action::new(0, ChangeLevel::new(false), &world).is_err());
+    }
+}
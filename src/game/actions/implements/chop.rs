@@ -0,0 +1,186 @@
+use roguemetry::Direction;
+
+use crate::game::{ActionType, GameEvent, ItemQuality, Terrain};
+
+use super::super::{
+    super::{
+        log::LogEvent,
+        map::{TerrainInteract, TerrainInteractAction, TerrainView},
+        traits::Name,
+        Avatar, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+use super::work::work_time;
+
+/// Ticks to fell a tree with a stone axe and d6 Strength, unless the terrain sets its own
+const CHOP_TIME: f32 = 900.0;
+
+/// Felling a tree for wood
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Chop {
+    dir: Direction,
+}
+
+impl Chop {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(dir: Direction) -> ActionType {
+        Self { dir }.into()
+    }
+}
+
+impl ActionImpl for Chop {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        if actor.char_sheet().is_dead() {
+            return No("You are dead".to_string());
+        }
+        let Some(tool) = actor
+            .inventory()
+            .and_then(|inventory| inventory.tool(&ItemQuality::Chop))
+        else {
+            return No("You have nothing to chop with".to_string());
+        };
+
+        let pos = actor.pos() + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return No("There is nothing to chop there".to_string());
+        };
+        if !tile.terrain.supports_action(TerrainInteractAction::Chop) {
+            return No(format!("You can't chop the {}", tile.terrain.name()));
+        }
+        if !tile.units.is_empty() {
+            return No("Someone is up there".to_string());
+        }
+
+        let base_time = match &tile.terrain {
+            Terrain::DataTerrain(terrain) => terrain
+                .proto()
+                .chop
+                .as_ref()
+                .and_then(|chop| chop.time)
+                .map_or(CHOP_TIME, |ticks| ticks as f32),
+            _ => CHOP_TIME,
+        };
+        Yes(work_time(base_time, actor, tool))
+    }
+
+    fn exertion(&self) -> u32 {
+        2
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = world.units.get_unit(action.owner);
+        let pos = owner.pos() + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return;
+        };
+        world.log.push(LogEvent::info(
+            format!(
+                "{} fell{} the {}.",
+                owner.name_for_actions(),
+                if owner.pronouns().verb_ends_with_s() {
+                    "s"
+                } else {
+                    ""
+                },
+                tile.terrain.name()
+            ),
+            pos,
+        ));
+
+        let (new_terrain, mut items) = tile.terrain.chop(&mut world.rng);
+        world.events.emit(GameEvent::TerrainChanged {
+            pos,
+            terrain: new_terrain.clone(),
+        });
+        for item in &items {
+            world.events.emit(GameEvent::ItemDropped {
+                unit_id: Some(action.owner),
+                item: Box::new(item.clone()),
+                pos,
+            });
+        }
+        let tile = world.map.get_tile_mut(pos);
+        tile.terrain = new_terrain;
+        tile.items.append(&mut items);
+
+        // there is a view now
+        world.calc_fov();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use roguemetry::{Direction, Point};
+    use test_case::test_case;
+
+    use crate::game::map::items::helpers::{BRANCH, GOD_AXE, LOG, STONE_SHOVEL, WOODEN_SPLINTER};
+    use crate::game::map::terrains::DataTerrain;
+    use crate::game::world::tests::{dirt, prepare_world_with_tool};
+    use crate::game::{Action, AttrLevel, Avatar, Item, Material, Terrain, TerrainView};
+
+    use super::Chop;
+
+    #[test_case("pine", &[LOG, BRANCH]; "live tree")]
+    #[test_case("dead_pine", &[BRANCH, WOODEN_SPLINTER]; "dead tree")]
+    fn test_chopping_the_tree(tree: &str, yields: &[&str]) {
+        let mut world = prepare_world_with_tool(Some(GOD_AXE));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant(tree, 0).into();
+        world.map.get_tile_mut(Point::new(1, 0)).items.clear();
+
+        let action = Action::new(0, Chop::new(Direction::East), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+
+        let tile = world.map.get_tile(Point::new(1, 0));
+        assert_eq!("dirt", tile.terrain.name());
+        for id in yields {
+            assert!(tile.items.iter().any(|item| item.proto().id == *id));
+        }
+    }
+
+    #[test]
+    fn test_dead_trees_are_faster() {
+        let mut world = prepare_world_with_tool(Some(GOD_AXE));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("birch", 0).into();
+        world.map.get_tile_mut(Point::new(-1, 0)).terrain =
+            DataTerrain::with_variant("dead_birch", 0).into();
+
+        let live = Action::new(0, Chop::new(Direction::East), &world).unwrap();
+        let dead = Action::new(0, Chop::new(Direction::West), &world).unwrap();
+        assert!(dead.length < live.length);
+    }
+
+    #[test]
+    fn test_sturdier_axe_chops_faster() {
+        let mut world = prepare_world_with_tool(Some(GOD_AXE));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("pine", 0).into();
+        world.player_mut().char_sheet_mut().attributes.strength = AttrLevel::D6;
+        let stone = Action::new(0, Chop::new(Direction::East), &world).unwrap();
+        assert_eq!(900, stone.length);
+
+        let mut proto = Item::new(GOD_AXE).proto().clone();
+        proto.materials = HashSet::from([Material::Wood, Material::Demonite]);
+        world.player_mut().inventory.clear();
+        world.player_mut().inventory.wield(Item::custom(proto));
+        let demonic = Action::new(0, Chop::new(Direction::East), &world).unwrap();
+        assert_eq!(450, demonic.length);
+    }
+
+    #[test_case(DataTerrain::with_variant("pine", 0).into(), None; "with empty hands")]
+    #[test_case(DataTerrain::with_variant("pine", 0).into(), Some(STONE_SHOVEL); "with a shovel")]
+    #[test_case(dirt(), Some(GOD_AXE); "the dirt")]
+    fn test_cant_chop(terrain: Terrain, tool: Option<&str>) {
+        let mut world = prepare_world_with_tool(tool);
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = terrain;
+        assert!(Action::new(0, Chop::new(Direction::East), &world).is_err());
+    }
+}
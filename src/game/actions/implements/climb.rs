@@ -0,0 +1,192 @@
+use roguemetry::Direction;
+
+use crate::game::ActionType;
+
+use super::super::{
+    super::{
+        log::LogEvent,
+        map::{TerrainInteract, TerrainInteractAction, TerrainView},
+        savage::{climbing_roll, fall_damage, HitResult},
+        traits::Name,
+        Avatar, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Ticks to climb, for the normal walking speed
+const CLIMB_TIME: f32 = 50.0;
+
+/// Climbing on top of a boulder or up a tree
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Climb {
+    dir: Direction,
+}
+
+impl Climb {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(dir: Direction) -> ActionType {
+        Self { dir }.into()
+    }
+}
+
+impl ActionImpl for Climb {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        if actor.char_sheet().is_dead() {
+            return No("You are dead".to_string());
+        }
+
+        let pos = actor.pos() + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return No("There is nothing to climb there".to_string());
+        };
+        if !tile.terrain.supports_action(TerrainInteractAction::Climb) {
+            return No(format!("You can't climb the {}", tile.terrain.name()));
+        }
+        if !tile.units.is_empty() {
+            return No("Someone is already there".to_string());
+        }
+        let k_character = actor.char_sheet().walk_koeff();
+        if k_character == 0.0 {
+            return No("You can't climb".to_string());
+        }
+
+        Yes((CLIMB_TIME * k_character).round() as u32)
+    }
+
+    fn exertion(&self) -> u32 {
+        1
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = world.units.get_unit(action.owner);
+        let from = owner.pos();
+        let pos = from + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return;
+        };
+        let terrain_name = tile.terrain.name().to_string();
+        let penalty = tile.terrain.climbing_penalty().unwrap_or_default();
+        let name = owner.name_for_actions().to_string();
+        let s = if owner.pronouns().verb_ends_with_s() {
+            "s"
+        } else {
+            ""
+        };
+
+        if climbing_roll(owner.char_sheet(), penalty, &mut world.rng) {
+            world.move_avatar(action.owner, self.dir);
+            world
+                .units
+                .get_unit_mut(action.owner)
+                .char_sheet_mut()
+                .elevated = true;
+            if action.owner == 0 {
+                world.calc_fov();
+            }
+            world.log.push(LogEvent::info(
+                format!("{name} climb{s} the {terrain_name}."),
+                pos,
+            ));
+        } else {
+            world.log.push(LogEvent::warning(
+                format!("{name} fall{s} off the {terrain_name}!"),
+                from,
+            ));
+            let damage = fall_damage(&mut world.rng);
+            let hit = HitResult::calculate(
+                damage,
+                0,
+                world.units.get_unit(action.owner).as_fighter(),
+                false,
+                &mut world.rng,
+            );
+            world.apply_damage(action.owner, hit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::{Direction, Point};
+
+    use crate::game::map::terrains::DataTerrain;
+    use crate::game::world::tests::{add_dummy, boulder, dirt, prepare_world};
+    use crate::game::{Action, Avatar, Skill, SkillLevel};
+
+    use super::super::Walk;
+    use super::Climb;
+
+    #[test]
+    fn test_climbing_up_the_tree() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("pine", 0).into();
+        world.map.get_tile_mut(Point::new(2, 0)).terrain = dirt();
+        world
+            .player_mut()
+            .char_sheet_mut()
+            .skills
+            .set_skill(Skill::Climbing, SkillLevel::D12);
+        let sight_range = world.player().char_sheet().sight_range();
+
+        for _ in 0..10 {
+            if world.player().char_sheet().elevated {
+                break;
+            }
+            let action = Action::new(0, Climb::new(Direction::East), &world).unwrap();
+            world.player_mut().set_action(Some(action));
+            world.tick();
+        }
+        assert_eq!(Point::new(1, 0), world.player().pos());
+        assert!(world.player().char_sheet().elevated);
+        assert!(world.player().char_sheet().sight_range() > sight_range);
+
+        // climbing down is just walking away
+        let action = Action::new(0, Walk::new(Direction::East), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert_eq!(Point::new(2, 0), world.player().pos());
+        assert!(!world.player().char_sheet().elevated);
+    }
+
+    #[test]
+    fn test_falling_off_the_boulder() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = boulder();
+        world.map.get_tile_mut(Point::new(0, 1)).terrain = dirt();
+        let unit_id = add_dummy(&mut world, Point::new(0, 1));
+        // unskilled one is rolling d4-4 for the huge boulder, so falls almost every time
+        world
+            .units
+            .get_unit_mut(unit_id)
+            .char_sheet_mut()
+            .skills
+            .set_skill(Skill::Climbing, SkillLevel::None);
+
+        let mut fell = false;
+        for _ in 0..10 {
+            let action = Action::new(unit_id, Climb::new(Direction::NorthEast), &world).unwrap();
+            action.act(&mut world);
+            let events = world.log.new_events();
+            if events.iter().any(|e| e.msg.contains("falls off")) {
+                fell = true;
+                break;
+            }
+            // lucky one, trying again from the ground
+            world.move_avatar(unit_id, Direction::SouthWest);
+        }
+        assert!(fell);
+        assert_eq!(Point::new(0, 1), world.units.get_unit(unit_id).pos());
+        assert!(!world.units.get_unit(unit_id).char_sheet().elevated);
+    }
+
+    #[test]
+    fn test_cant_climb_dirt() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+        assert!(Action::new(0, Climb::new(Direction::East), &world).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use roguemetry::Direction;
 use crate::game::log::{LogCategory, LogEvent};
 use crate::game::map::{TerrainInteract, TerrainInteractAction, TerrainView};
 use crate::game::traits::Name;
-use crate::game::{Action, Avatar, World};
+use crate::game::{Action, Avatar, GameEvent, World};
 
 use super::super::{
     ActionImpl,
@@ -69,7 +69,11 @@ impl ActionImpl for Close {
         ));
         let tile = world.map.get_tile_mut(pos);
         let new_terrain = tile.terrain.close(tile.items.drain(..).collect());
-        tile.terrain = new_terrain;
+        tile.terrain = new_terrain.clone();
+        world.events.emit(GameEvent::TerrainChanged {
+            pos,
+            terrain: new_terrain,
+        });
 
         world.calc_fov();
     }
@@ -80,16 +84,17 @@ mod tests {
     use roguemetry::{Direction, Point};
 
     use crate::game::map::items::helpers::WOODEN_SPLINTER;
-    use crate::game::map::terrains::Chest;
+    use crate::game::map::terrains::DataTerrain;
     use crate::game::world::tests::prepare_world;
-    use crate::game::{Action, Avatar, Item, Terrain, TerrainInteract, TerrainInteractAction};
+    use crate::game::{Action, Avatar, Item, TerrainInteract, TerrainInteractAction};
 
     use super::Close;
 
     #[test]
     fn test_closing() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Chest::new(Vec::new(), true).into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("open_chest", 0).into();
         world
             .map
             .get_tile_mut(Point::new(1, 0))
@@ -117,19 +122,16 @@ mod tests {
         let tile = world.map.get_tile(Point::new(1, 0));
         assert!(tile.terrain.supports_action(TerrainInteractAction::Open));
         assert!(tile.items.is_empty());
-        if let Terrain::Chest(Chest { items_inside, open }) = &tile.terrain {
-            assert!(!open);
-            assert_eq!(items_inside.len(), 1);
-            assert_eq!(items_inside[0].proto().id, WOODEN_SPLINTER);
-        } else {
-            panic!("Not a chest");
-        };
+        let (_, items_inside) = tile.terrain.open();
+        assert_eq!(items_inside.len(), 1);
+        assert_eq!(items_inside[0].proto().id, WOODEN_SPLINTER);
     }
 
     #[test]
     fn test_cant_close_closed_chest() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Chest::new(Vec::new(), false).into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("chest", 0).into();
         let action = Action::new(
             0,
             Close {
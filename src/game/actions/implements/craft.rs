@@ -0,0 +1,368 @@
+use roguemetry::{Point, DIR9};
+
+use crate::game::{ActionType, GameData, GameEvent, Item, ItemQuality, Recipe};
+
+use super::super::{
+    super::{log::LogEvent, Avatar, World},
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Where an ingredient is taken from
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Source {
+    /// Tile nearby and index of the item lying there
+    Ground(Point, usize),
+    /// Index of the worn container and index of the item inside it
+    Worn(usize, usize),
+    /// Index of the wielded item
+    Hands(usize),
+}
+
+impl Source {
+    fn index(self) -> usize {
+        match self {
+            Self::Ground(_, index) | Self::Worn(_, index) | Self::Hands(index) => index,
+        }
+    }
+}
+
+/// Making new items from the ones lying nearby, carried in a backpack or wielded
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Craft {
+    recipe: String,
+}
+
+impl Craft {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(recipe: impl Into<String>) -> ActionType {
+        Self {
+            recipe: recipe.into(),
+        }
+        .into()
+    }
+
+    fn recipe(&self) -> Option<&'static Recipe> {
+        GameData::instance().get_recipe(&self.recipe)
+    }
+}
+
+/// Wielded tools are never spent, even the ones the recipe doesn't need
+fn is_tool(item: &Item) -> bool {
+    item.qualities()
+        .iter()
+        .any(|quality| !matches!(quality, ItemQuality::Container { .. }))
+}
+
+/// How many items to take from every source, the ones lying around are taken first
+fn gather(recipe: &Recipe, actor_id: usize, world: &World) -> Result<Vec<(Source, u8)>, String> {
+    let actor = world.units.get_unit(actor_id);
+    let mut candidates: Vec<(Source, &Item, u8)> = Vec::new();
+    for dir in DIR9 {
+        let pos = actor.pos() + dir;
+        if let Some(tile) = world.map.get_tile_opt(pos) {
+            for (i, item) in tile.items.iter().enumerate() {
+                candidates.push((Source::Ground(pos, i), item, item.stack_size()));
+            }
+        }
+    }
+    if let Some(inventory) = actor.inventory() {
+        for (i, worn) in inventory.iter_wear().enumerate() {
+            let Some(container) = worn.container() else {
+                continue;
+            };
+            for (j, item) in container.items.iter().enumerate() {
+                candidates.push((Source::Worn(i, j), item, item.stack_size()));
+            }
+        }
+        for (i, item) in inventory.wielded().enumerate() {
+            if !is_tool(item) {
+                candidates.push((Source::Hands(i), item, item.stack_size()));
+            }
+        }
+    }
+
+    let mut plan: Vec<(Source, u8)> = Vec::new();
+    for ingredient in &recipe.ingredients {
+        let mut need = ingredient.count;
+        for (source, item, left) in &mut candidates {
+            if need == 0 {
+                break;
+            }
+            if *left == 0 || !ingredient.matches(item) {
+                continue;
+            }
+            let take = need.min(*left);
+            *left -= take;
+            need -= take;
+            if let Some((_, count)) = plan.iter_mut().find(|(s, _)| s == source) {
+                *count += take;
+            } else {
+                plan.push((*source, take));
+            }
+        }
+        if need > 0 {
+            return Err(format!("You don't have {}", ingredient.name()));
+        }
+    }
+
+    Ok(plan)
+}
+
+fn spend(item: &mut Item, count: u8) -> bool {
+    if count >= item.stack_size() {
+        true
+    } else {
+        item.decrease_stack(count);
+        false
+    }
+}
+
+fn consume(mut plan: Vec<(Source, u8)>, actor_id: usize, world: &mut World) {
+    // removing from the end, so indices of the other items stay the same
+    plan.sort_by_key(|(source, _)| std::cmp::Reverse(source.index()));
+    for (source, count) in plan {
+        match source {
+            Source::Hands(index) => {
+                let Some(inventory) = world.units.get_unit_mut(actor_id).inventory_mut() else {
+                    continue;
+                };
+                if inventory
+                    .wielded_mut(index)
+                    .is_some_and(|item| spend(item, count))
+                {
+                    inventory.take_wielded(index);
+                }
+            }
+            Source::Ground(pos, index) => {
+                let items = &mut world.map.get_tile_mut(pos).items;
+                if items.get_mut(index).is_some_and(|item| spend(item, count)) {
+                    items.remove(index);
+                }
+            }
+            Source::Worn(worn, index) => {
+                let Some(items) = world
+                    .units
+                    .get_unit_mut(actor_id)
+                    .inventory_mut()
+                    .and_then(|inventory| inventory.worn_mut(worn))
+                    .and_then(Item::container_mut)
+                    .map(|container| &mut container.items)
+                else {
+                    continue;
+                };
+                if items.get_mut(index).is_some_and(|item| spend(item, count)) {
+                    items.remove(index);
+                }
+            }
+        }
+    }
+}
+
+impl ActionImpl for Craft {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        if actor.char_sheet().is_dead() {
+            return No("You are dead".to_string());
+        }
+        let Some(recipe) = self.recipe() else {
+            return No("You don't know how to make it".to_string());
+        };
+        for quality in &recipe.tools {
+            if actor
+                .inventory()
+                .and_then(|inventory| inventory.tool(quality))
+                .is_none()
+            {
+                return No(format!("You need a tool to {} with", quality.name()));
+            }
+        }
+        if let Err(msg) = gather(recipe, actor_id, world) {
+            return No(msg);
+        }
+
+        Yes(recipe.time)
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let Some(recipe) = self.recipe() else {
+            return;
+        };
+        let Ok(plan) = gather(recipe, action.owner, world) else {
+            return;
+        };
+        let owner = world.units.get_unit(action.owner);
+        let pos = owner.pos();
+        let name = owner.name_for_actions().to_string();
+        let s = if owner.pronouns().verb_ends_with_s() {
+            "s"
+        } else {
+            ""
+        };
+
+        if let Some(check) = &recipe.skill {
+            let roll = owner
+                .char_sheet()
+                .roll_skill(check.skill, check.modifier, &mut world.rng);
+            if roll.successes() == 0 {
+                // critical failure wastes the ingredients
+                if roll.natural == 1 {
+                    consume(plan, action.owner, world);
+                    world.log.push(LogEvent::warning(
+                        format!("{name} ruin{s} the materials for {}.", recipe.name()),
+                        pos,
+                    ));
+                } else {
+                    world.log.push(LogEvent::warning(
+                        format!("{name} fail{s} to make {}.", recipe.name()),
+                        pos,
+                    ));
+                }
+                return;
+            }
+        }
+
+        consume(plan, action.owner, world);
+        world.log.push(LogEvent::info(
+            format!("{name} make{s} {}.", recipe.name()),
+            pos,
+        ));
+        let mut items = recipe.make_outputs();
+        for item in &items {
+            world.events.emit(GameEvent::ItemDropped {
+                unit_id: Some(action.owner),
+                item: Box::new(item.clone()),
+                pos,
+            });
+        }
+        world.map.get_tile_mut(pos).items.append(&mut items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use roguemetry::{Point, DIR9};
+
+    use crate::game::map::items::helpers::{
+        BACKPACK, ROCK, STONE_KNIFE, STONE_SHOVEL, WOODEN_SPLINTER,
+    };
+    use crate::game::world::tests::prepare_world_with_tool;
+    use crate::game::{Action, Avatar, Item, Material, World};
+
+    use super::Craft;
+
+    fn prepare_crafter(tool: Option<&str>) -> World {
+        let mut world = prepare_world_with_tool(tool);
+        for dir in DIR9 {
+            world.map.get_tile_mut(Point::default() + dir).items.clear();
+        }
+
+        world
+    }
+
+    fn count(items: &[Item], id: &str) -> u8 {
+        items
+            .iter()
+            .filter(|item| item.proto().id == id)
+            .map(Item::stack_size)
+            .sum()
+    }
+
+    #[test]
+    fn test_crafting_from_ground_first() {
+        let mut world = prepare_crafter(Some(STONE_KNIFE));
+        world
+            .player_mut()
+            .inventory
+            .wield(Item::new(WOODEN_SPLINTER).with_stack(2));
+        world
+            .map
+            .get_tile_mut(Point::new(1, 0))
+            .items
+            .push(Item::new(WOODEN_SPLINTER).with_stack(2));
+
+        let action = Action::new(0, Craft::new("arrow_wood"), &world).unwrap();
+        action.act(&mut world);
+
+        let crafted = &world.map.get_tile(Point::new(0, 0)).items;
+        assert_eq!(count(crafted, "arrow_wood"), 3);
+        assert!(world.map.get_tile(Point::new(1, 0)).items.is_empty());
+        // the knife is a tool, not an ingredient
+        let wielded: Vec<Item> = world.player().inventory.wielded().cloned().collect();
+        assert_eq!(count(&wielded, STONE_KNIFE), 1);
+        assert_eq!(count(&wielded, WOODEN_SPLINTER), 1);
+    }
+
+    #[test]
+    fn test_crafting_from_backpack() {
+        let mut world = prepare_crafter(Some(STONE_KNIFE));
+        let mut backpack = Item::new(BACKPACK);
+        backpack
+            .container_mut()
+            .unwrap()
+            .push_item(Item::new(WOODEN_SPLINTER).with_stack(4));
+        world.player_mut().inventory.wear(backpack, 0);
+
+        let action = Action::new(0, Craft::new("arrow_wood"), &world).unwrap();
+        action.act(&mut world);
+
+        let crafted = &world.map.get_tile(Point::new(0, 0)).items;
+        assert_eq!(count(crafted, "arrow_wood"), 3);
+        let backpack = world.player().inventory.iter_wear().next().unwrap();
+        assert_eq!(
+            count(&backpack.container().unwrap().items, WOODEN_SPLINTER),
+            1
+        );
+    }
+
+    #[test]
+    fn test_wielded_tool_is_not_an_ingredient() {
+        let mut world = prepare_crafter(Some(STONE_KNIFE));
+        let mut proto = Item::new(STONE_SHOVEL).proto().clone();
+        proto.materials = HashSet::from([Material::Wood, Material::Steel]);
+        proto.two_handed = false;
+        world.player_mut().inventory.wield(Item::custom(proto));
+        world
+            .map
+            .get_tile_mut(Point::new(1, 0))
+            .items
+            .push(Item::new(WOODEN_SPLINTER).with_stack(6));
+
+        // the steel shovel is not needed to cut, but it's still a tool
+        let result = Action::new(0, Craft::new("arrow_steel"), &world);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cant_craft_without_ingredients() {
+        let mut world = prepare_crafter(Some(STONE_KNIFE));
+        world
+            .map
+            .get_tile_mut(Point::new(1, 0))
+            .items
+            .push(Item::new(WOODEN_SPLINTER));
+
+        let result = Action::new(0, Craft::new("arrow_stone"), &world);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cant_craft_without_tools() {
+        let mut world = prepare_crafter(None);
+        let tile = world.map.get_tile_mut(Point::new(1, 0));
+        tile.items.push(Item::new(ROCK));
+        tile.items.push(Item::new(WOODEN_SPLINTER).with_stack(3));
+
+        let result = Action::new(0, Craft::new("arrow_stone"), &world);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_unknown_recipe() {
+        let world = prepare_crafter(Some(STONE_KNIFE));
+        assert!(Action::new(0, Craft::new("removed_recipe"), &world).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use roguemetry::Direction;
+
+use crate::game::{ActionType, GameEvent, ItemQuality, Terrain};
+
+use super::super::{
+    super::{
+        log::LogEvent,
+        map::{TerrainInteract, TerrainInteractAction, TerrainView},
+        traits::Name,
+        Avatar, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+use super::work::work_time;
+
+/// Ticks to dig a pit with a stone shovel and d6 Strength, unless the terrain sets its own
+const DIG_TIME: f32 = 600.0;
+/// Ticks to fill the pit back in, it's easier
+const FILL_TIME: f32 = 300.0;
+
+/// Digging a pit in the ground or filling it back in
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Dig {
+    dir: Direction,
+}
+
+impl Dig {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(dir: Direction) -> ActionType {
+        Self { dir }.into()
+    }
+}
+
+impl ActionImpl for Dig {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        if actor.char_sheet().is_dead() {
+            return No("You are dead".to_string());
+        }
+        let Some(tool) = actor
+            .inventory()
+            .and_then(|inventory| inventory.tool(&ItemQuality::Dig))
+        else {
+            return No("You have nothing to dig with".to_string());
+        };
+
+        let pos = actor.pos() + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return No("You can't dig there".to_string());
+        };
+        if !tile.terrain.supports_action(TerrainInteractAction::Dig) {
+            return No(format!("You can't dig the {}", tile.terrain.name()));
+        }
+        if !tile.units.is_empty() {
+            return No("Someone is in the way".to_string());
+        }
+
+        let base_time = match &tile.terrain {
+            Terrain::Pit(..) => FILL_TIME,
+            Terrain::DataTerrain(terrain) => terrain
+                .proto()
+                .dig
+                .as_ref()
+                .and_then(|dig| dig.time)
+                .map_or(DIG_TIME, |ticks| ticks as f32),
+            Terrain::Stairs(..) => DIG_TIME,
+        };
+        Yes(work_time(base_time, actor, tool))
+    }
+
+    fn exertion(&self) -> u32 {
+        2
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = world.units.get_unit(action.owner);
+        let pos = owner.pos() + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return;
+        };
+        let s = if owner.pronouns().verb_ends_with_s() {
+            "s"
+        } else {
+            ""
+        };
+        let msg = if matches!(tile.terrain, Terrain::Pit(..)) {
+            format!("{} fill{s} the pit.", owner.name_for_actions())
+        } else {
+            format!(
+                "{} dig{s} a pit in the {}.",
+                owner.name_for_actions(),
+                tile.terrain.name()
+            )
+        };
+        world.log.push(LogEvent::info(msg, pos));
+
+        let (new_terrain, mut items) = tile.terrain.dig(&mut world.rng);
+        world.events.emit(GameEvent::TerrainChanged {
+            pos,
+            terrain: new_terrain.clone(),
+        });
+        for item in &items {
+            world.events.emit(GameEvent::ItemDropped {
+                unit_id: Some(action.owner),
+                item: Box::new(item.clone()),
+                pos,
+            });
+        }
+        let tile = world.map.get_tile_mut(pos);
+        tile.terrain = new_terrain;
+        tile.items.append(&mut items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use roguemetry::{Direction, Point};
+    use test_case::test_case;
+
+    use crate::game::map::items::helpers::{GOD_AXE, ROCK, STONE_SHOVEL};
+    use crate::game::world::tests::{boulder, dirt, prepare_world_with_tool};
+    use crate::game::{Action, AttrLevel, Avatar, Item, Material, Terrain, TerrainView};
+
+    use super::Dig;
+
+    #[test]
+    fn test_dig_and_fill_the_pit() {
+        let mut world = prepare_world_with_tool(Some(STONE_SHOVEL));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+
+        let action = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert!(matches!(
+            world.map.get_tile(Point::new(1, 0)).terrain,
+            Terrain::Pit(..)
+        ));
+
+        let fill = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        world.player_mut().set_action(Some(fill.clone()));
+        world.tick();
+        assert_eq!("dirt", world.map.get_tile(Point::new(1, 0)).terrain.name());
+        let dig = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        assert!(fill.length < dig.length);
+    }
+
+    #[test]
+    fn test_digging_yields_rocks() {
+        let mut world = prepare_world_with_tool(Some(STONE_SHOVEL));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+        world.map.get_tile_mut(Point::new(1, 0)).items.clear();
+
+        // 70% chance for rocks every pit, dig and fill until they show up
+        for _ in 0..10 {
+            let action = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+            world.player_mut().set_action(Some(action));
+            world.tick();
+        }
+
+        let items = &world.map.get_tile(Point::new(1, 0)).items;
+        assert!(items.iter().any(|item| item.proto().id == ROCK));
+    }
+
+    #[test]
+    fn test_stronger_digs_faster() {
+        let mut world = prepare_world_with_tool(Some(STONE_SHOVEL));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+        world.player_mut().char_sheet_mut().attributes.strength = AttrLevel::D4;
+        let weak = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        world.player_mut().char_sheet_mut().attributes.strength = AttrLevel::D12;
+        let strong = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        assert!(strong.length < weak.length);
+    }
+
+    #[test]
+    fn test_steel_shovel_digs_faster() {
+        let mut world = prepare_world_with_tool(Some(STONE_SHOVEL));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+        world.player_mut().char_sheet_mut().attributes.strength = AttrLevel::D6;
+        let stone = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        assert_eq!(600, stone.length);
+
+        let mut proto = Item::new(STONE_SHOVEL).proto().clone();
+        proto.materials = HashSet::from([Material::Wood, Material::Steel]);
+        world.player_mut().inventory.clear();
+        world.player_mut().inventory.wield(Item::custom(proto));
+        let steel = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        assert_eq!(400, steel.length);
+    }
+
+    #[test_case(dirt(), None; "with empty hands")]
+    #[test_case(dirt(), Some(GOD_AXE); "with an axe")]
+    #[test_case(boulder(), Some(STONE_SHOVEL); "the boulder")]
+    fn test_cant_dig(terrain: Terrain, tool: Option<&str>) {
+        let mut world = prepare_world_with_tool(tool);
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = terrain;
+        assert!(Action::new(0, Dig::new(Direction::East), &world).is_err());
+    }
+}
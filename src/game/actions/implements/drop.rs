@@ -5,7 +5,7 @@ use super::super::{
     super::{
         log::{LogCategory, LogEvent},
         map::{TerrainInteract, TerrainInteractAction, TerrainView},
-        Avatar, World,
+        Avatar, GameEvent, World,
     },
     Action, ActionImpl,
     ActionPossibility::{self, No, Yes},
@@ -52,6 +52,11 @@ impl ActionImpl for DropMainHand {
             .unwrap();
         let pos = action.owner(world).pos() + self.dir;
         let name = item.name();
+        world.events.emit(GameEvent::ItemDropped {
+            unit_id: Some(action.owner),
+            item: Box::new(item.clone()),
+            pos,
+        });
         world.map.get_tile_mut(pos).items.push(item);
         world.log.push(LogEvent::new(
             format!(
@@ -69,8 +74,7 @@ mod tests {
     use roguemetry::{Direction, Point};
 
     use crate::game::map::items::helpers::GOD_AXE;
-    use crate::game::map::terrains::Dirt;
-    use crate::game::world::tests::prepare_world;
+    use crate::game::world::tests::{dirt, prepare_world};
     use crate::game::{Action, Avatar, Item};
 
     use super::DropMainHand;
@@ -78,7 +82,7 @@ mod tests {
     #[test]
     fn test_dropping() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(0, 0)).terrain = Dirt::default().into();
+        world.map.get_tile_mut(Point::new(0, 0)).terrain = dirt();
         world.map.get_tile_mut(Point::new(0, 0)).items.clear();
         let inventory = world.player_inventory_mut();
         inventory.clear();
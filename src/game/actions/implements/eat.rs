@@ -0,0 +1,194 @@
+use roguemetry::Direction;
+
+use crate::game::{
+    savage::{HYDRATION_MAX, NUTRITION_MAX},
+    ActionType, Tile,
+};
+
+use super::super::{
+    super::{
+        log::LogEvent,
+        map::{TerrainInteract, TerrainInteractAction, TerrainView},
+        traits::Name,
+        Avatar, World,
+    },
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+};
+
+/// Ticks to eat a piece of food
+const EAT_TIME: u32 = 300;
+/// Ticks to drink, or to drink one's fill from a lake
+const DRINK_TIME: u32 = 100;
+
+fn edible_index(tile: &Tile) -> Option<usize> {
+    tile.items.iter().rposition(|item| item.edible().is_some())
+}
+
+/// Eating food lying nearby, or drinking it, or drinking from the water
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Eat {
+    dir: Direction,
+}
+
+impl Eat {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(dir: Direction) -> ActionType {
+        Self { dir }.into()
+    }
+}
+
+impl ActionImpl for Eat {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        let char_sheet = actor.char_sheet();
+        if char_sheet.is_dead() {
+            return No("You are dead".to_string());
+        }
+
+        let pos = actor.pos() + self.dir;
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return No("There is nothing to eat there".to_string());
+        };
+        if let Some(index) = edible_index(tile) {
+            let edible = tile.items[index].edible().unwrap_or_default();
+            return if edible.is_drink() {
+                if char_sheet.hydration >= HYDRATION_MAX {
+                    return No("You are not thirsty".to_string());
+                }
+                Yes(DRINK_TIME)
+            } else {
+                if char_sheet.nutrition >= NUTRITION_MAX {
+                    return No("You are not hungry".to_string());
+                }
+                Yes(EAT_TIME)
+            };
+        }
+        if tile.terrain.supports_action(TerrainInteractAction::Drink) {
+            if char_sheet.hydration >= HYDRATION_MAX {
+                return No("You are not thirsty".to_string());
+            }
+            return Yes(DRINK_TIME);
+        }
+
+        No("There is nothing to eat there".to_string())
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let owner = world.units.get_unit(action.owner);
+        let pos = owner.pos() + self.dir;
+        let name = owner.name_for_actions().to_string();
+        let s = if owner.pronouns().verb_ends_with_s() {
+            "s"
+        } else {
+            ""
+        };
+        let Some(tile) = world.map.get_tile_opt(pos) else {
+            return;
+        };
+
+        let (msg, nutrition, hydration) = if let Some(index) = edible_index(tile) {
+            let items = &mut world.map.get_tile_mut(pos).items;
+            let item = &mut items[index];
+            let edible = item.edible().unwrap_or_default();
+            let item_name = item.name();
+            if item.is_stack() {
+                item.decrease_stack(1);
+            } else {
+                items.remove(index);
+            }
+            let verb = if edible.is_drink() { "drink" } else { "eat" };
+            (
+                format!("{name} {verb}{s} the {item_name}."),
+                edible.nutrition_ticks(),
+                edible.hydration_ticks(),
+            )
+        } else {
+            (
+                format!("{name} drink{s} from the {}.", tile.terrain.name()),
+                0,
+                HYDRATION_MAX,
+            )
+        };
+
+        world
+            .units
+            .get_unit_mut(action.owner)
+            .char_sheet_mut()
+            .eat(nutrition, hydration);
+        world.log.push(LogEvent::info(msg, pos));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::{Direction, Point};
+    use test_case::test_case;
+
+    use crate::game::map::items::helpers::{MEAT, ROCK};
+    use crate::game::map::terrains::DataTerrain;
+    use crate::game::savage::{HYDRATION_MAX, NUTRITION_MAX};
+    use crate::game::world::tests::{dirt, prepare_world};
+    use crate::game::{Action, Avatar, Item, Terrain, World};
+
+    use super::Eat;
+
+    fn prepare_meal(terrain: Terrain, items: &[&str]) -> World {
+        let mut world = prepare_world();
+        let tile = world.map.get_tile_mut(Point::new(1, 0));
+        tile.terrain = terrain;
+        tile.items = items.iter().map(|&id| Item::new(id)).collect();
+
+        world
+    }
+
+    fn water() -> Terrain {
+        DataTerrain::with_variant("shallow_water", 0).into()
+    }
+
+    #[test]
+    fn test_eating_meat() {
+        let mut world = prepare_meal(dirt(), &[]);
+        world.player_mut().char_sheet_mut().nutrition = 0;
+        world
+            .map
+            .get_tile_mut(Point::new(1, 0))
+            .items
+            .push(Item::new(MEAT).with_stack(2));
+
+        let action = Action::new(0, Eat::new(Direction::East), &world).unwrap();
+        action.act(&mut world);
+
+        let nutrition = world.player().char_sheet().nutrition;
+        assert!(nutrition > 0 && nutrition < NUTRITION_MAX);
+        let items = &world.map.get_tile(Point::new(1, 0)).items;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].stack_size(), 1);
+    }
+
+    #[test]
+    fn test_drinking_from_the_lake() {
+        let mut world = prepare_meal(water(), &[]);
+        world.player_mut().char_sheet_mut().hydration = 0;
+
+        let action = Action::new(0, Eat::new(Direction::East), &world).unwrap();
+        action.act(&mut world);
+
+        assert_eq!(HYDRATION_MAX, world.player().char_sheet().hydration);
+        assert!(Action::new(0, Eat::new(Direction::East), &world).is_err());
+    }
+
+    #[test_case(dirt(), &[]; "nothing")]
+    #[test_case(dirt(), &[ROCK]; "a rock")]
+    #[test_case(dirt(), &[MEAT]; "not hungry")]
+    #[test_case(water(), &[]; "not thirsty")]
+    fn test_cant_eat(terrain: Terrain, items: &[&str]) {
+        let mut world = prepare_meal(terrain, items);
+        let char_sheet = world.player_mut().char_sheet_mut();
+        char_sheet.nutrition = NUTRITION_MAX;
+        char_sheet.hydration = HYDRATION_MAX;
+
+        assert!(Action::new(0, Eat::new(Direction::East), &world).is_err());
+    }
+}
@@ -9,7 +9,7 @@ use super::super::{
             UnitMeleeAttackResult, ATTACK_MOVES,
         },
         traits::Name,
-        Action, AttackType, Avatar, LogEvent, World,
+        Action, AttackType, Avatar, GameEvent, LogEvent, World,
     },
     ActionImpl,
     ActionPossibility::{self, No, Yes},
@@ -23,6 +23,7 @@ pub struct Melee {
 
 impl Melee {
     #[allow(clippy::new_ret_no_self)]
+    #[must_use]
     pub fn new(pos: Point, world: &World) -> ActionType {
         Self {
             target: AttackTarget::auto(pos, world),
@@ -39,17 +40,17 @@ impl Melee {
         let AttackTarget::Terrain(target) = self.target else {
             return false;
         };
-        let owner = action.owner(world);
+        let owner = world.units.get_unit(action.owner);
         let Some(tile) = world.map.get_tile_opt(target) else {
             return false;
         };
         let weapon = owner.as_fighter().weapon(AttackType::Melee).unwrap();
         let can_smash = weapon.damage.damage_types.contains(&DamageType::Blunt)
-            && tile.terrain.smash().is_some();
+            && tile.terrain.smash_toughness().is_some();
         if !can_smash {
             return false;
         }
-        let attack = melee_smash_terrain(owner.as_fighter(), &tile.terrain);
+        let attack = melee_smash_terrain(owner.as_fighter(), &tile.terrain, &mut world.rng);
         match attack {
             TerrainMeleeAttackResult::Miss => {
                 world.log.push(LogEvent::info(
@@ -106,7 +107,18 @@ impl Melee {
                     ),
                     target,
                 ));
-                let (new_terrain, mut items) = tile.terrain.smash().unwrap().result;
+                let (new_terrain, mut items) = tile.terrain.smash(&mut world.rng);
+                world.events.emit(GameEvent::TerrainChanged {
+                    pos: target,
+                    terrain: new_terrain.clone(),
+                });
+                for item in &items {
+                    world.events.emit(GameEvent::ItemDropped {
+                        unit_id: None,
+                        item: Box::new(item.clone()),
+                        pos: target,
+                    });
+                }
                 world.map.get_tile_mut(target).terrain = new_terrain;
                 world.map.get_tile_mut(target).items.append(&mut items);
             }
@@ -119,10 +131,10 @@ impl Melee {
         let AttackTarget::Avatar(unit_id) = self.target else {
             return false;
         };
-        let owner = action.owner(world);
+        let owner = world.units.get_unit(action.owner);
         let weapon_name = owner.as_fighter().weapon(AttackType::Melee).unwrap().name;
         let unit = world.units.get_unit(unit_id);
-        let attack = melee_attack_unit(owner.as_fighter(), unit.as_fighter());
+        let attack = melee_attack_unit(owner.as_fighter(), unit.as_fighter(), &mut world.rng);
         match attack {
             UnitMeleeAttackResult::Hit(hit) => {
                 let damage = hit.params.damage.to_string();
@@ -155,9 +167,19 @@ impl Melee {
                     world.log.push(event);
                 }
 
+                world.events.emit(GameEvent::AttackResolved {
+                    attacker: action.owner,
+                    target: unit_id,
+                    hit: Some(hit.clone()),
+                });
                 world.apply_damage(unit_id, hit);
             }
             UnitMeleeAttackResult::Miss => {
+                world.events.emit(GameEvent::AttackResolved {
+                    attacker: action.owner,
+                    target: unit_id,
+                    hit: None,
+                });
                 world.log.push(LogEvent::warning(
                     format!(
                         "{} attack{} {} with {} {weapon_name} but miss{}.",
@@ -246,7 +268,7 @@ mod tests {
     use roguemetry::Point;
 
     use crate::game::map::items::helpers::{DEMONIC_SAP, GOD_AXE, STONE_KNIFE, STONE_SPEAR};
-    use crate::game::map::terrains::Boulder;
+    use crate::game::map::terrains::DataTerrain;
     use crate::game::world::tests::{add_dummy, prepare_world};
     use crate::game::{Action, Avatar, Item, Race};
 
@@ -299,7 +321,8 @@ mod tests {
         let mut world = prepare_world();
         assert_eq!(world.meta.current_tick, 0);
 
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Boulder::default().into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("small_boulder", 0).into();
         world.player_inventory_mut().wield(Item::new(DEMONIC_SAP));
         let action = Action::new(0, Melee::new(Point::new(1, 0), &world).into(), &world).unwrap();
         world.player_mut().set_action(Some(action));
@@ -321,7 +344,8 @@ mod tests {
         let mut world = prepare_world();
         assert_eq!(world.meta.current_tick, 0);
 
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Boulder::default().into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("small_boulder", 0).into();
         world.player_inventory_mut().wield(Item::new(STONE_KNIFE));
         let action = Action::new(0, Melee::new(Point::new(1, 0), &world).into(), &world).unwrap();
         world.player_mut().set_action(Some(action));
@@ -342,7 +366,8 @@ mod tests {
         assert_eq!(world.meta.current_tick, 0);
         assert_eq!(world.player().personality.appearance.race, Race::Gazan);
 
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Boulder::default().into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("small_boulder", 0).into();
         world.player_inventory_mut().clear();
         let action = Action::new(0, Melee::new(Point::new(1, 0), &world).into(), &world).unwrap();
         world.player_mut().set_action(Some(action));
@@ -363,7 +388,8 @@ mod tests {
         world.player_mut().personality.appearance.race = Race::Lagnam;
         world.player_inventory_mut().clear();
 
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Boulder::default().into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("small_boulder", 0).into();
         let action = Action::new(0, Melee::new(Point::new(1, 0), &world).into(), &world).unwrap();
         world.player_mut().set_action(Some(action));
         world.tick();
@@ -1,18 +1,33 @@
+pub use butcher::Butcher;
+pub use change_level::ChangeLevel;
+pub use chop::Chop;
+pub use climb::Climb;
 pub use close::Close;
+pub use craft::Craft;
+pub use dig::Dig;
 pub use drop::DropMainHand;
+pub use eat::Eat;
 pub use melee::Melee;
 pub use open::Open;
 pub use read::Read;
 pub use reload::Reload;
 pub use shoot::Shoot;
 pub use skip::Skip;
+pub use sneak::Sneak;
 pub use throw::Throw;
 pub use walk::Walk;
 pub use wear::Wear;
 pub use wield::WieldFromGround;
 
+mod butcher;
+mod change_level;
+mod chop;
+mod climb;
 mod close;
+mod craft;
+mod dig;
 mod drop;
+mod eat;
 mod melee;
 mod open;
 mod ranged_attack;
@@ -20,7 +35,9 @@ mod read;
 mod reload;
 mod shoot;
 mod skip;
+mod sneak;
 mod throw;
 mod walk;
 mod wear;
 mod wield;
+mod work;
@@ -3,7 +3,7 @@ use roguemetry::Direction;
 use crate::game::log::{LogCategory, LogEvent};
 use crate::game::map::{TerrainInteract, TerrainInteractAction, TerrainView};
 use crate::game::traits::Name;
-use crate::game::{Action, ActionType, Avatar, World};
+use crate::game::{Action, ActionType, Avatar, GameEvent, World};
 
 use super::super::{
     ActionImpl,
@@ -17,6 +17,7 @@ pub struct Open {
 
 impl Open {
     #[allow(clippy::new_ret_no_self)]
+    #[must_use]
     pub fn new(dir: Direction) -> ActionType {
         Self { dir }.into()
     }
@@ -60,8 +61,12 @@ impl ActionImpl for Open {
         ));
         let tile = world.map.get_tile_mut(pos);
         let (new_terrain, mut items) = tile.terrain.open();
-        tile.terrain = new_terrain;
+        tile.terrain = new_terrain.clone();
         tile.items.append(&mut items);
+        world.events.emit(GameEvent::TerrainChanged {
+            pos,
+            terrain: new_terrain,
+        });
 
         world.calc_fov();
     }
@@ -72,7 +77,7 @@ mod tests {
     use roguemetry::{Direction, Point};
 
     use crate::game::map::items::helpers::WOODEN_SPLINTER;
-    use crate::game::map::terrains::Chest;
+    use crate::game::map::terrains::DataTerrain;
     use crate::game::world::tests::prepare_world;
     use crate::game::{Action, Avatar, Item, TerrainInteract, TerrainInteractAction};
 
@@ -81,8 +86,9 @@ mod tests {
     #[test]
     fn test_opening() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).terrain =
-            Chest::new(vec![Item::new(WOODEN_SPLINTER)], false).into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = DataTerrain::with_variant("chest", 0)
+            .with_items(vec![Item::new(WOODEN_SPLINTER)])
+            .into();
 
         let action = Action::new(0, Open::new(Direction::East), &world).unwrap();
         world.player_mut().set_action(Some(action));
@@ -105,7 +111,8 @@ mod tests {
     #[test]
     fn test_cant_open_already_opened() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Chest::new(Vec::new(), true).into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::with_variant("open_chest", 0).into();
 
         let action = Action::new(0, Open::new(Direction::East), &world);
         assert!(action.is_err());
@@ -10,7 +10,7 @@ use crate::game::{
     savage::{ranged_attack_unit, UnitRangedAttackResult, ATTACK_MOVES},
     traits::Name,
     units::Inventory,
-    AttackType, Avatar, LogEvent, RangedDistance, World,
+    AttackType, Avatar, GameEvent, LogEvent, RangedDistance, World,
 };
 use crate::lang::a;
 use roguemetry::Point;
@@ -129,13 +129,31 @@ fn finish_unit(unit_id: usize, world: &mut World, attack_type: AttackType, actio
 
     let victim_id = match attack_result {
         UnitRangedAttackResult::InnocentBystander(victim_id, hit) => {
+            world.events.emit(GameEvent::AttackResolved {
+                attacker: action.owner,
+                target: victim_id,
+                hit: Some(hit.clone()),
+            });
             world.apply_damage(victim_id, hit);
             victim_id
         }
         UnitRangedAttackResult::Hit(hit) => {
+            world.events.emit(GameEvent::AttackResolved {
+                attacker: action.owner,
+                target: unit_id,
+                hit: Some(hit.clone()),
+            });
             world.apply_damage(unit_id, hit);
             unit_id
         }
+        UnitRangedAttackResult::Miss => {
+            world.events.emit(GameEvent::AttackResolved {
+                attacker: action.owner,
+                target: unit_id,
+                hit: None,
+            });
+            unit_id
+        }
         _ => unit_id,
     };
 
@@ -170,9 +188,16 @@ fn get_unit_attack_result(
     attack_type: AttackType,
     action: &Action,
 ) -> UnitRangedAttackResult {
-    let owner = action.owner(world);
+    let owner = world.units.get_unit(action.owner);
     let unit = world.units.get_unit(unit_id);
-    ranged_attack_unit(attack_type, owner.as_fighter(), unit.as_fighter(), world)
+    ranged_attack_unit(
+        attack_type,
+        owner.as_fighter(),
+        unit.as_fighter(),
+        &world.map,
+        &world.units,
+        &mut world.rng,
+    )
 }
 
 /// Logs the result of a ranged attack on a unit.
@@ -13,22 +13,27 @@ use super::super::{
 #[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
 pub struct Read {
     dir: Direction,
-    reading_roll: RollResult,
 }
 
 impl Read {
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(dir: Direction, avatar: &dyn Avatar) -> ActionType {
-        let reading_roll = avatar
-            .char_sheet()
-            .get_skill_with_modifiers(Skill::Reading)
-            .roll_explosive();
-        Self { dir, reading_roll }.into()
+    #[must_use]
+    pub fn new(dir: Direction) -> ActionType {
+        Self { dir }.into()
     }
+}
 
-    #[cfg(test)]
-    pub fn new_test(dir: Direction, reading_roll: RollResult) -> ActionType {
-        Self { dir, reading_roll }.into()
+/// Reading time after the Reading roll, `None` if it failed
+fn reading_time(text_length: usize, roll: RollResult) -> Option<u32> {
+    // Every character takes one tick to read, wow!
+    let reading_time = text_length as f32;
+    if roll.natural == 1 {
+        return None;
+    }
+    match roll.successes() {
+        0 => Some((reading_time * 2.0).round() as u32),
+        1 => Some(reading_time.round() as u32),
+        2.. => Some((reading_time * 0.5).round() as u32),
     }
 }
 
@@ -44,20 +49,27 @@ impl ActionImpl for Read {
             return No("There is nothing to read there".to_string());
         };
         if tile.is_readable() {
-            // Every character takes one tick to read, wow!
-            let reading_time = tile.read().len() as f32;
-            if self.reading_roll.natural == 1 {
-                return No("You tried to read it but failed".to_string());
-            }
-            match self.reading_roll.successes() {
-                0 => Yes((reading_time * 2.0).round() as u32),
-                1 => Yes(reading_time.round() as u32),
-                2.. => Yes((reading_time * 0.5).round() as u32),
-            }
+            // the usual time, the roll on start makes it slower or faster
+            Yes(tile.read().len() as u32)
         } else {
             No("There is nothing to read".to_string())
         }
     }
+
+    fn on_start(&self, action: &Action, world: &mut World) {
+        let pos = action.owner(world).pos() + self.dir;
+        let text_length = world.map.get_tile(pos).read().len();
+        let roll = action
+            .owner(world)
+            .char_sheet()
+            .get_skill_with_modifiers(Skill::Reading)
+            .roll_explosive(&mut world.rng);
+        match reading_time(text_length, roll) {
+            Some(length) => world.change_action_length(action.owner, length),
+            None => action.cancel_action(world, "You tried to read it but failed".to_string()),
+        }
+    }
+
     fn on_finish(&self, action: &Action, world: &mut World) {
         let owner = action.owner(world);
         if owner.is_player() {
@@ -78,7 +90,7 @@ mod tests {
     use crate::game::world::tests::prepare_world;
     use crate::game::{Action, Avatar, RollResult};
 
-    use super::Read;
+    use super::{reading_time, Read};
 
     #[test]
     fn test_reading() {
@@ -90,8 +102,8 @@ mod tests {
             .items
             .push(book_debug());
 
-        let typ = Read::new_test(Direction::East, RollResult::new(4, 4));
-        if let Ok(action) = Action::new(0, typ, &world) {
+        let start = world.meta.current_tick;
+        if let Ok(action) = Action::new(0, Read::new(Direction::East), &world) {
             assert_eq!(action.length, 52);
             world.player_mut().set_action(Some(action));
             while world.player().action().is_some() {
@@ -100,6 +112,8 @@ mod tests {
         } else {
             panic!("Cannot read");
         }
+        // the roll on start changes the time
+        assert!([26, 52, 104].contains(&(world.meta.current_tick - start)));
 
         let event = &world.log.new_events()[0];
         assert!(
@@ -113,45 +127,23 @@ mod tests {
 
     #[test]
     fn test_cant_read() {
-        let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).items.clear();
-        world
-            .map
-            .get_tile_mut(Point::new(1, 0))
-            .items
-            .push(book_debug());
-
-        let typ = Read::new_test(Direction::East, RollResult::new(1, 1));
-        assert!(Action::new(0, typ, &world).is_err());
+        assert_eq!(reading_time(52, RollResult::new(1, 1)), None);
     }
 
     #[test]
-    fn test_slow_read() {
+    fn test_nothing_to_read() {
         let mut world = prepare_world();
         world.map.get_tile_mut(Point::new(1, 0)).items.clear();
-        world
-            .map
-            .get_tile_mut(Point::new(1, 0))
-            .items
-            .push(book_debug());
+        assert!(Action::new(0, Read::new(Direction::East), &world).is_err());
+    }
 
-        let typ = Read::new_test(Direction::East, RollResult::new(2, 2));
-        let action = Action::new(0, typ, &world).unwrap();
-        assert_eq!(action.length, 104);
+    #[test]
+    fn test_slow_read() {
+        assert_eq!(reading_time(52, RollResult::new(2, 2)), Some(104));
     }
 
     #[test]
     fn test_fast_read() {
-        let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).items.clear();
-        world
-            .map
-            .get_tile_mut(Point::new(1, 0))
-            .items
-            .push(book_debug());
-
-        let typ = Read::new_test(Direction::East, RollResult::new(8, 8));
-        let action = Action::new(0, typ, &world).unwrap();
-        assert_eq!(action.length, 26);
+        assert_eq!(reading_time(52, RollResult::new(8, 8)), Some(26));
     }
 }
@@ -13,6 +13,7 @@ pub struct Reload {}
 
 impl Reload {
     #[allow(clippy::new_ret_no_self)]
+    #[must_use]
     pub fn new() -> ActionType {
         Self {}.into()
     }
@@ -25,6 +25,7 @@ pub struct Shoot {
 
 impl Shoot {
     #[allow(clippy::new_ret_no_self)]
+    #[must_use]
     pub fn new(pos: Point, world: &World) -> ActionType {
         Self {
             target: AttackTarget::auto(pos, world),
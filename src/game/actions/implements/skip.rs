@@ -12,10 +12,12 @@ pub struct Skip {
 
 impl Skip {
     #[allow(clippy::new_ret_no_self)]
+    #[must_use]
     pub fn new(length: u32) -> ActionType {
         Self { length }.into()
     }
 
+    #[must_use]
     pub fn one() -> ActionType {
         Self::new(1)
     }
@@ -0,0 +1,79 @@
+use super::super::{
+    super::{Avatar, LogEvent, World},
+    Action, ActionImpl,
+    ActionPossibility::{self, No, Yes},
+    ActionType,
+};
+
+/// Switches between sneaking and normal walking
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Sneak {
+    sneaking: bool,
+}
+
+impl Sneak {
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new(sneaking: bool) -> ActionType {
+        Self { sneaking }.into()
+    }
+}
+
+impl ActionImpl for Sneak {
+    fn is_possible(&self, actor_id: usize, world: &World) -> ActionPossibility {
+        let actor = world.units.get_unit(actor_id);
+        if actor.char_sheet().is_dead() {
+            return No("You are dead".to_string());
+        }
+        if actor.char_sheet().sneaking == self.sneaking {
+            return No(if self.sneaking {
+                "You are already sneaking".to_string()
+            } else {
+                "You are not sneaking".to_string()
+            });
+        }
+
+        Yes(0)
+    }
+
+    fn on_finish(&self, action: &Action, world: &mut World) {
+        let unit = world.units.get_unit_mut(action.owner);
+        unit.char_sheet_mut().sneaking = self.sneaking;
+        if action.owner == 0 {
+            let msg = if self.sneaking {
+                "You start sneaking."
+            } else {
+                "You stop sneaking."
+            };
+            let pos = unit.pos();
+            world.log.push(LogEvent::info(msg, pos));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::{Direction, Point};
+
+    use crate::game::world::tests::{dirt, prepare_world};
+    use crate::game::{Action, Avatar};
+
+    use super::super::Walk;
+    use super::Sneak;
+
+    #[test]
+    fn test_sneaking_is_slower() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+        let walk = Action::new(0, Walk::new(Direction::East), &world).unwrap();
+
+        let action = Action::new(0, Sneak::new(true), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert!(world.player().char_sheet().sneaking);
+        assert!(Action::new(0, Sneak::new(true), &world).is_err());
+
+        let sneak = Action::new(0, Walk::new(Direction::East), &world).unwrap();
+        assert!(sneak.length > walk.length);
+    }
+}
@@ -22,6 +22,7 @@ pub struct Throw {
 
 impl Throw {
     #[allow(clippy::new_ret_no_self)]
+    #[must_use]
     pub fn new(target: Point, world: &World) -> ActionType {
         Self {
             target: AttackTarget::auto(target, world),
@@ -46,12 +47,12 @@ mod tests {
 
     use roguemetry::{Direction, Point};
 
-    use crate::assets::Sprite;
     use crate::game::actions::implements::Skip;
     use crate::game::actions::AttackTarget;
     use crate::game::map::items::helpers::ROCK;
     use crate::game::world::tests::{add_dummy, add_monster, prepare_world};
     use crate::game::{Action, Avatar, Item, ItemPrototype, ItemSize};
+    use crate::sprite::Sprite;
 
     use super::*;
 
@@ -127,6 +128,8 @@ mod tests {
                 need_ammo: None,
                 is_ammo: None,
                 stackable: false,
+                emits_light: 0,
+                edible: None,
             }));
 
         assert!(Action::new(0, Throw::new(target, &world), &world).is_err());
@@ -8,6 +8,7 @@ use super::super::{
     super::{
         log::{LogCategory, LogEvent},
         map::{Passage::Passable, TerrainInteract, TerrainView},
+        savage::SNEAKING_SLOWDOWN,
         traits::Name,
         Avatar, World,
     },
@@ -22,6 +23,7 @@ pub struct Walk {
 
 impl Walk {
     #[allow(clippy::new_ret_no_self)]
+    #[must_use]
     pub fn new(dir: Direction) -> ActionType {
         Self { dir }.into()
     }
@@ -48,6 +50,9 @@ impl ActionImpl for Walk {
         }
         let unit_on_tile = tile.units.iter().copied().next();
         if let Some(unit_id) = unit_on_tile {
+            if actor_id == 0 && !world.detection.has_noticed(0, unit_id) {
+                return No("Something is on the way".to_string());
+            }
             return No(format!(
                 "{} is on the way",
                 world.units.get_unit(unit_id).name_for_actions()
@@ -60,7 +65,12 @@ impl ActionImpl for Walk {
 
         Yes({
             let k_diagonal = if self.dir.is_diagonal() { SQRT_2 } else { 1.0 };
-            let k = k_diagonal * k_character;
+            let k_sneaking = if actor.char_sheet().sneaking {
+                SNEAKING_SLOWDOWN
+            } else {
+                1.0
+            };
+            let k = k_diagonal * k_character * k_sneaking;
             if let Passable(pass_time) = tile.terrain.passage() {
                 (pass_time as f32 * k).round() as u32
             } else {
@@ -83,24 +93,27 @@ impl ActionImpl for Walk {
                 LogCategory::Info,
             ));
         }
+        world.keep_afloat(action.owner);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
     use roguemetry::{Direction, Point};
 
     use crate::game::actions::implements::Skip;
-    use crate::game::map::terrains::{Boulder, BoulderSize, Dirt};
-    use crate::game::world::tests::{add_dummy, prepare_world};
-    use crate::game::{Action, ActionType, Avatar};
+    use crate::game::world::tests::{add_dummy, boulder, dirt, prepare_world};
+    use crate::game::{Action, ActionType, Avatar, GameEvent};
 
     use super::Walk;
 
     #[test]
     fn test_walking() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Dirt::default().into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
 
         let typ = Walk {
             dir: Direction::East,
@@ -115,7 +128,7 @@ mod tests {
     #[test]
     fn test_walking_fail_to_impassable_terrain() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Boulder::new(BoulderSize::Huge).into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = boulder();
 
         assert!(Action::new(
             0,
@@ -131,7 +144,7 @@ mod tests {
     #[test]
     fn test_walking_fail_to_unit() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 0)).terrain = Dirt::default().into();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
         add_dummy(&mut world, Point::new(1, 0));
 
         assert!(Action::new(
@@ -145,10 +158,25 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn test_walking_cancelled_on_start() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+
+        let action = Action::new(0, Walk::new(Direction::East), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        add_dummy(&mut world, Point::new(1, 0));
+        world.tick();
+
+        assert_eq!(Point::new(0, 0), world.player().pos());
+        assert!(world.player().action().is_none());
+        assert_eq!(0, world.meta.current_tick);
+    }
+
     #[test]
     fn test_fail_walking_two_units_to_same_place() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(0, 1)).terrain = Dirt::default().into();
+        world.map.get_tile_mut(Point::new(0, 1)).terrain = dirt();
         let npc = add_dummy(&mut world, Point::new(1, 0));
 
         let action = Action::new(
@@ -176,7 +204,7 @@ mod tests {
         assert_eq!(Point::new(1, 0), world.units.get_unit(npc).pos());
         assert!(world.player().action().is_none());
 
-        let action = Action::new(0, Skip::one().into(), &world).unwrap();
+        let action = Action::new(0, Skip::one(), &world).unwrap();
         world.player_mut().set_action(Some(action));
         world.tick();
         assert!(matches!(
@@ -189,10 +217,41 @@ mod tests {
         assert_eq!(0, world.map.get_tile(Point::new(0, 0)).units.len());
     }
 
+    #[test]
+    fn test_walk_is_stopped_when_tile_is_taken() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(0, 1)).terrain = dirt();
+        let npc = add_dummy(&mut world, Point::new(1, 0));
+        let events = Rc::new(RefCell::new(Vec::new()));
+        world.events.subscribe(events.clone());
+
+        let action = Action::new(npc, Walk::new(Direction::SouthWest), &world).unwrap();
+        let npc_finish = action.finish;
+        world.units.get_unit_mut(npc).set_action(Some(action));
+        let action = Action::new(0, Walk::new(Direction::South), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+
+        assert!(world.meta.current_tick < npc_finish);
+        assert!(events.borrow().iter().any(|e| matches!(
+            e,
+            GameEvent::ActionCancelled {
+                unit_id,
+                typ: ActionType::Walk(..),
+                ..
+            } if *unit_id == npc
+        )));
+        // npc doesn't wait for the end of its walk to plan something else
+        assert!(matches!(
+            world.units.get_unit(npc).action().unwrap().typ,
+            ActionType::Skip(..)
+        ));
+    }
+
     #[test]
     fn test_two_monsters_cant_walk_to_same_tile() {
         let mut world = prepare_world();
-        world.map.get_tile_mut(Point::new(1, 1)).terrain = Dirt::default().into();
+        world.map.get_tile_mut(Point::new(1, 1)).terrain = dirt();
         let npc1 = add_dummy(&mut world, Point::new(1, 0));
         let npc2 = add_dummy(&mut world, Point::new(0, 1));
 
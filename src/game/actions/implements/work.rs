@@ -0,0 +1,13 @@
+/// The `work` module provides the timing shared by the hard work with tools, like digging and chopping.
+use crate::game::{Attribute, Avatar, Item};
+
+/// Ticks of the hard work: sturdier tools and stronger workers are faster, d6 Strength is the usual
+pub fn work_time(base_time: f32, worker: &dyn Avatar, tool: &Item) -> u32 {
+    let strength = worker
+        .char_sheet()
+        .attributes
+        .get_attribute(Attribute::Strength)
+        .value();
+    let speed = f32::from(strength) / 6.0 * tool.tool_speed();
+    (base_time / speed).round() as u32
+}
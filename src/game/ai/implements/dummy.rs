@@ -9,7 +9,7 @@ use super::super::AIImpl;
 pub struct DummyAI;
 
 impl AIImpl for DummyAI {
-    fn plan(&mut self, unit_id: usize, world: &World) -> Option<Action> {
+    fn plan(&mut self, unit_id: usize, world: &mut World) -> Option<Action> {
         Action::new(unit_id, Skip::new(100), world).ok()
     }
 }
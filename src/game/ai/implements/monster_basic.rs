@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 
-use rand::RngExt;
 use roguemetry::{Direction, Point};
 use serde::{Deserialize, Serialize};
 
@@ -61,22 +60,15 @@ impl BasicMonsterAI {
 }
 
 impl AIImpl for BasicMonsterAI {
-    fn plan(&mut self, unit_id: usize, world: &World) -> Option<Action> {
+    fn plan(&mut self, unit_id: usize, world: &mut World) -> Option<Action> {
         let player_pos = world.player().pos();
         let unit = world.units.get_unit(unit_id).as_monster()?;
         let pos = unit.pos();
-        let distance = pos.distance_to(player_pos).floor() as u32;
-        let sight_range = unit.char_sheet().sight_range();
-        // TODO: use World's rng instead of thread_rng
-        // TODO: check if the player is visible
-        // TODO: add aggro state and periodic Notice roll
-        if distance > sight_range {
-            return Action::new(
-                unit_id,
-                Walk::new(Direction::random(&mut rand::rng(), false)),
-                world,
-            )
-            .ok();
+        // TODO: add aggro state
+        // units lose the player from the detection when there is no line of sight to them
+        if !world.detection.has_noticed(unit_id, 0) {
+            let dir = Direction::random(&mut world.rng, false);
+            return Action::new(unit_id, Walk::new(dir), world).ok();
         }
 
         let attack = Action::new(unit_id, Melee::new(player_pos, world), world);
@@ -114,7 +106,6 @@ mod tests {
     use roguemetry::{Direction, Point};
 
     use crate::game::actions::AttackTarget;
-    use crate::game::map::terrains::{Boulder, BoulderSize, Dirt, DirtVariant};
     use crate::game::world::tests::{add_monster, boulder, dirt, prepare_world};
     use crate::game::{Avatar, Terrain};
 
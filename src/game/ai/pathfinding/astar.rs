@@ -22,7 +22,6 @@ pub fn astar(map: &Map, start: Point, end: Point) -> Option<(Vec<Point>, u32)> {
 
 #[cfg(test)]
 mod test {
-    use crate::game::map::terrains::{Boulder, BoulderSize, Dirt, DirtVariant};
     use crate::game::world::tests::{boulder, dirt, prepare_world};
     use crate::game::Terrain;
 
@@ -16,7 +16,7 @@ pub enum AI {
 }
 
 pub trait AIImpl {
-    fn plan(&mut self, unit_id: usize, world: &World) -> Option<Action>;
+    fn plan(&mut self, unit_id: usize, world: &mut World) -> Option<Action>;
 }
 
 static AI_MANAGER: OnceCell<Mutex<AIManager>> = OnceCell::new();
@@ -28,6 +28,9 @@ pub struct AIManager {
 }
 
 impl AIManager {
+    /// # Panics
+    ///
+    /// Panics if the AI manager mutex is poisoned
     pub fn instance() -> MutexGuard<'static, AIManager> {
         AI_MANAGER
             .get_or_init(|| {
@@ -40,7 +43,7 @@ impl AIManager {
             .expect("AI_MANAGER: ALL YOUR AI ARE BELONGS TO US")
     }
 
-    pub fn plan(&mut self, ai: AI, unit_id: usize, world: &World) -> Option<Action> {
+    pub fn plan(&mut self, ai: AI, unit_id: usize, world: &mut World) -> Option<Action> {
         match ai {
             AI::Dummy => self.dummy.plan(unit_id, world),
             AI::BasicMonster => self.basic_monster.plan(unit_id, world),
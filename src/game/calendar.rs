@@ -0,0 +1,152 @@
+use std::fmt::{Display, Formatter};
+
+/// Walking one tile on dirt is about a second
+pub const TICKS_PER_SECOND: u128 = 10;
+pub const TICKS_PER_MINUTE: u128 = TICKS_PER_SECOND * 60;
+pub const TICKS_PER_HOUR: u128 = TICKS_PER_MINUTE * 60;
+pub const TICKS_PER_DAY: u128 = TICKS_PER_HOUR * 24;
+pub const DAYS_PER_SEASON: u128 = 28;
+pub const TICKS_PER_SEASON: u128 = TICKS_PER_DAY * DAYS_PER_SEASON;
+pub const TICKS_PER_YEAR: u128 = TICKS_PER_SEASON * 4;
+/// New game starts in the morning of the first day of spring
+pub const START_OFFSET: u128 = TICKS_PER_HOUR * 8;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Autumn => "Autumn",
+            Season::Winter => "Winter",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TimeOfDay {
+    Night,
+    Dawn,
+    Day,
+    Dusk,
+}
+
+/// Date and time of the Midia world
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DateTime {
+    /// Starting from 1
+    pub year: u128,
+    pub season: Season,
+    /// Day of the season, starting from 1
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl DateTime {
+    #[must_use]
+    pub fn from_tick(tick: u128) -> Self {
+        let tick = tick + START_OFFSET;
+        let season = match (tick % TICKS_PER_YEAR) / TICKS_PER_SEASON {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        };
+        Self {
+            year: tick / TICKS_PER_YEAR + 1,
+            season,
+            day: ((tick % TICKS_PER_SEASON) / TICKS_PER_DAY + 1) as u8,
+            hour: ((tick % TICKS_PER_DAY) / TICKS_PER_HOUR) as u8,
+            minute: ((tick % TICKS_PER_HOUR) / TICKS_PER_MINUTE) as u8,
+        }
+    }
+
+    /// Minutes since midnight
+    fn minutes(self) -> u32 {
+        u32::from(self.hour) * 60 + u32::from(self.minute)
+    }
+
+    #[must_use]
+    pub fn time_of_day(self) -> TimeOfDay {
+        match self.hour {
+            5 | 6 => TimeOfDay::Dawn,
+            7..=18 => TimeOfDay::Day,
+            19 | 20 => TimeOfDay::Dusk,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    /// Sunlight level from 0.0 at night to 1.0 at day, changing smoothly on dawn and dusk
+    #[must_use]
+    pub fn daylight(self) -> f32 {
+        let minutes = self.minutes() as f32;
+        match self.time_of_day() {
+            TimeOfDay::Night => 0.0,
+            TimeOfDay::Day => 1.0,
+            TimeOfDay::Dawn => (minutes - 5.0 * 60.0) / 120.0,
+            TimeOfDay::Dusk => 1.0 - (minutes - 19.0 * 60.0) / 120.0,
+        }
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}, {} {}, year {}",
+            self.hour,
+            self.minute,
+            self.season.name(),
+            self.day,
+            self.year
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::{DateTime, Season, TimeOfDay, TICKS_PER_DAY, TICKS_PER_HOUR, TICKS_PER_SEASON};
+
+    #[test]
+    fn test_game_starts_in_the_morning() {
+        let time = DateTime::from_tick(0);
+        assert_eq!(1, time.year);
+        assert_eq!(Season::Spring, time.season);
+        assert_eq!(1, time.day);
+        assert_eq!(8, time.hour);
+        assert_eq!(0, time.minute);
+        assert_eq!("08:00, Spring 1, year 1", time.to_string());
+    }
+
+    #[test]
+    fn test_seasons_and_years() {
+        let time = DateTime::from_tick(TICKS_PER_SEASON * 3 + TICKS_PER_DAY * 2);
+        assert_eq!(Season::Winter, time.season);
+        assert_eq!(3, time.day);
+
+        let time = DateTime::from_tick(TICKS_PER_SEASON * 4);
+        assert_eq!(Season::Spring, time.season);
+        assert_eq!(2, time.year);
+    }
+
+    #[test_case(0, TimeOfDay::Day, 1.0)]
+    #[test_case(14, TimeOfDay::Night, 0.0)]
+    #[test_case(22, TimeOfDay::Dawn, 0.5)]
+    #[test_case(12, TimeOfDay::Dusk, 0.5)]
+    fn test_time_of_day(hours_passed: u128, time_of_day: TimeOfDay, daylight: f32) {
+        let time = DateTime::from_tick(hours_passed * TICKS_PER_HOUR);
+        assert_eq!(time_of_day, time.time_of_day());
+        assert!((time.daylight() - daylight).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,41 @@
+use std::collections::BTreeSet;
+
+use super::calendar::TICKS_PER_SECOND;
+
+/// How often units are trying to notice each other
+pub const NOTICE_PERIOD: u128 = TICKS_PER_SECOND * 5;
+
+/// Who has noticed whom, only units that could see each other are remembered
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct Detection {
+    /// Pairs of observer and target
+    noticed: BTreeSet<(usize, usize)>,
+    /// Tick of the next Notice rolls
+    pub next_roll: u128,
+}
+
+impl Detection {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn has_noticed(&self, observer: usize, target: usize) -> bool {
+        observer == target || self.noticed.contains(&(observer, target))
+    }
+
+    pub fn notice(&mut self, observer: usize, target: usize) {
+        self.noticed.insert((observer, target));
+    }
+
+    pub fn lose_sight(&mut self, observer: usize, target: usize) {
+        self.noticed.remove(&(observer, target));
+    }
+
+    /// Unit is dead or gone
+    pub fn forget_unit(&mut self, unit_id: usize) {
+        self.noticed
+            .retain(|&(observer, target)| observer != unit_id && target != unit_id);
+    }
+}
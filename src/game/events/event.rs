@@ -0,0 +1,48 @@
+use roguemetry::Point;
+
+use super::super::{savage::HitResult, ActionType, Item, Terrain};
+
+/// Something that happened in the world, for everyone who needs to know it without parsing the log
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    UnitMoved {
+        unit_id: usize,
+        from: Point,
+        to: Point,
+    },
+    /// `hit` is `None` if attacker missed
+    AttackResolved {
+        attacker: usize,
+        target: usize,
+        hit: Option<HitResult>,
+    },
+    UnitChangedLevel {
+        unit_id: usize,
+        pos: Point,
+        from: i32,
+        to: i32,
+    },
+    UnitDied {
+        unit_id: usize,
+        pos: Point,
+    },
+    /// `unit_id` is `None` if item wasn't dropped by a unit (e.g. it's a remains of a smashed terrain)
+    ItemDropped {
+        unit_id: Option<usize>,
+        item: Box<Item>,
+        pos: Point,
+    },
+    TerrainChanged {
+        pos: Point,
+        terrain: Terrain,
+    },
+    UnitNoticed {
+        observer: usize,
+        target: usize,
+    },
+    ActionCancelled {
+        unit_id: usize,
+        typ: ActionType,
+        reason: String,
+    },
+}
@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub use event::GameEvent;
+
+mod event;
+
+pub trait EventSubscriber {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+/// Collects everything that happened in the world, it's useful for tests
+impl EventSubscriber for Vec<GameEvent> {
+    fn on_event(&mut self, event: &GameEvent) {
+        self.push(event.clone());
+    }
+}
+
+/// Events are queued while the world is ticking and sent to subscribers after the tick
+#[derive(Default)]
+pub struct EventBus {
+    queue: Vec<GameEvent>,
+    subscribers: Vec<Rc<RefCell<dyn EventSubscriber>>>,
+}
+
+impl EventBus {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subscribe(&mut self, subscriber: Rc<RefCell<dyn EventSubscriber>>) {
+        self.subscribers.push(subscriber);
+    }
+
+    pub fn emit(&mut self, event: GameEvent) {
+        self.queue.push(event);
+    }
+
+    pub fn dispatch(&mut self) {
+        for event in self.queue.drain(..) {
+            for subscriber in &self.subscribers {
+                subscriber.borrow_mut().on_event(&event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use roguemetry::Point;
+
+    use super::{EventBus, GameEvent};
+
+    #[test]
+    fn test_events_are_sent_on_dispatch() {
+        let mut bus = EventBus::new();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        bus.subscribe(events.clone());
+
+        bus.emit(GameEvent::UnitDied {
+            unit_id: 1,
+            pos: Point::new(0, 0),
+        });
+        assert!(events.borrow().is_empty());
+
+        bus.dispatch();
+        assert!(matches!(
+            events.borrow()[..],
+            [GameEvent::UnitDied { unit_id: 1, .. }]
+        ));
+
+        bus.dispatch();
+        assert_eq!(events.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::game::{
+    ai::AI,
+    races::{BodyColor, Pronouns, Race, Sex},
+    savage::{Attribute, Attributes, Skill, Skills},
+    AttrLevel, CharSheet, SkillLevel,
+};
+
+use super::DamageValue;
+
+/// Creature that can be spawned with `Monster::from_prototype`
+#[derive(Deserialize, Debug)]
+pub struct CreaturePrototype {
+    pub id: String,
+    pub name: String,
+    pub race: Race,
+    #[serde(default)]
+    pub body_color: Option<BodyColor>,
+    #[serde(default)]
+    pub sex: Sex,
+    #[serde(default = "default_pronouns")]
+    pub pronouns: Pronouns,
+    #[serde(default = "default_age")]
+    pub age: u8,
+    #[serde(default = "default_ai")]
+    pub ai: AI,
+    #[serde(default)]
+    pub wild_card: bool,
+    /// Attributes that differ from d4
+    #[serde(default)]
+    pub attributes: HashMap<Attribute, AttrLevel>,
+    /// Skills that differ from the race defaults
+    #[serde(default)]
+    pub skills: HashMap<Skill, SkillLevel>,
+    /// Replaces the natural weapon of the race
+    pub natural_weapon: Option<NaturalWeapon>,
+    /// Replaces the natural armor of the race
+    pub natural_armor: Option<i8>,
+    #[serde(default)]
+    pub equipment: Equipment,
+    /// Loot table for the drop on death
+    pub loot: Option<String>,
+}
+
+impl CreaturePrototype {
+    #[must_use]
+    pub fn char_sheet(&self) -> CharSheet {
+        let mut attributes = Attributes::default();
+        for (&attribute, &level) in &self.attributes {
+            attributes.set_attribute(attribute, level);
+        }
+        let mut skills = Skills::default(self.race);
+        for (&skill, &level) in &self.skills {
+            skills.set_skill(skill, level);
+        }
+        CharSheet::new(self.wild_card, self.race, attributes, skills)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NaturalWeapon {
+    pub name: String,
+    pub damage: DamageValue,
+}
+
+/// Item ids the creature starts with
+#[derive(Deserialize, Debug, Default)]
+pub struct Equipment {
+    #[serde(default)]
+    pub wield: Vec<String>,
+    #[serde(default)]
+    pub wear: Vec<String>,
+}
+
+fn default_pronouns() -> Pronouns {
+    Pronouns::ItIts
+}
+
+fn default_age() -> u8 {
+    1
+}
+
+fn default_ai() -> AI {
+    AI::BasicMonster
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::savage::{Attribute, Skill};
+    use crate::game::{AttrLevel, SkillLevel};
+
+    use super::CreaturePrototype;
+
+    #[test]
+    fn test_char_sheet_from_prototype() {
+        let creature: CreaturePrototype = serde_json::from_str(
+            r#"{
+              "id": "test",
+              "name": "test bug",
+              "race": "bug",
+              "attributes": { "STRENGTH": "D8" },
+              "skills": { "FIGHTING": "D6" }
+            }"#,
+        )
+        .unwrap();
+        let char_sheet = creature.char_sheet();
+        assert_eq!(
+            AttrLevel::D8,
+            char_sheet.attributes.get_attribute(Attribute::Strength)
+        );
+        assert_eq!(
+            AttrLevel::D4,
+            char_sheet.attributes.get_attribute(Attribute::Agility)
+        );
+        assert_eq!(SkillLevel::D6, char_sheet.skills.get_skill(Skill::Fighting));
+        assert_eq!(SkillLevel::None, char_sheet.skills.get_skill(Skill::Notice));
+    }
+}
@@ -1,6 +1,9 @@
 use serde::Deserialize;
 
-use super::{names_pack::NamesPack, ItemPrototype};
+use super::{
+    names_pack::NamesPack, CreaturePrototype, ItemPrototype, LootTable, Prefab, Recipe, SpawnTable,
+    TerrainPrototype,
+};
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Deserialize, Debug)]
@@ -9,7 +12,12 @@ use super::{names_pack::NamesPack, ItemPrototype};
 pub enum DataEntity {
     Item(ItemPrototype),
     NamesPack(NamesPack),
-    // TODO: terrains, creatures, etc.
+    Prefab(Prefab),
+    LootTable(LootTable),
+    Creature(CreaturePrototype),
+    Terrain(TerrainPrototype),
+    SpawnTable(SpawnTable),
+    Recipe(Recipe),
 }
 
 #[cfg(test)]
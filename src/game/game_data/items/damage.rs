@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use rand::seq::IteratorRandom;
+use rand::{seq::IteratorRandom, Rng};
 use serde::{Deserialize, Serialize};
 
 use crate::game::{
@@ -44,6 +44,7 @@ fn damage_types(item: &Item) -> HashSet<DamageType> {
 }
 
 impl DamageValue {
+    #[must_use]
     pub fn zero() -> Self {
         Self {
             damage: Damage {
@@ -61,6 +62,7 @@ impl DamageValue {
         }
     }
 
+    #[must_use]
     pub fn strength(damage_type: DamageType) -> Self {
         Self {
             damage: Damage {
@@ -78,6 +80,7 @@ impl DamageValue {
         }
     }
 
+    #[must_use]
     pub fn simple(dice: DamageDice, damage_type: DamageType) -> Self {
         Self {
             damage: Damage {
@@ -95,6 +98,7 @@ impl DamageValue {
         }
     }
 
+    #[must_use]
     pub fn improvised_melee(item: &Item) -> Self {
         Self {
             damage: Damage {
@@ -123,6 +127,7 @@ impl DamageValue {
         }
     }
 
+    #[must_use]
     pub fn improvised_throw(item: &Item) -> Option<Self> {
         if item.size() == ItemSize::Huge {
             return None;
@@ -138,22 +143,18 @@ impl DamageValue {
         })
     }
 
-    pub fn roll(
+    pub fn roll<R: Rng + ?Sized>(
         &self,
+        rng: &mut R,
         char_sheet: &CharSheet,
         critical: bool,
         explosive: bool,
     ) -> DamageRollResult {
-        let damage_type = self
-            .damage_types
-            .iter()
-            .copied()
-            // TODO: use World's rng instead of thread_rng
-            .choose(&mut rand::rng());
+        let damage_type = self.damage_types.iter().copied().choose(rng);
         if let Some(damage_type) = damage_type {
             DamageRollResult::new(
                 self.damage
-                    .roll(char_sheet, critical, explosive, self.minimum_strength),
+                    .roll(rng, char_sheet, critical, explosive, self.minimum_strength),
                 damage_type,
                 self.penetration,
             )
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::calendar::TICKS_PER_MINUTE;
+
+/// Food and drink, in minutes of hunger and thirst they satisfy
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub struct EdibleValue {
+    #[serde(default)]
+    pub nutrition: u32,
+    #[serde(default)]
+    pub hydration: u32,
+}
+
+impl EdibleValue {
+    pub fn nutrition_ticks(self) -> u32 {
+        self.nutrition * TICKS_PER_MINUTE as u32
+    }
+
+    pub fn hydration_ticks(self) -> u32 {
+        self.hydration * TICKS_PER_MINUTE as u32
+    }
+
+    /// Mostly water, so it's drunk instead of eaten
+    pub fn is_drink(self) -> bool {
+        self.hydration > self.nutrition
+    }
+}
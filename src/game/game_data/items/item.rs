@@ -2,9 +2,11 @@ use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
 
-use crate::assets::Sprite;
+use crate::sprite::Sprite;
 
-use super::{DamageValue, IsAmmoValue, ItemQuality, Material, NeedAmmoValue, WearableValue};
+use super::{
+    DamageValue, EdibleValue, IsAmmoValue, ItemQuality, Material, NeedAmmoValue, WearableValue,
+};
 
 #[derive(Serialize, Deserialize, Debug, Hash, Eq, PartialEq, Copy, Clone, Ord, PartialOrd)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -49,4 +51,10 @@ pub struct ItemPrototype {
     pub is_ammo: Option<IsAmmoValue>,
     #[serde(default)]
     pub stackable: bool,
+    /// Radius of the light around the item, in tiles
+    #[serde(default)]
+    pub emits_light: u8,
+    /// Food or drink
+    #[serde(default)]
+    pub edible: Option<EdibleValue>,
 }
@@ -1,7 +1,6 @@
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
 
-use crate::colors::Colors;
+use crate::colors::{Color, Colors};
 
 #[derive(Serialize, Deserialize, Debug, Hash, Eq, PartialEq, Copy, Clone, Ord, PartialOrd)]
 #[serde(rename_all = "snake_case")]
@@ -23,6 +22,27 @@ pub enum Material {
 }
 
 impl Material {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cloth => "cloth",
+            Self::Wool => "wool",
+            Self::Leather => "leather",
+            Self::Wood => "wood",
+            Self::Stone => "stone",
+            Self::Iron => "iron",
+            Self::Steel => "steel",
+            Self::Obsidian => "obsidian",
+            Self::Demonite => "demonite",
+            Self::LapisLazuli => "lapis lazuli",
+            Self::Bone => "bone",
+            Self::Flesh => "flesh",
+            Self::Plant => "plant",
+            Self::Paper => "paper",
+        }
+    }
+
+    #[must_use]
     pub fn is_hard(self) -> bool {
         matches!(
             self,
@@ -36,6 +56,20 @@ impl Material {
                 | Self::Bone
         )
     }
+
+    /// How fast tools made of this material are working, stone is the usual
+    #[must_use]
+    pub fn tool_speed(self) -> f32 {
+        match self {
+            Self::Iron | Self::Steel => 1.5,
+            Self::Demonite => 2.0,
+            Self::Stone | Self::Obsidian => 1.0,
+            Self::Wood | Self::Bone | Self::LapisLazuli => 0.75,
+            Self::Cloth | Self::Wool | Self::Leather | Self::Flesh | Self::Plant | Self::Paper => {
+                0.5
+            }
+        }
+    }
 }
 
 impl From<Material> for Color {
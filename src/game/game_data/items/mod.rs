@@ -1,5 +1,6 @@
 pub use ammo::{AmmoDamageModifier, AmmoType, IsAmmoValue, NeedAmmoValue};
 pub use damage::DamageValue;
+pub use edible::EdibleValue;
 pub use item::{ItemPrototype, ItemSize};
 pub use material::Material;
 pub use quality::ItemQuality;
@@ -7,6 +8,7 @@ pub use wear::{WearLayer, WearableValue};
 
 mod ammo;
 mod damage;
+mod edible;
 mod item;
 mod material;
 mod quality;
@@ -22,3 +22,17 @@ pub enum ItemQuality {
         for_ammo: HashSet<AmmoType>,
     },
 }
+
+impl ItemQuality {
+    /// What this tool is used for
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Dig => "dig",
+            Self::Butch => "butcher",
+            Self::Cut => "cut",
+            Self::Chop => "chop",
+            Self::Container { .. } => "carry",
+        }
+    }
+}
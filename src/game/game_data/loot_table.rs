@@ -0,0 +1,273 @@
+use rand::{Rng, RngExt};
+use serde::Deserialize;
+
+use crate::game::{map::Biome, GameData, Item, Race};
+
+/// Nested tables deeper than this are ignored, in case some of them refer to each other
+const MAX_DEPTH: u8 = 8;
+
+/// Where the loot is rolled, conditional groups are checked against it
+#[derive(Debug, Default, Copy, Clone)]
+pub struct LootContext {
+    pub biome: Option<Biome>,
+    pub z: i32,
+    pub race: Option<Race>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LootTable {
+    pub id: String,
+    pub groups: Vec<LootGroup>,
+}
+
+impl LootTable {
+    pub fn roll<R: Rng + ?Sized>(&self, context: LootContext, rng: &mut R) -> Vec<Item> {
+        let mut items = Vec::new();
+        self.roll_into(&mut items, context, rng, 0);
+        items
+    }
+
+    fn roll_into<R: Rng + ?Sized>(
+        &self,
+        items: &mut Vec<Item>,
+        context: LootContext,
+        rng: &mut R,
+        depth: u8,
+    ) {
+        if depth > MAX_DEPTH {
+            return;
+        }
+        for group in &self.groups {
+            if !group.condition.as_ref().is_none_or(|c| c.matches(context)) {
+                continue;
+            }
+            if group.chance < 1.0 && !rng.random_bool(group.chance) {
+                continue;
+            }
+            let rolls = group.rolls.roll(rng);
+            for _ in 0..rolls {
+                if let Some(entry) = group.pick(rng) {
+                    entry.roll_into(items, context, rng, depth);
+                }
+            }
+        }
+    }
+}
+
+/// Every roll picks one of the entries by weight
+#[derive(Deserialize, Debug)]
+pub struct LootGroup {
+    pub condition: Option<LootCondition>,
+    /// Chance that the group is rolled at all
+    #[serde(default = "always")]
+    pub chance: f64,
+    #[serde(default = "single")]
+    pub rolls: LootRange,
+    pub entries: Vec<LootEntry>,
+}
+
+impl LootGroup {
+    fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&LootEntry> {
+        let total: u32 = self.entries.iter().map(|e| e.weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.random_range(0..total);
+        self.entries.iter().find(|entry| {
+            if roll < entry.weight {
+                true
+            } else {
+                roll -= entry.weight;
+                false
+            }
+        })
+    }
+}
+
+/// Item with a count, another table, or nothing if both are missing
+#[derive(Deserialize, Debug)]
+pub struct LootEntry {
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    pub item: Option<String>,
+    #[serde(default = "single")]
+    pub count: LootRange,
+    pub table: Option<String>,
+}
+
+impl LootEntry {
+    fn roll_into<R: Rng + ?Sized>(
+        &self,
+        items: &mut Vec<Item>,
+        context: LootContext,
+        rng: &mut R,
+        depth: u8,
+    ) {
+        if let Some(id) = &self.item {
+            let count = self.count.roll(rng);
+            if count == 0 {
+                return;
+            }
+            let item = Item::new(id);
+            if item.proto().stackable && count > 1 {
+                items.push(item.with_stack(count.min(u32::from(u8::MAX)) as u8));
+            } else {
+                items.extend((0..count).map(|_| Item::new(id)));
+            }
+        }
+        if let Some(id) = &self.table {
+            GameData::instance()
+                .get_loot_table(id)
+                .roll_into(items, context, rng, depth + 1);
+        }
+    }
+}
+
+/// Every field that is set should match
+#[derive(Deserialize, Debug, Default)]
+pub struct LootCondition {
+    pub biome: Option<Biome>,
+    pub underground: Option<bool>,
+    pub race: Option<Race>,
+}
+
+impl LootCondition {
+    pub fn matches(&self, context: LootContext) -> bool {
+        self.biome.is_none_or(|b| context.biome == Some(b))
+            && self.underground.is_none_or(|u| u == (context.z < 0))
+            && self.race.is_none_or(|r| context.race == Some(r))
+    }
+}
+
+/// Inclusive `[min, max]` range, checked when the data is loaded
+#[derive(Deserialize, Debug, Copy, Clone)]
+#[serde(try_from = "(u32, u32)")]
+pub struct LootRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl TryFrom<(u32, u32)> for LootRange {
+    type Error = String;
+
+    fn try_from((min, max): (u32, u32)) -> Result<Self, Self::Error> {
+        if min > max {
+            Err(format!(
+                "Invalid range: min {min} is greater than max {max}"
+            ))
+        } else {
+            Ok(Self { min, max })
+        }
+    }
+}
+
+impl LootRange {
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u32 {
+        rng.random_range(self.min..=self.max)
+    }
+}
+
+fn always() -> f64 {
+    1.0
+}
+
+fn single() -> LootRange {
+    LootRange { min: 1, max: 1 }
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::map::Biome;
+    use crate::game::{GameData, WorldRng};
+
+    use super::{LootContext, LootEntry, LootGroup, LootTable};
+
+    const TABLE: &str = r#"
+    {
+      "id": "test",
+      "groups": [
+        {
+          "rolls": [2, 2],
+          "entries": [
+            { "item": "rock", "count": [2, 4], "weight": 3 },
+            { "item": "knife_stone" },
+            { "weight": 1 }
+          ]
+        },
+        {
+          "condition": { "biome": "forest" },
+          "entries": [ { "item": "torch" } ]
+        },
+        {
+          "chance": 0.0,
+          "entries": [ { "item": "axe_god" } ]
+        }
+      ]
+    }
+    "#;
+
+    #[test]
+    fn test_roll_loot() {
+        let table: LootTable = serde_json::from_str(TABLE).unwrap();
+        let mut rng = WorldRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let items = table.roll(LootContext::default(), &mut rng);
+            assert!(items.len() <= 2);
+            for item in &items {
+                assert_ne!("torch", item.proto().id);
+                assert_ne!("axe_god", item.proto().id);
+                if item.proto().id == "rock" {
+                    assert!((2..=4).contains(&item.stack_size()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_ranges() {
+        let entry = r#"{ "item": "rock", "count": [4, 2] }"#;
+        assert!(serde_json::from_str::<LootEntry>(entry).is_err());
+        let group = r#"{ "rolls": [3, 0], "entries": [] }"#;
+        assert!(serde_json::from_str::<LootGroup>(group).is_err());
+    }
+
+    #[test]
+    fn test_conditional_group() {
+        let table: LootTable = serde_json::from_str(TABLE).unwrap();
+        let mut rng = WorldRng::seed_from_u64(42);
+        let context = LootContext {
+            biome: Some(Biome::Forest),
+            ..LootContext::default()
+        };
+        let items = table.roll(context, &mut rng);
+        assert!(items.iter().any(|i| i.proto().id == "torch"));
+    }
+
+    #[test]
+    fn test_nested_tables_from_data() {
+        let data = GameData::instance();
+        let mut rng = WorldRng::seed_from_u64(42);
+        let items = data
+            .get_loot_table("huge_boulder_smash")
+            .roll(LootContext::default(), &mut rng);
+        assert!(items.iter().any(|i| i.proto().id == "rock"));
+        for table in data.loot_tables.values() {
+            for group in &table.groups {
+                for entry in &group.entries {
+                    if let Some(item) = &entry.item {
+                        assert!(data.items.contains_key(item), "No item {item}");
+                    }
+                    if let Some(nested) = &entry.table {
+                        assert!(data.loot_tables.contains_key(nested), "No table {nested}");
+                    }
+                }
+            }
+        }
+    }
+}
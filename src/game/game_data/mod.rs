@@ -1,21 +1,33 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
 
 use once_cell::sync::OnceCell;
 
+pub use creature::{CreaturePrototype, Equipment, NaturalWeapon};
 use data_entity::DataEntity;
 pub use items::{
-    AmmoType, DamageValue, IsAmmoValue, ItemPrototype, ItemQuality, ItemSize, Material,
-    NeedAmmoValue, WearLayer,
+    AmmoType, DamageValue, EdibleValue, IsAmmoValue, ItemPrototype, ItemQuality, ItemSize,
+    Material, NeedAmmoValue, WearLayer,
 };
+pub use loot_table::{LootCondition, LootContext, LootEntry, LootGroup, LootRange, LootTable};
+pub use prefab::{Prefab, PrefabTile};
+pub use recipe::{Ingredient, Recipe, RecipeOutput, SkillCheck};
+pub use spawn_table::{SpawnEntry, SpawnTable};
+pub use terrain::{TerrainId, TerrainPrototype, TerrainSmash, TerrainStep, TerrainYield};
 
 use crate::game::races::{Race, Sex};
 
+mod creature;
 mod data_entity;
 mod items;
+mod loot_table;
 mod names_pack;
+mod prefab;
+mod recipe;
+mod spawn_table;
+mod terrain;
 
 const PATH: &str = "data";
 static INSTANCE: OnceCell<GameData> = OnceCell::new();
@@ -24,6 +36,14 @@ static INSTANCE: OnceCell<GameData> = OnceCell::new();
 pub struct GameData {
     pub names: HashMap<Race, HashMap<Sex, Vec<String>>>,
     pub items: HashMap<String, ItemPrototype>,
+    /// Ordered by id, so world generation doesn't depend on the order of loading
+    pub prefabs: BTreeMap<String, Prefab>,
+    pub loot_tables: HashMap<String, LootTable>,
+    pub creatures: HashMap<String, CreaturePrototype>,
+    pub terrains: HashMap<String, TerrainPrototype>,
+    pub spawn_tables: HashMap<String, SpawnTable>,
+    /// Ordered by id, so the crafting list is always the same
+    pub recipes: BTreeMap<String, Recipe>,
 }
 
 impl GameData {
@@ -31,6 +51,10 @@ impl GameData {
         INSTANCE.get_or_init(Self::load)
     }
 
+    /// # Panics
+    ///
+    /// Panics if there is no item prototype with such id
+    #[must_use]
     pub fn get_item_prototype(&self, id: &str) -> &ItemPrototype {
         if let Some(proto) = self.items.get(id) {
             proto
@@ -39,12 +63,60 @@ impl GameData {
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if there is no creature with such id
+    #[must_use]
+    pub fn get_creature(&self, id: &str) -> &CreaturePrototype {
+        if let Some(creature) = self.creatures.get(id) {
+            creature
+        } else {
+            panic!("No creature with id: {id}");
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if there is no terrain with such id
+    #[must_use]
+    pub fn get_terrain(&self, id: &str) -> &TerrainPrototype {
+        if let Some(terrain) = self.terrains.get(id) {
+            terrain
+        } else {
+            panic!("No terrain with id: {id}");
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if there is no loot table with such id
+    #[must_use]
+    pub fn get_loot_table(&self, id: &str) -> &LootTable {
+        if let Some(table) = self.loot_tables.get(id) {
+            table
+        } else {
+            panic!("No loot table with id: {id}");
+        }
+    }
+
+    /// Recipe by id, `None` if it was removed from the data since the action was saved
+    #[must_use]
+    pub fn get_recipe(&self, id: &str) -> Option<&Recipe> {
+        self.recipes.get(id)
+    }
+
     fn load() -> Self {
         let mut data = Self {
             names: Race::iter()
                 .map(|r| (r, Sex::iter().map(|s| (s, Vec::new())).collect()))
                 .collect(),
             items: HashMap::with_capacity(100),
+            prefabs: BTreeMap::new(),
+            loot_tables: HashMap::new(),
+            creatures: HashMap::new(),
+            terrains: HashMap::new(),
+            spawn_tables: HashMap::new(),
+            recipes: BTreeMap::new(),
         };
 
         data.load_dir(&PathBuf::from(PATH));
@@ -88,6 +160,24 @@ impl GameData {
             DataEntity::Item(item) => {
                 self.items.insert(item.id.clone(), item);
             }
+            DataEntity::Creature(creature) => {
+                self.creatures.insert(creature.id.clone(), creature);
+            }
+            DataEntity::Terrain(terrain) => {
+                self.terrains.insert(terrain.id.clone(), terrain);
+            }
+            DataEntity::SpawnTable(table) => {
+                self.spawn_tables.insert(table.id.clone(), table);
+            }
+            DataEntity::LootTable(table) => {
+                self.loot_tables.insert(table.id.clone(), table);
+            }
+            DataEntity::Recipe(recipe) => {
+                self.recipes.insert(recipe.id.clone(), recipe);
+            }
+            DataEntity::Prefab(prefab) => {
+                self.prefabs.insert(prefab.id.clone(), prefab);
+            }
             DataEntity::NamesPack(name_pack) => {
                 for (race, value) in name_pack.names {
                     for (sex, names) in value {
@@ -108,7 +198,7 @@ impl GameData {
 mod tests {
     use crate::game::races::{Race, Sex};
 
-    use super::GameData;
+    use super::{GameData, TerrainId};
 
     #[test]
     fn data_load() {
@@ -131,5 +221,92 @@ mod tests {
             .contains(&"Dragan".to_string()));
         assert!(data.items.len() > 0);
         assert!(data.items.contains_key("book"));
+        assert!(data.prefabs.contains_key("ruins"));
+        for prefab in data.prefabs.values() {
+            for (_, tile) in prefab.tiles() {
+                for item in &tile.items {
+                    assert!(data.items.contains_key(item), "No item {item}");
+                }
+                if let Some(TerrainId::Data(terrain)) = &tile.terrain {
+                    assert!(data.terrains.contains_key(terrain), "No terrain {terrain}");
+                }
+                if let Some(creature) = &tile.creature {
+                    assert!(
+                        data.creatures.contains_key(creature),
+                        "No creature {creature}"
+                    );
+                }
+            }
+        }
+        for id in ["mud", "dirt", "grass", "chest"] {
+            assert!(data.terrains.contains_key(id), "No terrain {id}");
+        }
+        for terrain in data.terrains.values() {
+            assert!(!terrain.sprites.is_empty(), "No sprites for {}", terrain.id);
+            for id in terrain.opens_to.iter().chain(&terrain.closes_to) {
+                assert!(data.terrains.contains_key(id), "No terrain {id}");
+            }
+            let results = terrain
+                .smash
+                .iter()
+                .map(|smash| &smash.result)
+                .chain(terrain.on_step.iter().map(|step| &step.result))
+                .chain(
+                    terrain
+                        .dig
+                        .iter()
+                        .chain(&terrain.chop)
+                        .map(|work| &work.result),
+                );
+            for result in results {
+                if let TerrainId::Data(id) = result {
+                    assert!(data.terrains.contains_key(id), "No terrain {id}");
+                }
+            }
+        }
+        for table in data.spawn_tables.values() {
+            for entry in &table.entries {
+                assert!(
+                    data.creatures.contains_key(&entry.creature),
+                    "No creature {}",
+                    entry.creature
+                );
+            }
+        }
+        assert!(!data.recipes.is_empty());
+        for recipe in data.recipes.values() {
+            for ingredient in &recipe.ingredients {
+                assert!(
+                    ingredient.item.is_some() || ingredient.material.is_some(),
+                    "Empty ingredient in {}",
+                    recipe.id
+                );
+                if let Some(item) = &ingredient.item {
+                    assert!(data.items.contains_key(item), "No item {item}");
+                }
+            }
+            assert!(!recipe.outputs.is_empty(), "No outputs in {}", recipe.id);
+            for output in &recipe.outputs {
+                assert!(
+                    data.items.contains_key(&output.item),
+                    "No item {}",
+                    output.item
+                );
+            }
+        }
+        assert!(data.creatures.contains_key("green_bug"));
+        for creature in data.creatures.values() {
+            for item in creature
+                .equipment
+                .wield
+                .iter()
+                .chain(&creature.equipment.wear)
+            {
+                assert!(data.items.contains_key(item), "No item {item}");
+            }
+            if let Some(loot) = &creature.loot {
+                assert!(data.loot_tables.contains_key(loot), "No loot table {loot}");
+            }
+        }
     }
 }
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use roguemetry::Point;
+use serde::Deserialize;
+
+use crate::game::map::Biome;
+
+use super::TerrainId;
+
+/// Structure stamped into the generated world: ASCII grid and what every character means
+#[derive(Deserialize, Debug)]
+pub struct Prefab {
+    pub id: String,
+    /// Biomes where it can be placed
+    pub biomes: Vec<Biome>,
+    /// Chance to be placed in the chunk of the suitable biome
+    pub chance: f64,
+    pub grid: Vec<String>,
+    /// Characters that are not in the legend leave generated tiles as they are
+    pub legend: HashMap<char, PrefabTile>,
+}
+
+impl Prefab {
+    #[must_use]
+    pub fn width(&self) -> i32 {
+        self.grid
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0) as i32
+    }
+
+    #[must_use]
+    pub fn height(&self) -> i32 {
+        self.grid.len() as i32
+    }
+
+    /// Tiles of the prefab with positions relative to its left top corner
+    pub fn tiles(&self) -> impl Iterator<Item = (Point, &PrefabTile)> {
+        self.grid.iter().enumerate().flat_map(move |(y, row)| {
+            row.chars().enumerate().filter_map(move |(x, c)| {
+                self.legend
+                    .get(&c)
+                    .map(|tile| (Point::new(x as i32, y as i32), tile))
+            })
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PrefabTile {
+    pub terrain: Option<TerrainId>,
+    /// Item ids, they are put inside if the terrain is a container
+    #[serde(default)]
+    pub items: Vec<String>,
+    /// Loot table rolled for more items
+    pub loot: Option<String>,
+    /// Id of the creature living here
+    pub creature: Option<String>,
+}
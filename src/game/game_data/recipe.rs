@@ -0,0 +1,129 @@
+use serde::Deserialize;
+
+use crate::game::{GameData, Item, ItemQuality, Material, Skill};
+
+/// How to make new items from the other ones
+#[derive(Deserialize, Debug)]
+pub struct Recipe {
+    pub id: String,
+    /// All of them are spent on crafting
+    pub ingredients: Vec<Ingredient>,
+    /// Qualities of wielded tools, they are not spent
+    #[serde(default)]
+    pub tools: Vec<ItemQuality>,
+    /// No roll is needed if there is no check
+    pub skill: Option<SkillCheck>,
+    /// Ticks of work
+    pub time: u32,
+    pub outputs: Vec<RecipeOutput>,
+}
+
+impl Recipe {
+    /// Name of the first output, recipes are shown to the player by it
+    #[must_use]
+    pub fn name(&self) -> String {
+        let Some(output) = self.outputs.first() else {
+            return self.id.clone();
+        };
+        let name = &GameData::instance().get_item_prototype(&output.item).name;
+        if output.count > 1 {
+            format!("{name} x{}", output.count)
+        } else {
+            name.clone()
+        }
+    }
+
+    #[must_use]
+    pub fn make_outputs(&self) -> Vec<Item> {
+        let mut items = Vec::new();
+        for output in &self.outputs {
+            let item = Item::new(&output.item);
+            if item.proto().stackable && output.count > 1 {
+                items.push(item.with_stack(output.count));
+            } else {
+                items.extend((0..output.count).map(|_| Item::new(&output.item)));
+            }
+        }
+        items
+    }
+}
+
+/// Specific item or any item made of the material
+#[derive(Deserialize, Debug)]
+pub struct Ingredient {
+    pub item: Option<String>,
+    pub material: Option<Material>,
+    #[serde(default = "single")]
+    pub count: u8,
+}
+
+impl Ingredient {
+    pub fn name(&self) -> String {
+        match (&self.item, self.material) {
+            (Some(id), _) => GameData::instance().get_item_prototype(id).name.clone(),
+            (None, Some(material)) => format!("something made of {}", material.name()),
+            (None, None) => "nothing".to_string(),
+        }
+    }
+
+    pub fn matches(&self, item: &Item) -> bool {
+        let proto = item.proto();
+        self.item.as_ref().is_none_or(|id| proto.id == *id)
+            && self
+                .material
+                .is_none_or(|material| proto.materials.contains(&material))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SkillCheck {
+    pub skill: Skill,
+    #[serde(default)]
+    pub modifier: i8,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RecipeOutput {
+    pub item: String,
+    #[serde(default = "single")]
+    pub count: u8,
+}
+
+fn single() -> u8 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::map::items::helpers::{ROCK, STONE_KNIFE, WOODEN_SPLINTER};
+    use crate::game::Item;
+
+    use super::Recipe;
+
+    #[test]
+    fn test_ingredients() {
+        let recipe: Recipe = serde_json::from_str(
+            r#"{
+              "id": "test",
+              "ingredients": [ { "item": "rock" }, { "material": "wood", "count": 2 } ],
+              "tools": ["CUT"],
+              "skill": { "skill": "SURVIVAL", "modifier": -1 },
+              "time": 100,
+              "outputs": [ { "item": "arrow_stone", "count": 2 } ]
+            }"#,
+        )
+        .unwrap();
+        assert!(recipe.ingredients[0].matches(&Item::new(ROCK)));
+        assert!(!recipe.ingredients[0].matches(&Item::new(WOODEN_SPLINTER)));
+        assert!(recipe.ingredients[1].matches(&Item::new(WOODEN_SPLINTER)));
+        assert!(recipe.ingredients[1].matches(&Item::new(STONE_KNIFE)));
+        assert!(!recipe.ingredients[1].matches(&Item::new(ROCK)));
+        assert_eq!(recipe.ingredients[1].count, 2);
+        assert_eq!(recipe.skill.as_ref().unwrap().modifier, -1);
+        assert_eq!(recipe.name(), "stone arrow x2");
+
+        let outputs = recipe.make_outputs();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].stack_size(), 2);
+    }
+}
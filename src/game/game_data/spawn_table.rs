@@ -0,0 +1,106 @@
+use rand::{Rng, RngExt};
+use serde::Deserialize;
+
+use super::LootRange;
+
+/// Wildlife of the biome: a group of creatures rolled for every generated chunk
+#[derive(Deserialize, Debug)]
+pub struct SpawnTable {
+    pub id: String,
+    /// Chance that the chunk gets a group at all
+    #[serde(default = "always")]
+    pub chance: f64,
+    pub entries: Vec<SpawnEntry>,
+}
+
+impl SpawnTable {
+    /// Creature ids of the group, empty if there is no luck
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<&str> {
+        if self.chance < 1.0 && !rng.random_bool(self.chance) {
+            return Vec::new();
+        }
+        let total: u32 = self.entries.iter().map(|e| e.weight).sum();
+        if total == 0 {
+            return Vec::new();
+        }
+        let mut roll = rng.random_range(0..total);
+        let Some(entry) = self.entries.iter().find(|entry| {
+            if roll < entry.weight {
+                true
+            } else {
+                roll -= entry.weight;
+                false
+            }
+        }) else {
+            return Vec::new();
+        };
+        vec![entry.creature.as_str(); entry.count.roll(rng) as usize]
+    }
+}
+
+/// Group of the same creatures, picked by weight
+#[derive(Deserialize, Debug)]
+pub struct SpawnEntry {
+    pub creature: String,
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    #[serde(default = "single")]
+    pub count: LootRange,
+}
+
+fn always() -> f64 {
+    1.0
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+fn single() -> LootRange {
+    LootRange { min: 1, max: 1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{prelude::StdRng, SeedableRng};
+
+    use super::SpawnTable;
+
+    #[test]
+    fn test_roll_group() {
+        let table: SpawnTable = serde_json::from_str(
+            r#"{
+              "id": "test",
+              "entries": [
+                { "creature": "green_bug", "count": [2, 3] },
+                { "creature": "scavenger", "weight": 0 }
+              ]
+            }"#,
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..10 {
+            let group = table.roll(&mut rng);
+            assert!((2..=3).contains(&group.len()));
+            assert!(group.iter().all(|&c| c == "green_bug"));
+        }
+    }
+
+    #[test]
+    fn test_no_luck() {
+        let table: SpawnTable = serde_json::from_str(
+            r#"{ "id": "test", "chance": 0.0, "entries": [ { "creature": "green_bug" } ] }"#,
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        assert!(table.roll(&mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_count() {
+        let table = serde_json::from_str::<SpawnTable>(
+            r#"{ "id": "test", "entries": [ { "creature": "green_bug", "count": [3, 2] } ] }"#,
+        );
+        assert!(table.is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::colors::Color;
+use crate::game::{
+    map::terrains::{DataTerrain, Pit},
+    Item, Terrain, TerrainInteractAction,
+};
+use crate::sprite::Sprite;
+
+use super::GameData;
+
+/// Terrain that needs no Rust code, see `DataTerrain`
+#[derive(Deserialize, Debug)]
+pub struct TerrainPrototype {
+    pub id: String,
+    pub name: String,
+    /// Sprites of the variants, one is picked randomly when the terrain is created
+    pub sprites: Vec<Sprite>,
+    /// RGB
+    pub color: Option<[u8; 3]>,
+    #[serde(default = "default_transparent")]
+    pub transparent: bool,
+    /// Ticks to pass, impassable if not set
+    pub passage: Option<u32>,
+    /// Radius of the light around, in tiles
+    #[serde(default)]
+    pub emits_light: u8,
+    /// Text to read, like on a sign
+    pub text: Option<String>,
+    /// Items are kept inside, like in a chest
+    #[serde(default)]
+    pub container: bool,
+    /// Id of the terrain it becomes when opened, items inside are taken out
+    pub opens_to: Option<String>,
+    /// Id of the terrain it becomes when closed, items are put inside
+    pub closes_to: Option<String>,
+    pub smash: Option<TerrainSmash>,
+    pub on_step: Option<TerrainStep>,
+    /// What is left after digging, can't be dug if not set
+    pub dig: Option<TerrainYield>,
+    /// What is left after chopping, can't be chopped if not set
+    pub chop: Option<TerrainYield>,
+    /// Penalty to Climbing rolls to get on top, can't be climbed if not set
+    pub climb: Option<i8>,
+    /// Can't be crossed without Swimming rolls
+    #[serde(default)]
+    pub deep_water: bool,
+    /// Other actions, like dropping items or going down
+    #[serde(default)]
+    pub actions: Vec<TerrainInteractAction>,
+}
+
+fn default_transparent() -> bool {
+    true
+}
+
+impl TerrainPrototype {
+    #[must_use]
+    pub fn color(&self) -> Option<Color> {
+        self.color.map(|[r, g, b]| {
+            Color::rgb(
+                f32::from(r) / 255.0,
+                f32::from(g) / 255.0,
+                f32::from(b) / 255.0,
+            )
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TerrainSmash {
+    /// Minimal damage to break it
+    pub toughness: u8,
+    /// What is left after smashing
+    pub result: TerrainId,
+    /// Loot table of the dropped items, besides the items inside
+    pub loot: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TerrainStep {
+    pub chance: f64,
+    pub result: TerrainId,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TerrainYield {
+    pub result: TerrainId,
+    /// Loot table of the dug out or chopped items
+    pub loot: Option<String>,
+    /// Ticks it takes, the usual time of the action if not set
+    pub time: Option<u32>,
+}
+
+/// Built-in terrain kind or id of the terrain from the data
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TerrainId {
+    Pit,
+    #[serde(untagged)]
+    Data(String),
+}
+
+impl TerrainId {
+    /// Terrain with a random variant, items are put inside if it's a container
+    pub fn terrain<R: Rng + ?Sized>(&self, items: Vec<Item>, rng: &mut R) -> Terrain {
+        match self {
+            TerrainId::Pit => Pit::new().into(),
+            TerrainId::Data(id) => DataTerrain::new(id, rng).with_items(items).into(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_container(&self) -> bool {
+        match self {
+            TerrainId::Pit => false,
+            TerrainId::Data(id) => GameData::instance().get_terrain(id).container,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TerrainId;
+
+    #[test]
+    fn test_terrain_ids() {
+        let ids: Vec<TerrainId> =
+            serde_json::from_str(r#"["pit", "huge_boulder", "mud"]"#).unwrap();
+        assert_eq!(TerrainId::Pit, ids[0]);
+        assert_eq!(TerrainId::Data("huge_boulder".to_string()), ids[1]);
+        assert_eq!(TerrainId::Data("mud".to_string()), ids[2]);
+    }
+}
@@ -0,0 +1,73 @@
+use super::{DateTime, WeatherKind};
+
+/// Even a moonless night isn't completely dark
+pub const STARLIGHT: f32 = 0.1;
+/// Neighbour tiles can be seen in the pitch darkness
+pub const DARK_VISION: f32 = 1.5;
+/// Light sources that far from the vision range can't light anything in it
+pub const MAX_LIGHT_RADIUS: i32 = 10;
+
+/// Light level outside, from `STARLIGHT` to 1.0
+#[must_use]
+pub fn ambient_light(time: DateTime, weather: WeatherKind) -> f32 {
+    (time.daylight() * weather.sunlight()).max(STARLIGHT)
+}
+
+/// How far one can see in a clear day
+#[must_use]
+pub fn vision_range(sight_range: u32, weather: WeatherKind) -> u32 {
+    (sight_range as f32 * weather.visibility()).round() as u32
+}
+
+/// Light from the source is full in its radius and fades out on the edge
+#[must_use]
+pub fn light_from_source(square_distance: u32, radius: u8) -> f32 {
+    let distance = (square_distance as f32).sqrt();
+    (f32::from(radius) + 1.0 - distance).clamp(0.0, 1.0)
+}
+
+/// Tile on the distance is seen if it's lit enough
+#[must_use]
+pub fn is_lit_enough(square_distance: u32, vision_range: u32, light: f32) -> bool {
+    let range = (vision_range as f32 * light).max(DARK_VISION);
+    square_distance as f32 <= range * range
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::super::{calendar::TICKS_PER_HOUR, DateTime, WeatherKind};
+    use super::{ambient_light, is_lit_enough, light_from_source, vision_range, STARLIGHT};
+
+    #[test_case(0, WeatherKind::Clear, 1.0)]
+    #[test_case(0, WeatherKind::Rain, 0.6)]
+    #[test_case(14, WeatherKind::Clear, STARLIGHT)]
+    #[test_case(14, WeatherKind::Fog, STARLIGHT)]
+    fn test_ambient_light(hours_passed: u128, weather: WeatherKind, expected: f32) {
+        let time = DateTime::from_tick(hours_passed * TICKS_PER_HOUR);
+        assert!((ambient_light(time, weather) - expected).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_night_vision() {
+        let range = vision_range(30, WeatherKind::Clear);
+        assert!(is_lit_enough(20 * 20, range, 1.0));
+        assert!(!is_lit_enough(20 * 20, range, STARLIGHT));
+        assert!(is_lit_enough(3 * 3, range, STARLIGHT));
+        assert!(is_lit_enough(2, range, 0.0));
+    }
+
+    #[test_case(0, 6, 1.0)]
+    #[test_case(36, 6, 1.0)]
+    #[test_case(49, 6, 0.0)]
+    #[test_case(2, 0, 0.0)]
+    fn test_light_from_source(square_distance: u32, radius: u8, expected: f32) {
+        assert!((light_from_source(square_distance, radius) - expected).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_fog_lowers_vision_range() {
+        assert!(vision_range(30, WeatherKind::Fog) < vision_range(30, WeatherKind::Rain));
+    }
+}
@@ -1,6 +1,4 @@
-use tetra::graphics::Color;
-
-use crate::colors::Colors;
+use crate::colors::{Color, Colors};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
 pub enum LogCategory {
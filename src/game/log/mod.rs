@@ -14,6 +14,7 @@ pub struct Log {
 }
 
 impl Log {
+    #[must_use]
     pub fn new() -> Self {
         Self {
             events: Vec::new(),
@@ -2,12 +2,61 @@ use std::hash::{Hash, Hasher};
 
 use arrayvec::ArrayVec;
 
+use roguemetry::Point;
+
 use super::{ChunkPos, Tile};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[serde(try_from = "ChunkData")]
 pub struct Chunk {
     pub pos: ChunkPos,
     pub surface: ArrayVec<Tile, { Chunk::USIZE }>,
+    /// Creatures of the chunk, wildlife waits here until the chunk is in the bubble and there is room
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spawns: Vec<Spawn>,
+}
+
+/// Creature waiting to be added to the world
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Spawn {
+    #[serde(rename = "p")]
+    pub pos: Point,
+    pub z: i32,
+    /// Creature id
+    #[serde(rename = "c")]
+    pub creature: String,
+    /// Wildlife is limited by the population cap, prefab dwellers are not
+    #[serde(rename = "w")]
+    pub wild: bool,
+}
+
+/// Tiles are deserialized to the heap first, a whole chunk is too big for the stack
+#[derive(serde::Deserialize)]
+struct ChunkData {
+    pos: ChunkPos,
+    surface: Vec<Tile>,
+    #[serde(default)]
+    spawns: Vec<Spawn>,
+}
+
+impl TryFrom<ChunkData> for Chunk {
+    type Error = String;
+
+    fn try_from(data: ChunkData) -> Result<Self, Self::Error> {
+        if data.surface.len() != Chunk::USIZE {
+            return Err(format!(
+                "Chunk {:?} has {} tiles instead of {}",
+                data.pos,
+                data.surface.len(),
+                Chunk::USIZE
+            ));
+        }
+        Ok(Self {
+            pos: data.pos,
+            surface: data.surface.into_iter().collect(),
+            spawns: data.spawns,
+        })
+    }
 }
 
 impl Chunk {
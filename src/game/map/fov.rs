@@ -12,6 +12,7 @@ pub struct Fov {
 }
 
 impl Fov {
+    #[must_use]
     pub fn new() -> Self {
         Self {
             visible: HashSet::default(),
@@ -22,6 +23,7 @@ impl Fov {
         self.visible = set;
     }
 
+    #[must_use]
     pub fn visible(&self) -> &HashSet<Point> {
         &self.visible
     }
@@ -37,6 +39,7 @@ pub trait FovMap {
     fn is_transparent(&self, point: Point) -> bool;
 }
 
+#[must_use]
 pub fn field_of_view_set(center: Point, range: i32, fov_check: &Map) -> HashSet<Point> {
     /* N, NE, E, SE, S, SW, W, NW */
     const SECTORS: [(i32, i32); 8] = [
@@ -25,14 +25,17 @@ impl Container {
         }
     }
 
+    #[must_use]
     pub fn volume_used(&self) -> u8 {
         self.items.len() as u8
     }
 
+    #[must_use]
     pub fn free_volume(&self) -> u8 {
         self.max_volume - self.volume_used()
     }
 
+    #[must_use]
     pub fn is_for_ammo(&self) -> bool {
         !self.for_ammo.is_empty()
     }
@@ -2,10 +2,10 @@
 
 use std::collections::HashSet;
 
-use crate::assets::Sprite;
 use crate::colors::Colors;
 use crate::game::units::Appearance;
 use crate::game::{Avatar, ItemPrototype, ItemSize, Material};
+use crate::sprite::Sprite;
 
 use super::Item;
 
@@ -124,7 +124,7 @@ pub const ROCK: &str = "rock";
 // pub const METAL_CHUNK: &str = "metal_chunk";
 // pub const SHARP_METAL_CHUNK: &str = "sharp_metal_chunk";
 // pub const METAL_SHOT: &str = "metal_shot";
-// pub const DEMONIC_CHUNK: &str = "demonic_chunk";
+pub const DEMONIC_CHUNK: &str = "demonic_chunk";
 // pub const SHARP_DEMONIC_CHUNK: &str = "sharp_demonic_chunk";
 // pub const DEMONIC_SHOT: &str = "demonic_shot";
 pub const OBSIDIAN_SHARD: &str = "shard_obsidian";
@@ -132,8 +132,13 @@ pub const OBSIDIAN_SHARD: &str = "shard_obsidian";
 pub const CORPSE: &str = "corpse";
 pub const BOOK: &str = "book";
 pub const WOODEN_SPLINTER: &str = "wooden_splinter";
-// pub const FLESH_CHUNK: &str = "flesh_chunk";
-// pub const BONE: &str = "bone";
+pub const LOG: &str = "log";
+pub const BRANCH: &str = "branch";
+pub const TORCH: &str = "torch";
+pub const MEAT: &str = "meat";
+pub const BONE: &str = "bone";
+pub const HIDE: &str = "hide";
+pub const CHITIN: &str = "chitin";
 pub const LAZULI: &str = "lazuli";
 
 // pub const WOODEN_SHOVEL: &str = "shovel_wood";
@@ -150,6 +155,7 @@ pub fn book_debug() -> Item {
         .with_readable("Lore Of The Midia")
 }
 
+#[must_use]
 pub fn dead_body(appearance: &Appearance) -> Item {
     let body = Item::custom(ItemPrototype {
         id: CORPSE.to_string(),
@@ -167,7 +173,10 @@ pub fn dead_body(appearance: &Appearance) -> Item {
         need_ammo: None,
         color_from_material: Some(Material::Flesh),
         stackable: false,
-    });
+        emits_light: 0,
+        edible: None,
+    })
+    .with_corpse_of(appearance.race);
     if let Some(color) = appearance.body_color {
         body.with_colored(color)
     } else {
@@ -182,6 +191,7 @@ mod tests {
     use crate::game::traits::{LooksLike, Name};
     use crate::game::units::tests::helpers::tester_girl;
     use crate::game::units::Player;
+    use crate::game::Race;
 
     use super::*;
 
@@ -193,6 +203,7 @@ mod tests {
         assert_eq!(body.looks_like(), Sprite::Corpse);
         assert_eq!(body.size(), ItemSize::Huge);
         assert_eq!(body.proto().color_from_material, Some(Material::Flesh));
-        assert_eq!(body.color(), unit.view().fg().unwrap())
+        assert_eq!(body.color(), unit.view().fg().unwrap());
+        assert_eq!(body.corpse_of(), Some(Race::Gazan));
     }
 }
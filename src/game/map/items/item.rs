@@ -1,12 +1,12 @@
 use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
 
-use crate::assets::Sprite;
-use crate::game::game_data::{AmmoType, NeedAmmoValue};
+use crate::colors::{Color, Colors};
+use crate::game::game_data::{AmmoType, EdibleValue, NeedAmmoValue};
 use crate::game::traits::{LooksLike, Name};
-use crate::game::{AttackType, DamageValue, GameData, ItemPrototype, ItemQuality, ItemSize};
+use crate::game::{AttackType, DamageValue, GameData, ItemPrototype, ItemQuality, ItemSize, Race};
+use crate::sprite::Sprite;
 
 use super::container::Container;
 
@@ -31,6 +31,8 @@ pub struct Item {
     container: Option<Container>,
     #[serde(default, rename = "q")]
     stack: Option<u8>,
+    #[serde(default, rename = "b")]
+    corpse_of: Option<Race>,
 }
 
 impl Item {
@@ -45,6 +47,7 @@ impl Item {
             looks_like: None,
             container: None,
             stack: None,
+            corpse_of: None,
         };
         if let Some(material) = item.proto().color_from_material {
             item.colored = Some(material.into());
@@ -77,9 +80,11 @@ impl Item {
             container: None,
             custom_proto: Some(proto),
             stack: None,
+            corpse_of: None,
         }
     }
 
+    #[must_use]
     pub fn proto(&self) -> &ItemPrototype {
         if let Some(custom_proto) = &self.custom_proto {
             custom_proto
@@ -89,26 +94,31 @@ impl Item {
         }
     }
 
+    #[must_use]
     pub fn with_named(mut self, name: impl Into<String>) -> Self {
         self.named = Some(name.into());
         self
     }
 
+    #[must_use]
     pub fn with_colored(mut self, color: impl Into<Color>) -> Self {
         self.colored = Some(color.into());
         self
     }
 
+    #[must_use]
     pub fn with_readable(mut self, text: impl Into<String>) -> Self {
         self.readable = Some(text.into());
         self
     }
 
+    #[must_use]
     pub fn with_looks_like(mut self, looks_like: impl Into<Sprite>) -> Self {
         self.looks_like = Some(looks_like.into());
         self
     }
 
+    #[must_use]
     pub fn with_container(
         mut self,
         items: impl Into<Vec<Item>>,
@@ -119,6 +129,7 @@ impl Item {
         self
     }
 
+    #[must_use]
     pub fn with_items_inside(mut self, items: impl Into<Vec<Item>>) -> Self {
         if let Some(container) = &mut self.container {
             container.push_items(items);
@@ -129,24 +140,43 @@ impl Item {
         self
     }
 
+    #[must_use]
+    pub fn with_corpse_of(mut self, race: Race) -> Self {
+        self.corpse_of = Some(race);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the item prototype is not stackable
+    #[must_use]
     pub fn with_stack(mut self, count: u8) -> Self {
         assert!(self.proto().stackable, "Trying to stack non-stackable item");
         self.stack = Some(count);
         self
     }
 
+    #[must_use]
     pub fn color(&self) -> Color {
         if let &Some(color) = &self.colored {
             return color;
         }
 
-        Color::WHITE
+        Colors::WHITE
     }
 
+    #[must_use]
     pub fn read(&self) -> Option<&str> {
         self.readable.as_deref()
     }
 
+    /// Race of the dead body, `None` if it's not a corpse
+    #[must_use]
+    pub fn corpse_of(&self) -> Option<Race> {
+        self.corpse_of
+    }
+
+    #[must_use]
     pub fn container(&self) -> Option<&Container> {
         self.container.as_ref()
     }
@@ -155,38 +185,66 @@ impl Item {
         self.container.as_mut()
     }
 
+    #[must_use]
     pub fn qualities(&self) -> &Vec<ItemQuality> {
         &self.proto().qualities
     }
 
+    /// Speed of work with this tool, the best of its materials
+    pub fn tool_speed(&self) -> f32 {
+        self.proto()
+            .materials
+            .iter()
+            .map(|material| material.tool_speed())
+            .fold(0.5, f32::max)
+    }
+
+    #[must_use]
     pub fn size(&self) -> ItemSize {
         self.proto().size
     }
 
+    #[must_use]
     pub fn is_two_handed(&self) -> bool {
         self.proto().two_handed || self.size() > ItemSize::Medium
     }
 
+    #[must_use]
     pub fn is_wearable(&self) -> bool {
         self.proto().wearable.is_some()
     }
 
+    #[must_use]
     pub fn armor(&self) -> u8 {
         self.proto().wearable.as_ref().map_or(0, |w| w.armor)
     }
 
+    #[must_use]
+    pub fn emits_light(&self) -> u8 {
+        self.proto().emits_light
+    }
+
+    #[must_use]
+    pub fn edible(&self) -> Option<EdibleValue> {
+        self.proto().edible
+    }
+
+    #[must_use]
     pub fn is_readable(&self) -> bool {
         self.readable.is_some()
     }
 
+    #[must_use]
     pub fn is_container(&self) -> bool {
         self.container.is_some()
     }
 
+    #[must_use]
     pub fn is_stack(&self) -> bool {
         self.stack.is_some()
     }
 
+    #[must_use]
     pub fn stack_size(&self) -> u8 {
         self.stack.unwrap_or(1)
     }
@@ -195,6 +253,12 @@ impl Item {
         self.stack = Some(count + self.stack_size());
     }
 
+    /// Caller should remove the item itself if the whole stack is spent
+    pub fn decrease_stack(&mut self, count: u8) {
+        let left = self.stack_size().saturating_sub(count);
+        self.stack = if left > 1 { Some(left) } else { None };
+    }
+
     pub fn pop_from_stack(&mut self) -> Option<Item> {
         if let Some(stack) = &mut self.stack {
             if *stack > 0 {
@@ -211,6 +275,7 @@ impl Item {
         None
     }
 
+    #[must_use]
     pub fn drop_time(&self) -> f32 {
         match self.size() {
             ItemSize::Huge => 10.0,
@@ -218,6 +283,7 @@ impl Item {
         }
     }
 
+    #[must_use]
     pub fn wield_time(&self) -> f32 {
         match self.size() {
             ItemSize::Tiny => 1.0,
@@ -228,6 +294,7 @@ impl Item {
         }
     }
 
+    #[must_use]
     pub fn damage(&self, attack_type: AttackType) -> Option<DamageValue> {
         match attack_type {
             AttackType::Melee => {
@@ -249,22 +316,30 @@ impl Item {
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if the item has no melee damage
+    #[must_use]
     pub fn melee_damage(&self) -> DamageValue {
         self.damage(AttackType::Melee).unwrap()
     }
 
+    #[must_use]
     pub fn throw_damage(&self) -> Option<DamageValue> {
         self.damage(AttackType::Throw)
     }
 
+    #[must_use]
     pub fn ranged_damage(&self) -> Option<DamageValue> {
         self.damage(AttackType::Shoot)
     }
 
+    #[must_use]
     pub fn need_ammo(&self) -> Option<NeedAmmoValue> {
         self.proto().need_ammo
     }
 
+    #[must_use]
     pub fn is_ammo(&self, ammo_type: AmmoType) -> bool {
         if let Some(ammo) = &self.proto().is_ammo {
             ammo.typ == ammo_type
@@ -273,6 +348,7 @@ impl Item {
         }
     }
 
+    #[must_use]
     pub fn has_ammo(&self, ammo_type: AmmoType) -> bool {
         if self.is_ammo(ammo_type) {
             return true;
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use super::{
+    super::traits::Name,
+    items::Item,
+    terrain::{Terrain, TerrainView},
+    ChunkPos, Tile,
+};
+
+/// What player saw on the tile last time
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct RememberedTile {
+    #[serde(rename = "t")]
+    pub terrain: Terrain,
+    #[serde(rename = "i")]
+    pub top_item: Option<Item>,
+}
+
+impl RememberedTile {
+    #[must_use]
+    pub fn description(&self) -> String {
+        let mut description = format!("You remember {} here", self.terrain.name());
+        if let Some(item) = &self.top_item {
+            description.push_str(format!(" and {}", item.name()).as_str());
+        }
+        description.push('.');
+        description
+    }
+}
+
+impl From<&Tile> for RememberedTile {
+    fn from(tile: &Tile) -> Self {
+        Self {
+            terrain: tile.terrain.clone(),
+            top_item: tile.top_item().cloned(),
+        }
+    }
+}
+
+/// Explored tiles of the chunk, by index in `Chunk.surface`
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct ChunkMemory {
+    pub pos: ChunkPos,
+    pub tiles: HashMap<usize, RememberedTile>,
+}
+
+impl ChunkMemory {
+    #[must_use]
+    pub fn new(pos: ChunkPos) -> Self {
+        Self {
+            pos,
+            tiles: HashMap::new(),
+        }
+    }
+}
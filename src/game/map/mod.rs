@@ -3,18 +3,23 @@ use std::collections::{HashMap, HashSet};
 use bracket_noise::prelude::{FastNoise, FractalType, NoiseType};
 use roguemetry::Point;
 
-pub use chunk::Chunk;
+use crate::savefile::ChunkStorage;
+
+pub use chunk::{Chunk, Spawn};
 pub use fov::{field_of_view_set, Fov, FovMap};
 pub use items::Item;
+pub use memory::{ChunkMemory, RememberedTile};
 pub use passage::Passage;
 pub use pos::{ChunkPos, TilePos};
 pub use terrain::{Terrain, TerrainInteract, TerrainInteractAction, TerrainView};
 pub use tile::Tile;
-use worldgen::{wasteland::Wasteland, WorldGen};
+pub use worldgen::biomes::Biome;
+use worldgen::generate;
 
 mod chunk;
 mod fov;
 pub mod items;
+mod memory;
 mod passage;
 mod pos;
 mod terrain;
@@ -26,6 +31,14 @@ pub struct Map {
     pub seed: u64,
     pub chunks: HashMap<ChunkPos, Chunk>,
     pub changed: HashSet<ChunkPos>,
+    /// Tiles explored by the player, unloaded to the storage with their chunks
+    pub memory: HashMap<ChunkPos, ChunkMemory>,
+    /// Current level, tiles are taken from it
+    pub level: i32,
+    /// Where distant changed chunks go, they are kept in the memory if there is no storage
+    pub storage: Option<ChunkStorage>,
+    /// Creatures coming to the world besides the ones of the chunks, like migrants
+    pub spawns: Vec<Spawn>,
     noise: FastNoise,
 }
 
@@ -41,68 +54,195 @@ fn default_noise(seed: u64) -> FastNoise {
 }
 
 impl Map {
-    pub fn new(seed: u64, chunks: HashMap<ChunkPos, Chunk>, changed: HashSet<ChunkPos>) -> Self {
+    #[must_use]
+    pub fn new(
+        seed: u64,
+        chunks: HashMap<ChunkPos, Chunk>,
+        changed: HashSet<ChunkPos>,
+        memory: HashMap<ChunkPos, ChunkMemory>,
+    ) -> Self {
         let noise = default_noise(seed);
 
         Self {
             seed,
             chunks,
             changed,
+            memory,
+            level: 0,
+            storage: None,
+            spawns: Vec::new(),
             noise,
         }
     }
 
+    /// Spawn table for the creatures living around the point on the current level
+    #[must_use]
+    pub fn spawn_table_at(&self, pos: Point) -> &'static str {
+        worldgen::spawns::spawn_table(&self.noise, pos, self.level)
+    }
+
+    /// Chunks farther than this are unloaded, it's more than the units' bubble
+    pub const KEEP_RADIUS: i32 = 6;
+
+    pub fn attach_storage(&mut self, mut storage: ChunkStorage) {
+        for &pos in self.chunks.keys() {
+            storage.forget(pos);
+        }
+        for &pos in self.memory.keys() {
+            storage.forget_memory(pos);
+        }
+        self.storage = Some(storage);
+    }
+
+    fn load_chunk(&mut self, pos: ChunkPos) -> &mut Chunk {
+        if !self.chunks.contains_key(&pos) {
+            let chunk = if let Some(chunk) = self.storage.as_mut().and_then(|s| s.take(pos)) {
+                // it's not the generated one, so it should be saved again
+                self.changed.insert(pos);
+                chunk
+            } else {
+                let chunk = generate(self.seed, &self.noise, pos);
+                if !chunk.spawns.is_empty() {
+                    // regenerating it would bring the same creatures again
+                    self.changed.insert(pos);
+                }
+                chunk
+            };
+            self.chunks.insert(pos, chunk);
+            if let Some(memory) = self.storage.as_mut().and_then(|s| s.take_memory(pos)) {
+                self.memory.insert(pos, memory);
+            }
+        }
+        self.chunks.get_mut(&pos).unwrap()
+    }
+
     pub fn get_chunk(&mut self, pos: ChunkPos) -> &Chunk {
-        self.chunks
-            .entry(pos)
-            .or_insert_with_key(|pos| Wasteland::generate(self.seed, &self.noise, *pos))
+        self.load_chunk(pos)
     }
 
     pub fn get_chunk_mut(&mut self, pos: ChunkPos) -> &mut Chunk {
         self.changed.insert(pos);
-        self.chunks
-            .entry(pos)
-            .or_insert_with_key(|pos| Wasteland::generate(self.seed, &self.noise, *pos))
+        self.load_chunk(pos)
+    }
+
+    /// Drops chunks far from the center or on other levels, changed ones are moved to the storage.
+    /// Explored tiles of the dropped chunks are moved to the storage too.
+    /// Returns positions of the dropped chunks.
+    pub fn unload_distant_chunks(&mut self, center: Point) -> Vec<ChunkPos> {
+        let (center, _) = self.chunk_pos(center);
+        let distant: Vec<ChunkPos> = self
+            .chunks
+            .keys()
+            .copied()
+            .filter(|pos| {
+                pos.z != center.z
+                    || (pos.x - center.x).abs() > Self::KEEP_RADIUS
+                    || (pos.y - center.y).abs() > Self::KEEP_RADIUS
+            })
+            .collect();
+        let mut unloaded = Vec::with_capacity(distant.len());
+        for pos in distant {
+            if self.changed.contains(&pos) {
+                let Some(storage) = self.storage.as_mut() else {
+                    continue;
+                };
+                if storage.save(&self.chunks[&pos]).is_err() {
+                    continue;
+                }
+                self.changed.remove(&pos);
+            }
+            self.chunks.remove(&pos);
+            self.unload_memory(pos);
+            unloaded.push(pos);
+        }
+        unloaded
     }
 
+    /// Memory stays if there is no storage or it can't be written
+    fn unload_memory(&mut self, pos: ChunkPos) {
+        let (Some(storage), Some(memory)) = (self.storage.as_mut(), self.memory.get(&pos)) else {
+            return;
+        };
+        if storage.save_memory(memory).is_ok() {
+            self.memory.remove(&pos);
+        }
+    }
+
+    /// Chunk on the current level and index of the tile in it
+    #[must_use]
+    pub fn chunk_pos(&self, pos: Point) -> (ChunkPos, usize) {
+        let (chunk, index) = pos.to_chunk();
+        (chunk.with_z(self.level), index)
+    }
+
+    #[must_use]
     pub fn get_tile_opt(&self, pos: Point) -> Option<&Tile> {
-        let (chunk, pos) = pos.to_chunk();
+        let (chunk, pos) = self.chunk_pos(pos);
         self.chunks.get(&chunk).map(|c| &c.surface[pos])
     }
 
     pub fn get_tile(&mut self, pos: Point) -> &Tile {
-        let (chunk, pos) = pos.to_chunk();
+        let (chunk, pos) = self.chunk_pos(pos);
         let chunk = self.get_chunk(chunk);
         &chunk.surface[pos]
     }
 
     pub fn get_tile_mut(&mut self, pos: Point) -> &mut Tile {
+        self.get_tile_on_level_mut(pos, self.level)
+    }
+
+    /// Tile on any level, for units leaving the current one
+    pub fn get_tile_on_level_mut(&mut self, pos: Point, level: i32) -> &mut Tile {
         let (chunk, pos) = pos.to_chunk();
-        let chunk = self.get_chunk_mut(chunk);
+        let chunk = self.get_chunk_mut(chunk.with_z(level));
         &mut chunk.surface[pos]
     }
 
     pub fn load_tiles_between(&mut self, left_top: Point, right_bottom: Point) {
-        let (ChunkPos { x: lt_x, y: lt_y }, _) = left_top.to_chunk();
-        let (ChunkPos { x: rb_x, y: rb_y }, _) = right_bottom.to_chunk();
+        let (
+            ChunkPos {
+                x: lt_x, y: lt_y, ..
+            },
+            _,
+        ) = left_top.to_chunk();
+        let (
+            ChunkPos {
+                x: rb_x, y: rb_y, ..
+            },
+            _,
+        ) = right_bottom.to_chunk();
 
         for x in lt_x..=rb_x {
             for y in lt_y..=rb_y {
-                let pos = ChunkPos::new(x, y);
+                let pos = ChunkPos::new(x, y).with_z(self.level);
                 self.get_chunk(pos);
             }
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if some of the chunks between the points are not loaded
+    #[must_use]
     pub fn tiles_between(&self, left_top: Point, right_bottom: Point) -> Vec<(Point, &Tile)> {
-        let (ChunkPos { x: lt_x, y: lt_y }, _) = left_top.to_chunk();
-        let (ChunkPos { x: rb_x, y: rb_y }, _) = right_bottom.to_chunk();
+        let (
+            ChunkPos {
+                x: lt_x, y: lt_y, ..
+            },
+            _,
+        ) = left_top.to_chunk();
+        let (
+            ChunkPos {
+                x: rb_x, y: rb_y, ..
+            },
+            _,
+        ) = right_bottom.to_chunk();
 
         let mut tiles =
             Vec::with_capacity(((rb_x - lt_x + 1) * (rb_y - lt_y + 1)) as usize * Chunk::USIZE);
         for x in lt_x..=rb_x {
             for y in lt_y..=rb_y {
-                let chunk_pos = ChunkPos::new(x, y);
+                let chunk_pos = ChunkPos::new(x, y).with_z(self.level);
                 let chunk = self.chunks.get(&chunk_pos).unwrap();
                 for (i, tile) in chunk.surface.iter().enumerate() {
                     tiles.push((TilePos::from_chunk(chunk_pos, i), tile));
@@ -112,6 +252,26 @@ impl Map {
         tiles
     }
 
+    /// Saves how the tile looks now to the player's memory
+    pub fn remember(&mut self, pos: Point) {
+        let (chunk, index) = self.chunk_pos(pos);
+        let Some(tile) = self.chunks.get(&chunk).map(|c| &c.surface[index]) else {
+            return;
+        };
+        self.memory
+            .entry(chunk)
+            .or_insert_with(|| ChunkMemory::new(chunk))
+            .tiles
+            .insert(index, tile.into());
+    }
+
+    #[must_use]
+    pub fn remembered(&self, pos: Point) -> Option<&RememberedTile> {
+        let (chunk, index) = self.chunk_pos(pos);
+        self.memory.get(&chunk)?.tiles.get(&index)
+    }
+
+    #[must_use]
     pub fn get_passage_cost(&self, pos: Point) -> Option<u32> {
         let tile = self.get_tile_opt(pos)?;
         match tile.passage() {
@@ -130,7 +290,7 @@ impl Map {
 
 impl FovMap for Map {
     fn is_transparent(&self, pos: Point) -> bool {
-        let (chunk, pos) = pos.to_chunk();
+        let (chunk, pos) = self.chunk_pos(pos);
         self.chunks
             .get(&chunk)
             .is_none_or(|c| c.surface[pos].terrain.is_transparent())
@@ -10,6 +10,7 @@ impl Passage {
     /// Units can't actually pass through other units, so this is just a dummy "big" value for pathfinding purposes.
     pub const UNIT_PASSAGE_COST: u32 = 100;
 
+    #[must_use]
     pub fn is_passable(self) -> bool {
         self != Passage::Impassable
     }
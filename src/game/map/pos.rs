@@ -32,18 +32,29 @@ impl TilePos for Point {
 pub struct ChunkPos {
     pub x: i32,
     pub y: i32,
+    /// Level, 0 is the surface and underground levels are negative
+    #[serde(default)]
+    pub z: i32,
 }
 
 impl ChunkPos {
+    #[must_use]
     pub fn new(x: i32, y: i32) -> Self {
-        ChunkPos { x, y }
+        ChunkPos { x, y, z: 0 }
     }
 
+    #[must_use]
+    pub fn with_z(self, z: i32) -> Self {
+        ChunkPos { z, ..self }
+    }
+
+    #[must_use]
     pub fn left_top(self) -> Point {
         Point::new(self.x * Chunk::SIZE, self.y * Chunk::SIZE)
     }
 
     #[allow(dead_code)]
+    #[must_use]
     pub fn right_bottom(self) -> Point {
         Point::new(
             self.x * Chunk::SIZE + Chunk::SIZE - 1,
@@ -1,25 +1,23 @@
 use enum_dispatch::enum_dispatch;
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
 
-use crate::assets::Sprite;
+use crate::colors::Color;
+use crate::game::WorldRng;
+use crate::sprite::Sprite;
 
 use super::{
-    terrains::{Boulder, Chest, Dirt, Grass, Tree},
+    terrains::{DataTerrain, Pit, Stairs},
     Item, Passage,
 };
 
-// TODO: JSON-ize all terrains
-
 #[enum_dispatch]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum Terrain {
-    Dirt,
-    Grass,
-    Boulder,
-    Tree,
-    Chest,
+    Pit,
+    Stairs,
+    /// Terrain from the data files
+    DataTerrain,
 }
 
 #[enum_dispatch(Terrain)]
@@ -31,6 +29,10 @@ pub trait TerrainView {
         None
     }
     fn is_transparent(&self) -> bool; // for FOV
+    /// Radius of the light around the terrain, in tiles
+    fn emits_light(&self) -> u8 {
+        0
+    }
 }
 
 #[enum_dispatch(Terrain)]
@@ -51,18 +53,40 @@ pub trait TerrainInteract {
     fn close(&self, _items: Vec<Item>) -> Terrain {
         unimplemented!()
     }
-    fn on_step(&self) -> Option<Terrain> {
+    fn on_step(&self, _rng: &mut WorldRng) -> Option<Terrain> {
         None
     }
-    fn smash(&self) -> Option<TerrainSmash> {
+    /// Minimal damage to break this terrain, `None` if it can't be smashed
+    fn smash_toughness(&self) -> Option<u8> {
         None
     }
+    /// What is left after smashing: new terrain and dropped items
+    fn smash(&self, _rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        unimplemented!()
+    }
+    /// What is left after digging: new terrain and dug out items
+    fn dig(&self, _rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        unimplemented!()
+    }
+    /// What is left after chopping: new terrain and dropped wood
+    fn chop(&self, _rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        unimplemented!()
+    }
     fn supports_action(&self, _action: TerrainInteractAction) -> bool {
         false
     }
+    /// Penalty to Climbing rolls to get on top, `None` if it can't be climbed
+    fn climbing_penalty(&self) -> Option<i8> {
+        None
+    }
+    /// Units have to swim here
+    fn is_deep_water(&self) -> bool {
+        false
+    }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
 pub enum TerrainInteractAction {
     Open,
     Close,
@@ -70,15 +94,28 @@ pub enum TerrainInteractAction {
     Drop,
     Examine,
     WieldFromGround,
+    GoDown,
+    GoUp,
+    Climb,
+    Dig,
+    Chop,
+    Butcher,
+    Drink,
 }
 
-pub struct TerrainSmash {
-    pub toughness: u8,
-    pub result: (Terrain, Vec<Item>),
-}
+#[cfg(test)]
+mod tests {
+    use crate::sprite::Sprite;
+
+    use super::{Terrain, TerrainView};
 
-impl TerrainSmash {
-    pub fn new(toughness: u8, result: (Terrain, Vec<Item>)) -> Self {
-        Self { toughness, result }
+    #[test]
+    fn test_saving_data_terrain() {
+        let terrains: Vec<Terrain> =
+            serde_json::from_str(r#"[{"data_terrain": {"id": "grass", "v": 4}}]"#).unwrap();
+        let saved = serde_json::to_string(&terrains).unwrap();
+        let loaded: Vec<Terrain> = serde_json::from_str(&saved).unwrap();
+        assert_eq!("grass", loaded[0].name());
+        assert_eq!(Sprite::Grass5, loaded[0].looks_like());
     }
 }
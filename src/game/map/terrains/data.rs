@@ -0,0 +1,322 @@
+use rand::{Rng, RngExt};
+
+use crate::colors::Color;
+use crate::game::game_data::TerrainYield;
+use crate::game::{GameData, Item, LootContext, TerrainInteractAction, TerrainPrototype, WorldRng};
+use crate::sprite::Sprite;
+
+use super::super::{Passage, Terrain, TerrainInteract, TerrainView};
+
+/// Ids of the tree terrains
+pub const LIVE_TREES: [&str; 5] = ["tree", "pine", "hickory", "willow", "birch"];
+pub const DEAD_TREES: [&str; 5] = [
+    "dead_tree",
+    "dead_pine",
+    "dead_hickory",
+    "dead_willow",
+    "dead_birch",
+];
+
+/// Terrain described by the `TerrainPrototype` from the data files
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct DataTerrain(Box<DataTerrainState>);
+
+/// Boxed, so the `Terrain` and every tile of the chunk stay small
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+struct DataTerrainState {
+    id: String,
+    /// Index in `TerrainPrototype.sprites`
+    #[serde(rename = "v", default)]
+    variant: usize,
+    #[serde(rename = "i", default, skip_serializing_if = "Vec::is_empty")]
+    items_inside: Vec<Item>,
+}
+
+impl DataTerrain {
+    /// Terrain with a random variant
+    pub fn new<R: Rng + ?Sized>(id: impl Into<String>, rng: &mut R) -> Self {
+        let id = id.into();
+        let variants = GameData::instance().get_terrain(&id).sprites.len();
+        let variant = if variants > 1 {
+            rng.random_range(0..variants)
+        } else {
+            0
+        };
+        Self::with_variant(id, variant)
+    }
+
+    /// Terrain with the given variant, the last one if there are fewer
+    #[must_use]
+    pub fn with_variant(id: impl Into<String>, variant: usize) -> Self {
+        let id = id.into();
+        let variants = GameData::instance().get_terrain(&id).sprites.len();
+        Self(Box::new(DataTerrainState {
+            id,
+            variant: variant.min(variants.saturating_sub(1)),
+            items_inside: Vec::new(),
+        }))
+    }
+
+    #[must_use]
+    pub fn with_items(mut self, items: Vec<Item>) -> Self {
+        self.0.items_inside = items;
+        self
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.0.id
+    }
+
+    #[must_use]
+    pub fn proto(&self) -> &'static TerrainPrototype {
+        GameData::instance().get_terrain(&self.0.id)
+    }
+
+    /// Terrain of the other kind looking the same way, like the other half of the open/close pair
+    #[must_use]
+    pub fn counterpart(&self, id: &str, items_inside: Vec<Item>) -> Terrain {
+        Self::with_variant(id, self.0.variant)
+            .with_items(items_inside)
+            .into()
+    }
+
+    fn work(work: &TerrainYield, rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        let items = work.loot.as_ref().map_or_else(Vec::new, |loot| {
+            GameData::instance()
+                .get_loot_table(loot)
+                .roll(LootContext::default(), rng)
+        });
+        (work.result.terrain(Vec::new(), rng), items)
+    }
+}
+
+impl TerrainView for DataTerrain {
+    fn name(&self) -> &str {
+        &self.proto().name
+    }
+
+    fn looks_like(&self) -> Sprite {
+        let sprites = &self.proto().sprites;
+        sprites
+            .get(self.0.variant)
+            .or_else(|| sprites.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn color(&self) -> Option<Color> {
+        self.proto().color()
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.proto().transparent
+    }
+
+    fn emits_light(&self) -> u8 {
+        self.proto().emits_light
+    }
+}
+
+impl TerrainInteract for DataTerrain {
+    fn passage(&self) -> Passage {
+        self.proto()
+            .passage
+            .map_or(Passage::Impassable, Passage::Passable)
+    }
+
+    fn read(&self) -> String {
+        self.proto().text.clone().unwrap_or_default()
+    }
+
+    fn can_contain_items(&self) -> bool {
+        self.proto().container
+    }
+
+    fn open(&self) -> (Terrain, Vec<Item>) {
+        let id = self
+            .proto()
+            .opens_to
+            .as_ref()
+            .expect("Terrain can't be opened");
+        (
+            self.counterpart(id, Vec::new()),
+            self.0.items_inside.clone(),
+        )
+    }
+
+    fn close(&self, items: Vec<Item>) -> Terrain {
+        let id = self
+            .proto()
+            .closes_to
+            .as_ref()
+            .expect("Terrain can't be closed");
+        self.counterpart(id, items)
+    }
+
+    fn on_step(&self, rng: &mut WorldRng) -> Option<Terrain> {
+        let step = self.proto().on_step.as_ref()?;
+        if rng.random_bool(step.chance) {
+            Some(step.result.terrain(Vec::new(), rng))
+        } else {
+            None
+        }
+    }
+
+    fn smash_toughness(&self) -> Option<u8> {
+        self.proto().smash.as_ref().map(|smash| smash.toughness)
+    }
+
+    fn smash(&self, rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        let smash = self
+            .proto()
+            .smash
+            .as_ref()
+            .expect("Terrain can't be smashed");
+        let mut items = self.0.items_inside.clone();
+        if let Some(loot) = &smash.loot {
+            items.append(
+                &mut GameData::instance()
+                    .get_loot_table(loot)
+                    .roll(LootContext::default(), rng),
+            );
+        }
+
+        (smash.result.terrain(Vec::new(), rng), items)
+    }
+
+    fn dig(&self, rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        let dig = self.proto().dig.as_ref().expect("Terrain can't be dug");
+        Self::work(dig, rng)
+    }
+
+    fn chop(&self, rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        let chop = self
+            .proto()
+            .chop
+            .as_ref()
+            .expect("Terrain can't be chopped");
+        Self::work(chop, rng)
+    }
+
+    fn supports_action(&self, action: TerrainInteractAction) -> bool {
+        let proto = self.proto();
+        match action {
+            TerrainInteractAction::Open => proto.opens_to.is_some(),
+            TerrainInteractAction::Close => proto.closes_to.is_some(),
+            TerrainInteractAction::Read => proto.text.is_some(),
+            TerrainInteractAction::Climb => proto.climb.is_some(),
+            TerrainInteractAction::Dig => proto.dig.is_some(),
+            TerrainInteractAction::Chop => proto.chop.is_some(),
+            _ => proto.actions.contains(&action),
+        }
+    }
+
+    fn climbing_penalty(&self) -> Option<i8> {
+        self.proto().climb
+    }
+
+    fn is_deep_water(&self) -> bool {
+        self.proto().deep_water
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::map::items::helpers::ROCK;
+    use crate::game::{Item, TerrainInteractAction, Tile, WorldRng};
+    use crate::sprite::Sprite;
+
+    use super::super::super::{Passage, Terrain, TerrainInteract, TerrainView};
+    use super::DataTerrain;
+
+    #[test]
+    fn test_mud() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let terrain: Terrain = DataTerrain::new("mud", &mut rng).into();
+        assert_eq!("mud", terrain.name());
+        assert!(terrain.is_transparent());
+        assert!(matches!(terrain.passage(), Passage::Passable(25)));
+        assert!(terrain.supports_action(TerrainInteractAction::Drop));
+        assert!(!terrain.supports_action(TerrainInteractAction::Open));
+        assert!(terrain.smash_toughness().is_none());
+    }
+
+    #[test]
+    fn test_dead_grass() {
+        let mut tile = Tile::new(DataTerrain::with_variant("grass", 4).into());
+        assert_eq!("grass", tile.terrain.name());
+        tile.kill_grass();
+        assert_eq!("dead grass", tile.terrain.name());
+        assert_eq!(Sprite::Grass5, tile.terrain.looks_like());
+    }
+
+    #[test]
+    fn test_flowers_are_trampled() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let flowers: Terrain = DataTerrain::new("flowers", &mut rng).into();
+        let trampled = (0..100).find_map(|_| flowers.on_step(&mut rng)).unwrap();
+        assert_eq!("grass", trampled.name());
+    }
+
+    #[test]
+    fn test_dig_and_chop() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let dirt: Terrain = DataTerrain::new("dirt", &mut rng).into();
+        assert!(dirt.supports_action(TerrainInteractAction::Dig));
+        assert!(!dirt.supports_action(TerrainInteractAction::Chop));
+        let (pit, _) = dirt.dig(&mut rng);
+        assert!(matches!(pit, Terrain::Pit(..)));
+
+        let tree: Terrain = DataTerrain::new("dead_pine", &mut rng).into();
+        assert!(tree.supports_action(TerrainInteractAction::Chop));
+        assert!(!tree.supports_action(TerrainInteractAction::Dig));
+        let (stump, wood) = tree.chop(&mut rng);
+        assert_eq!("dirt", stump.name());
+        assert!(!wood.is_empty());
+    }
+
+    #[test]
+    fn test_open_and_close_crate() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let terrain: Terrain = DataTerrain::new("crate", &mut rng)
+            .with_items(vec![Item::new(ROCK)])
+            .into();
+        assert!(terrain.supports_action(TerrainInteractAction::Open));
+        assert!(!terrain.supports_action(TerrainInteractAction::Close));
+
+        let (opened, items) = terrain.open();
+        assert_eq!(1, items.len());
+        assert_eq!("open crate", opened.name());
+        assert!(opened.supports_action(TerrainInteractAction::Close));
+
+        let closed = opened.close(items);
+        assert_eq!("crate", closed.name());
+        let (dirt, items) = closed.smash(&mut rng);
+        assert_eq!("dirt", dirt.name());
+        assert!(items.iter().any(|i| i.proto().id == ROCK));
+    }
+
+    #[test]
+    fn test_read_sign() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let terrain: Terrain = DataTerrain::new("signpost", &mut rng).into();
+        assert!(terrain.supports_action(TerrainInteractAction::Read));
+        assert!(!terrain.read().is_empty());
+        assert!(!terrain.is_passable());
+    }
+
+    #[test]
+    fn test_water() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let shallow: Terrain = DataTerrain::new("shallow_water", &mut rng).into();
+        let deep: Terrain = DataTerrain::new("deep_water", &mut rng).into();
+        assert!(shallow.is_passable() && deep.is_passable());
+        assert!(!shallow.is_deep_water());
+        assert!(deep.is_deep_water());
+        assert!(!deep.supports_action(TerrainInteractAction::Drop));
+    }
+}
@@ -1,13 +1,9 @@
 #![allow(unused_imports)]
 
-pub use boulder::{Boulder, BoulderSize};
-pub use chest::Chest;
-pub use dirt::{Dirt, DirtVariant};
-pub use grass::Grass;
-pub use tree::{DeadTrees, LiveTrees, Tree};
+pub use data::{DataTerrain, DEAD_TREES, LIVE_TREES};
+pub use pit::Pit;
+pub use stairs::Stairs;
 
-mod boulder;
-mod chest;
-mod dirt;
-mod grass;
-mod tree;
+mod data;
+mod pit;
+mod stairs;
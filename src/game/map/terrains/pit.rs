@@ -0,0 +1,53 @@
+use crate::colors::{Color, Colors};
+use crate::game::{Item, Terrain, TerrainInteractAction, WorldRng};
+use crate::sprite::Sprite;
+
+use super::super::{Passage, TerrainInteract, TerrainView};
+use super::DataTerrain;
+
+/// Hole in the ground, one can climb down but not up
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct Pit;
+
+impl Pit {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TerrainView for Pit {
+    fn name(&self) -> &'static str {
+        "pit"
+    }
+
+    fn looks_like(&self) -> Sprite {
+        Sprite::Pit
+    }
+
+    fn color(&self) -> Option<Color> {
+        Some(Colors::DIRT)
+    }
+
+    fn is_transparent(&self) -> bool {
+        true
+    }
+}
+
+impl TerrainInteract for Pit {
+    fn passage(&self) -> Passage {
+        Passage::Passable(30)
+    }
+
+    /// Filling it back in
+    fn dig(&self, rng: &mut WorldRng) -> (Terrain, Vec<Item>) {
+        (DataTerrain::new("dirt", rng).into(), Vec::new())
+    }
+
+    fn supports_action(&self, action: TerrainInteractAction) -> bool {
+        matches!(
+            action,
+            TerrainInteractAction::GoDown | TerrainInteractAction::Dig
+        )
+    }
+}
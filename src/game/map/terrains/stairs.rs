@@ -0,0 +1,59 @@
+use crate::game::TerrainInteractAction;
+use crate::sprite::Sprite;
+
+use super::super::{Passage, TerrainInteract, TerrainView};
+
+/// Connects the level with the one above or below it
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Stairs {
+    #[serde(rename = "d")]
+    down: bool,
+}
+
+impl Stairs {
+    #[must_use]
+    pub fn down() -> Self {
+        Self { down: true }
+    }
+
+    #[must_use]
+    pub fn up() -> Self {
+        Self { down: false }
+    }
+}
+
+impl TerrainView for Stairs {
+    fn name(&self) -> &'static str {
+        if self.down {
+            "stairs down"
+        } else {
+            "stairs up"
+        }
+    }
+
+    fn looks_like(&self) -> Sprite {
+        if self.down {
+            Sprite::MoreThan
+        } else {
+            Sprite::LessThan
+        }
+    }
+
+    fn is_transparent(&self) -> bool {
+        true
+    }
+}
+
+impl TerrainInteract for Stairs {
+    fn passage(&self) -> Passage {
+        Passage::Passable(15)
+    }
+
+    fn supports_action(&self, action: TerrainInteractAction) -> bool {
+        match action {
+            TerrainInteractAction::GoDown => self.down,
+            TerrainInteractAction::GoUp => !self.down,
+            _ => false,
+        }
+    }
+}
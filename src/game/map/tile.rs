@@ -2,7 +2,7 @@
 
 use std::collections::HashSet;
 
-use crate::game::TerrainInteractAction;
+use crate::game::{TerrainInteractAction, WorldRng};
 
 use super::{
     super::traits::Name,
@@ -23,6 +23,7 @@ pub struct Tile {
 }
 
 impl Tile {
+    #[must_use]
     pub fn new(terrain: Terrain) -> Self {
         Self {
             terrain,
@@ -36,21 +37,27 @@ impl Tile {
         self.units.remove(&unit_id);
     }
 
-    /// Calls when avatar walks on tile
-    pub fn on_step(&mut self, unit_id: usize) {
+    /// Calls when avatar walks on tile, returns true if terrain was changed
+    pub fn on_step(&mut self, unit_id: usize, rng: &mut WorldRng) -> bool {
         self.units.insert(unit_id);
         // TODO: (for future) footprints
-        if let Some(new_terrain) = self.terrain.on_step() {
+        if let Some(new_terrain) = self.terrain.on_step(rng) {
             self.terrain = new_terrain;
+            true
+        } else {
+            false
         }
     }
 
     pub fn kill_grass(&mut self) {
-        if let Terrain::Grass(grass) = &mut self.terrain {
-            grass.die();
+        if let Terrain::DataTerrain(terrain) = &self.terrain {
+            if terrain.id() == "grass" {
+                self.terrain = terrain.counterpart("dead_grass", Vec::new());
+            }
         }
     }
 
+    #[must_use]
     pub fn top_item(&self) -> Option<&Item> {
         self.items.last()
     }
@@ -60,6 +67,7 @@ impl Tile {
             || self.items.iter().any(Item::is_readable)
     }
 
+    #[must_use]
     pub fn read(&self) -> String {
         // TODO: probably we shouldn't read only first occurrence
         if self.terrain.supports_action(TerrainInteractAction::Read) {
@@ -85,14 +93,16 @@ impl Tile {
             .unwrap_or_else(|| "You can't find anything to read here.".to_string())
     }
 
+    #[must_use]
     pub fn passage(&self) -> Passage {
-        if self.units.is_empty() {
-            self.terrain.passage()
+        if let Some(&unit_id) = self.units.iter().next() {
+            Passage::TemporaryImpassable(unit_id)
         } else {
-            Passage::TemporaryImpassable(*self.units.iter().next().unwrap())
+            self.terrain.passage()
         }
     }
 
+    #[must_use]
     pub fn is_passable(&self) -> bool {
         self.passage().is_passable()
     }
@@ -0,0 +1,31 @@
+use rand::{prelude::StdRng, seq::IndexedRandom, RngExt};
+
+use super::{
+    super::super::{
+        terrains::{DataTerrain, DEAD_TREES},
+        Terrain,
+    },
+    BiomeGen, Climate,
+};
+
+/// Rocky highlands, boulders everywhere and almost nothing grows
+pub struct Badlands;
+
+impl BiomeGen for Badlands {
+    fn terrain(climate: Climate, rng: &mut StdRng) -> Terrain {
+        // higher is rockier
+        let rocks = (climate.elevation * 0.5).clamp(0.08, 0.2);
+        if rng.random_bool(f64::from(rocks)) {
+            let boulder = *["huge_boulder", "boulder", "small_boulder"]
+                .choose(rng)
+                .unwrap();
+            DataTerrain::new(boulder, rng).into()
+        } else if rng.random_bool(0.01) {
+            DataTerrain::new(*DEAD_TREES.choose(rng).unwrap(), rng).into()
+        } else if rng.random_bool(0.05) {
+            DataTerrain::new("dead_grass", rng).into()
+        } else {
+            DataTerrain::new("dirt", rng).into()
+        }
+    }
+}
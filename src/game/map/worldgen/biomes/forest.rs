@@ -0,0 +1,31 @@
+use rand::{prelude::StdRng, seq::IndexedRandom, RngExt};
+
+use super::{
+    super::super::{
+        terrains::{DataTerrain, DEAD_TREES, LIVE_TREES},
+        Terrain,
+    },
+    BiomeGen, Climate,
+};
+
+/// Dense trees and grass under them
+pub struct Forest;
+
+impl BiomeGen for Forest {
+    fn terrain(climate: Climate, rng: &mut StdRng) -> Terrain {
+        // forest is denser in its heart
+        let density = (0.2 + climate.humidity).clamp(0.2, 0.45);
+        if rng.random_bool(f64::from(density)) {
+            let trees = if rng.random_bool(0.9) {
+                LIVE_TREES
+            } else {
+                DEAD_TREES
+            };
+            DataTerrain::new(*trees.choose(rng).unwrap(), rng).into()
+        } else if rng.random_bool(0.9) {
+            DataTerrain::new("grass", rng).into()
+        } else {
+            DataTerrain::new("dirt", rng).into()
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use rand::{prelude::StdRng, RngExt};
+
+use super::{
+    super::super::{terrains::DataTerrain, Terrain},
+    BiomeGen, Climate,
+};
+
+/// Lowland mud with tall grass and willows
+pub struct Marsh;
+
+impl BiomeGen for Marsh {
+    fn terrain(climate: Climate, rng: &mut StdRng) -> Terrain {
+        // wetter is muddier
+        let mud = (climate.humidity * 2.0).clamp(0.3, 0.7);
+        if rng.random_bool(0.04) {
+            let willow = if rng.random_bool(0.7) {
+                "willow"
+            } else {
+                "dead_willow"
+            };
+            DataTerrain::new(willow, rng).into()
+        } else if rng.random_bool(f64::from(mud)) {
+            DataTerrain::new("mud", rng).into()
+        } else {
+            DataTerrain::new("grass", rng).into()
+        }
+    }
+}
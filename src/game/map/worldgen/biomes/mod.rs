@@ -0,0 +1,143 @@
+use bracket_noise::prelude::FastNoise;
+use rand::{prelude::StdRng, RngExt};
+use roguemetry::Point;
+
+use super::super::{Chunk, Terrain};
+
+pub use badlands::Badlands;
+pub use forest::Forest;
+pub use marsh::Marsh;
+pub use wasteland::Wasteland;
+
+mod badlands;
+mod forest;
+mod marsh;
+mod wasteland;
+
+/// Generator of a single biome, surface is made of them
+pub trait BiomeGen {
+    fn terrain(climate: Climate, rng: &mut StdRng) -> Terrain;
+}
+
+/// Noise channels of the tile, every one is roughly from -0.5 to 0.5
+#[derive(Debug, Copy, Clone)]
+pub struct Climate {
+    pub temperature: f32,
+    pub elevation: f32,
+    pub humidity: f32,
+}
+
+impl Climate {
+    /// Temperature and elevation change slower than humidity
+    pub fn at(noise: &FastNoise, pos: Point) -> Self {
+        let x = pos.x as f32 / Chunk::NOISE_SIZE;
+        let y = pos.y as f32 / Chunk::NOISE_SIZE;
+        Self {
+            temperature: noise.get_noise(x / 4.0 + 100.5, y / 4.0 + 100.5),
+            elevation: noise.get_noise(x / 3.0 - 200.5, y / 3.0 - 200.5),
+            humidity: noise.get_noise(x, y),
+        }
+    }
+
+    /// Slightly shifted climate, to blend biomes on the borders
+    #[must_use]
+    pub fn jitter(self, rng: &mut StdRng) -> Self {
+        const JITTER: f32 = 0.04;
+        Self {
+            temperature: self.temperature + rng.random_range(-JITTER..=JITTER),
+            elevation: self.elevation + rng.random_range(-JITTER..=JITTER),
+            humidity: self.humidity + rng.random_range(-JITTER..=JITTER),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Biome {
+    Wasteland,
+    Forest,
+    Badlands,
+    Marsh,
+}
+
+impl Biome {
+    #[must_use]
+    pub fn at(climate: Climate) -> Self {
+        if climate.elevation > 0.15 {
+            Biome::Badlands
+        } else if climate.humidity > 0.12 && climate.elevation < -0.05 {
+            Biome::Marsh
+        } else if climate.humidity > 0.05 && climate.temperature > -0.05 {
+            Biome::Forest
+        } else {
+            Biome::Wasteland
+        }
+    }
+
+    /// Loot table for the items lying around
+    #[must_use]
+    pub fn scatter_table(self) -> &'static str {
+        match self {
+            Biome::Wasteland => "wasteland_scatter",
+            Biome::Forest => "forest_scatter",
+            Biome::Badlands => "badlands_scatter",
+            Biome::Marsh => "marsh_scatter",
+        }
+    }
+
+    /// Spawn table for the creatures living here
+    #[must_use]
+    pub fn spawn_table(self) -> &'static str {
+        match self {
+            Biome::Wasteland => "wasteland_spawns",
+            Biome::Forest => "forest_spawns",
+            Biome::Badlands => "badlands_spawns",
+            Biome::Marsh => "marsh_spawns",
+        }
+    }
+
+    pub fn terrain(self, climate: Climate, rng: &mut StdRng) -> Terrain {
+        match self {
+            Biome::Wasteland => Wasteland::terrain(climate, rng),
+            Biome::Forest => Forest::terrain(climate, rng),
+            Biome::Badlands => Badlands::terrain(climate, rng),
+            Biome::Marsh => Marsh::terrain(climate, rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use roguemetry::Point;
+
+    use super::super::super::{default_noise, Chunk, ChunkPos, TilePos};
+    use super::{Biome, Climate};
+
+    #[test]
+    fn test_all_biomes_are_somewhere() {
+        let noise = default_noise(42);
+        let mut biomes = HashSet::new();
+        for x in -50..50 {
+            for y in -50..50 {
+                let climate = Climate::at(&noise, Point::new(x * 20, y * 20));
+                biomes.insert(Biome::at(climate));
+            }
+        }
+        assert_eq!(4, biomes.len(), "{biomes:?}");
+    }
+
+    #[test]
+    fn test_borders_are_not_chunk_aligned() {
+        let noise = default_noise(42);
+        let mixed = (0..1000).any(|x| {
+            let pos = ChunkPos::new(x, 0);
+            let biomes: HashSet<Biome> = (0..Chunk::USIZE)
+                .map(|i| Biome::at(Climate::at(&noise, Point::from_chunk(pos, i))))
+                .collect();
+            biomes.len() > 1
+        });
+        assert!(mixed);
+    }
+}
@@ -0,0 +1,37 @@
+use rand::{prelude::StdRng, seq::IndexedRandom, RngExt};
+
+use super::{
+    super::super::{
+        terrains::{DataTerrain, DEAD_TREES, LIVE_TREES},
+        Terrain,
+    },
+    BiomeGen, Climate,
+};
+
+/// Dirt and patches of grass with some lone trees, flowers where it's wetter
+pub struct Wasteland;
+
+impl BiomeGen for Wasteland {
+    fn terrain(climate: Climate, rng: &mut StdRng) -> Terrain {
+        let humidity = climate.humidity;
+        if rng.random_bool(0.05) {
+            let trees = if humidity > 0.0 + rng.random_range(-0.2..=0.2) {
+                LIVE_TREES
+            } else {
+                DEAD_TREES
+            };
+            DataTerrain::new(*trees.choose(rng).unwrap(), rng).into()
+        } else if humidity > 0.0 {
+            let grass = if humidity < 0.1 {
+                "dead_grass"
+            } else if humidity > 0.3 {
+                "flowers"
+            } else {
+                "grass"
+            };
+            DataTerrain::new(grass, rng).into()
+        } else {
+            DataTerrain::new("dirt", rng).into()
+        }
+    }
+}
@@ -1,15 +1,30 @@
 use std::hash::{DefaultHasher, Hash, Hasher};
 
 use bracket_noise::prelude::FastNoise;
+use rand::{prelude::StdRng, RngExt, SeedableRng};
 
 use super::{Chunk, ChunkPos};
 
-pub mod wasteland;
+pub mod biomes;
+pub mod prefabs;
+pub mod spawns;
+pub mod surface;
+pub mod underground;
+pub mod water;
 
 pub trait WorldGen {
     fn generate(world_seed: u64, noise: &FastNoise, pos: ChunkPos) -> Chunk;
 }
 
+/// Surface is made of biomes and everything below it is caves
+pub fn generate(world_seed: u64, noise: &FastNoise, pos: ChunkPos) -> Chunk {
+    if pos.z >= 0 {
+        surface::Surface::generate(world_seed, noise, pos)
+    } else {
+        underground::Underground::generate(world_seed, noise, pos)
+    }
+}
+
 #[derive(Hash)]
 struct ChunkUnique {
     pos: ChunkPos,
@@ -22,3 +37,11 @@ fn chunk_seed(world_seed: u64, pos: ChunkPos) -> u64 {
     seed.hash(&mut hasher);
     hasher.finish()
 }
+
+/// Index of the tile with stairs down in the chunk, same as stairs up in the chunk below
+fn stairs_down(world_seed: u64, pos: ChunkPos) -> Option<usize> {
+    // separate rng, so both chunks get the same answer
+    let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed.rotate_left(1), pos));
+    rng.random_bool(0.25)
+        .then(|| rng.random_range(0..Chunk::USIZE))
+}
@@ -0,0 +1,148 @@
+use bracket_noise::prelude::FastNoise;
+use rand::{prelude::StdRng, RngExt, SeedableRng};
+use roguemetry::Point;
+
+use crate::game::{GameData, Item, LootContext, Prefab};
+
+use super::{
+    super::{Chunk, ChunkPos, Spawn, TilePos},
+    biomes::{Biome, Climate},
+    chunk_seed,
+};
+
+/// Prefab anchored in the chunk by its left top corner, it may stick out to the right and bottom
+fn anchored_prefab(
+    world_seed: u64,
+    noise: &FastNoise,
+    pos: ChunkPos,
+) -> Option<(Point, &'static Prefab)> {
+    let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed.rotate_left(2), pos));
+    let anchor = Point::from_chunk(pos, rng.random_range(0..Chunk::USIZE));
+    let biome = Biome::at(Climate::at(noise, anchor));
+    GameData::instance()
+        .prefabs
+        .values()
+        .filter(|prefab| prefab.biomes.contains(&biome))
+        .find(|prefab| rng.random_bool(prefab.chance))
+        .map(|prefab| (anchor, prefab))
+}
+
+/// Prefabs overlapping the chunk, they are never bigger than a chunk
+pub fn prefabs_around(
+    world_seed: u64,
+    noise: &FastNoise,
+    pos: ChunkPos,
+) -> Vec<(Point, &'static Prefab)> {
+    [(0, 0), (-1, 0), (0, -1), (-1, -1)]
+        .into_iter()
+        .filter_map(|(dx, dy)| {
+            let neighbour = ChunkPos::new(pos.x + dx, pos.y + dy).with_z(pos.z);
+            anchored_prefab(world_seed, noise, neighbour)
+        })
+        .filter(|(_, prefab)| prefab.width() <= Chunk::SIZE && prefab.height() <= Chunk::SIZE)
+        .collect()
+}
+
+/// Replaces tiles of the chunk covered by the prefab, except the `keep` one
+pub fn stamp(
+    prefab: &Prefab,
+    anchor: Point,
+    chunk: &mut Chunk,
+    keep: Option<usize>,
+    rng: &mut StdRng,
+) {
+    for (shift, prefab_tile) in prefab.tiles() {
+        let point = anchor + shift;
+        let (tile_chunk, index) = point.to_chunk();
+        if tile_chunk.x != chunk.pos.x || tile_chunk.y != chunk.pos.y || keep == Some(index) {
+            continue;
+        }
+        let tile = &mut chunk.surface[index];
+        let mut items: Vec<Item> = prefab_tile.items.iter().map(Item::new).collect();
+        if let Some(loot) = &prefab_tile.loot {
+            let context = LootContext {
+                z: chunk.pos.z,
+                ..LootContext::default()
+            };
+            items.append(&mut GameData::instance().get_loot_table(loot).roll(context, rng));
+        }
+        if let Some(terrain) = &prefab_tile.terrain {
+            let inside = if terrain.is_container() {
+                std::mem::take(&mut items)
+            } else {
+                Vec::new()
+            };
+            tile.terrain = terrain.terrain(inside, rng);
+        }
+        tile.items.append(&mut items);
+        if let Some(creature) = &prefab_tile.creature {
+            chunk.spawns.push(Spawn {
+                pos: point,
+                z: chunk.pos.z,
+                creature: creature.clone(),
+                wild: false,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{prelude::StdRng, SeedableRng};
+    use roguemetry::Point;
+
+    use crate::game::Prefab;
+
+    use super::super::super::{default_noise, ChunkPos, TerrainInteract, TilePos};
+    use super::super::generate;
+    use super::{prefabs_around, stamp};
+
+    const PREFAB: &str = r#"
+    {
+      "id": "test",
+      "biomes": ["wasteland"],
+      "chance": 1.0,
+      "grid": [
+        "OOOO",
+        "O..O",
+        "O.bO",
+        "OOOO"
+      ],
+      "legend": {
+        "O": { "terrain": "huge_boulder" },
+        ".": { "terrain": "dirt", "items": ["rock"] },
+        "b": { "terrain": "dirt", "creature": "giant_bug" }
+      }
+    }
+    "#;
+
+    #[test]
+    fn test_prefab_across_chunk_border() {
+        let prefab: Prefab = serde_json::from_str(PREFAB).unwrap();
+        let noise = default_noise(42);
+        let mut rng = StdRng::seed_from_u64(42);
+        let anchor = Point::new(30, 30);
+        let mut left = generate(42, &noise, ChunkPos::new(0, 0));
+        let mut right = generate(42, &noise, ChunkPos::new(1, 1));
+        left.spawns.clear();
+        right.spawns.clear();
+        stamp(&prefab, anchor, &mut left, None, &mut rng);
+        stamp(&prefab, anchor, &mut right, None, &mut rng);
+
+        let (_, index) = anchor.to_chunk();
+        assert!(!left.surface[index].terrain.is_passable());
+        let (_, index) = Point::new(31, 31).to_chunk();
+        assert_eq!(1, left.surface[index].items.len());
+        let (_, index) = Point::new(33, 33).to_chunk();
+        assert!(!right.surface[index].terrain.is_passable());
+        assert!(left.spawns.is_empty());
+        assert_eq!(1, right.spawns.len());
+        assert_eq!(Point::new(32, 32), right.spawns[0].pos);
+    }
+
+    #[test]
+    fn test_prefabs_are_placed_somewhere() {
+        let noise = default_noise(42);
+        assert!((0..200).any(|x| !prefabs_around(42, &noise, ChunkPos::new(x, x)).is_empty()));
+    }
+}
@@ -0,0 +1,101 @@
+use bracket_noise::prelude::FastNoise;
+use rand::{prelude::StdRng, RngExt, SeedableRng};
+use roguemetry::Point;
+
+use crate::game::GameData;
+
+use super::{
+    super::{Chunk, Spawn, TerrainInteract, TerrainInteractAction, TilePos},
+    biomes::{Biome, Climate},
+    chunk_seed,
+};
+
+/// Wildlife of all the underground levels
+pub const CAVE_SPAWN_TABLE: &str = "cave_spawns";
+
+/// Tries to find a free tile for every creature of the group
+const PLACEMENT_TRIES: u8 = 10;
+
+/// Spawn table for the creatures living around the point
+pub fn spawn_table(noise: &FastNoise, pos: Point, z: i32) -> &'static str {
+    if z < 0 {
+        CAVE_SPAWN_TABLE
+    } else {
+        Biome::at(Climate::at(noise, pos)).spawn_table()
+    }
+}
+
+/// Adds a group of wildlife to the just generated chunk
+pub fn populate(world_seed: u64, noise: &FastNoise, chunk: &mut Chunk) {
+    let pos = chunk.pos;
+    // separate rng, so creatures don't change the terrain
+    let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed.rotate_left(3), pos));
+    let center = Point::from_chunk(pos, Chunk::USIZE / 2 + Chunk::SIZE as usize / 2);
+    let Some(table) = GameData::instance()
+        .spawn_tables
+        .get(spawn_table(noise, center, pos.z))
+    else {
+        return;
+    };
+    for creature in table.roll(&mut rng) {
+        for _ in 0..PLACEMENT_TRIES {
+            let index = rng.random_range(0..Chunk::USIZE);
+            let terrain = &chunk.surface[index].terrain;
+            let on_stairs = terrain.supports_action(TerrainInteractAction::GoDown)
+                || terrain.supports_action(TerrainInteractAction::GoUp);
+            let point = Point::from_chunk(pos, index);
+            if terrain.is_passable()
+                && !terrain.is_deep_water()
+                && !on_stairs
+                && chunk.spawns.iter().all(|spawn| spawn.pos != point)
+            {
+                chunk.spawns.push(Spawn {
+                    pos: point,
+                    z: pos.z,
+                    creature: creature.to_string(),
+                    wild: true,
+                });
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::{default_noise, Chunk, ChunkPos, TerrainInteract, TilePos};
+    use super::super::generate;
+
+    #[test]
+    fn test_chunks_are_populated() {
+        let noise = default_noise(42);
+        let mut creatures = 0;
+        for x in 0..10 {
+            let pos = ChunkPos::new(x, 3);
+            let chunk = generate(42, &noise, pos);
+            assert!(chunk.spawns.iter().all(|spawn| spawn.z == pos.z));
+            creatures += chunk.spawns.iter().filter(|s| s.wild).count();
+        }
+        assert!(creatures > 0);
+    }
+
+    #[test]
+    fn test_same_chunk_same_creatures() {
+        let noise = default_noise(42);
+        let pos = ChunkPos::new(-3, -2).with_z(-1);
+        let first = generate(42, &noise, pos);
+        let second = generate(42, &noise, pos);
+        let creatures = |chunk: &Chunk| {
+            chunk
+                .spawns
+                .iter()
+                .map(|s| (s.pos, s.creature.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(creatures(&first), creatures(&second));
+        for spawn in &first.spawns {
+            let (_, index) = spawn.pos.to_chunk();
+            assert!(first.surface[index].terrain.is_passable());
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use arrayvec::ArrayVec;
+use bracket_noise::prelude::FastNoise;
+use rand::{prelude::StdRng, RngExt, SeedableRng};
+use roguemetry::Point;
+
+use crate::game::{GameData, LootContext};
+
+use super::{
+    super::{
+        terrains::{Pit, Stairs},
+        Terrain, TerrainInteract, Tile, TilePos,
+    },
+    biomes::{Biome, Climate},
+    chunk_seed,
+    prefabs::{prefabs_around, stamp},
+    spawns::populate,
+    stairs_down,
+    water::Water,
+    Chunk, ChunkPos, WorldGen,
+};
+
+/// Picks a biome for every tile, so borders between them follow the climate, not chunks
+pub struct Surface;
+
+impl WorldGen for Surface {
+    fn generate(world_seed: u64, noise: &FastNoise, pos: ChunkPos) -> Chunk {
+        let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed, pos));
+        let stairs = stairs_down(world_seed, pos);
+        let mut surface = ArrayVec::new();
+        for i in 0..Chunk::USIZE {
+            let point = Point::from_chunk(pos, i);
+            let climate = Climate::at(noise, point);
+            // borders are a bit ragged
+            let biome = Biome::at(climate.jitter(&mut rng));
+            let water = Water::at(noise, climate, point);
+            let terrain: Terrain = if stairs == Some(i) {
+                Stairs::down().into()
+            } else if let Some(water) = water {
+                water.terrain(&mut rng)
+            } else if rng.random_bool(0.001) {
+                Pit::new().into()
+            } else {
+                biome.terrain(climate, &mut rng)
+            };
+
+            let mut tile = Tile::new(terrain);
+            // nothing is lying on the bottom or where nobody can reach it
+            if water.is_none() && tile.terrain.is_passable() {
+                if let Some(scatter) = GameData::instance().loot_tables.get(biome.scatter_table()) {
+                    let context = LootContext {
+                        biome: Some(biome),
+                        ..LootContext::default()
+                    };
+                    tile.items = scatter.roll(context, &mut rng);
+                }
+            }
+            surface.push(tile);
+        }
+        let mut chunk = Chunk {
+            pos,
+            surface,
+            spawns: Vec::new(),
+        };
+        for (anchor, prefab) in prefabs_around(world_seed, noise, pos) {
+            stamp(prefab, anchor, &mut chunk, stairs, &mut rng);
+        }
+        populate(world_seed, noise, &mut chunk);
+        chunk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::{default_noise, ChunkPos, TerrainInteract};
+    use super::super::generate;
+
+    #[test]
+    fn test_items_are_scattered_only_where_reachable() {
+        let noise = default_noise(42);
+        let mut scattered = 0;
+        for x in 0..20 {
+            for tile in &generate(42, &noise, ChunkPos::new(x, -x)).surface {
+                if !tile.items.is_empty() {
+                    assert!(tile.terrain.is_passable());
+                    scattered += 1;
+                }
+            }
+        }
+        assert!(scattered > 0);
+    }
+}
@@ -0,0 +1,115 @@
+use arrayvec::ArrayVec;
+use bracket_noise::prelude::FastNoise;
+use rand::{prelude::StdRng, RngExt, SeedableRng};
+use roguemetry::Point;
+
+use super::{
+    super::{
+        terrains::{DataTerrain, Stairs},
+        Terrain, Tile, TilePos,
+    },
+    chunk_seed,
+    spawns::populate,
+    stairs_down, Chunk, ChunkPos, WorldGen,
+};
+
+/// Caves carved in the solid rock
+pub struct Underground;
+
+impl Underground {
+    /// Caves are narrower on the deeper levels
+    fn cave_threshold(z: i32) -> f32 {
+        (0.15 + z as f32 * 0.03).max(-0.05)
+    }
+}
+
+impl WorldGen for Underground {
+    fn generate(world_seed: u64, noise: &FastNoise, pos: ChunkPos) -> Chunk {
+        let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed, pos));
+        let threshold = Self::cave_threshold(pos.z);
+        let stairs_up =
+            stairs_down(world_seed, pos.with_z(pos.z + 1)).map(|i| Point::from_chunk(pos, i));
+        let stairs_down = stairs_down(world_seed, pos).map(|i| Point::from_chunk(pos, i));
+        let mut surface = ArrayVec::new();
+        for i in 0..Chunk::USIZE {
+            let point = Point::from_chunk(pos, i);
+            let rock = noise.get_noise3d(
+                point.x as f32 / Chunk::NOISE_SIZE,
+                point.y as f32 / Chunk::NOISE_SIZE,
+                pos.z as f32,
+            );
+            let near_stairs = [stairs_up, stairs_down]
+                .into_iter()
+                .flatten()
+                .any(|stairs| stairs.square_distance_to(point) <= 2);
+            let terrain: Terrain = if Some(point) == stairs_up {
+                Stairs::up().into()
+            } else if Some(point) == stairs_down {
+                Stairs::down().into()
+            } else if rock > threshold && !near_stairs {
+                DataTerrain::new("huge_boulder", &mut rng).into()
+            } else if rng.random_bool(0.03) {
+                let boulder = if rng.random_bool(0.3) {
+                    "boulder"
+                } else {
+                    "small_boulder"
+                };
+                DataTerrain::new(boulder, &mut rng).into()
+            } else {
+                DataTerrain::new("dirt", &mut rng).into()
+            };
+
+            surface.push(Tile::new(terrain));
+        }
+        let mut chunk = Chunk {
+            pos,
+            surface,
+            spawns: Vec::new(),
+        };
+        populate(world_seed, noise, &mut chunk);
+        chunk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bracket_noise::prelude::FastNoise;
+
+    use super::super::{
+        super::{terrain::TerrainInteract, ChunkPos, Terrain, TerrainInteractAction},
+        generate,
+    };
+
+    #[test]
+    fn test_stairs_connect_levels() {
+        let noise = FastNoise::seeded(42);
+        let (pos, down) = (0..100)
+            .find_map(|x| {
+                let pos = ChunkPos::new(x, 0);
+                generate(42, &noise, pos)
+                    .surface
+                    .iter()
+                    .position(|t| matches!(t.terrain, Terrain::Stairs(..)))
+                    .map(|i| (pos, i))
+            })
+            .expect("There should be stairs down somewhere");
+
+        let below = generate(42, &noise, pos.with_z(-1));
+        let tile = &below.surface[down];
+        assert!(tile.terrain.supports_action(TerrainInteractAction::GoUp));
+        assert!(tile.terrain.is_passable());
+    }
+
+    #[test]
+    fn test_caves_have_walls_and_floor() {
+        let noise = FastNoise::seeded(42);
+        let chunk = generate(42, &noise, ChunkPos::new(0, 0).with_z(-1));
+        let passable = chunk
+            .surface
+            .iter()
+            .filter(|t| t.terrain.is_passable())
+            .count();
+        assert!(passable > 0);
+        assert!(passable < chunk.surface.len());
+    }
+}
@@ -0,0 +1,97 @@
+use bracket_noise::prelude::FastNoise;
+use rand::prelude::StdRng;
+use roguemetry::Point;
+
+use super::{
+    super::{terrains::DataTerrain, Chunk, Terrain},
+    biomes::Climate,
+};
+
+/// Lakes are filling the lowest places
+const LAKE_SHORE: f32 = -0.24;
+const LAKE_DEPTH: f32 = -0.27;
+/// Half-width of the river, in the units of its noise
+const RIVER_BANK: f32 = 0.006;
+const RIVER_DEPTH: f32 = 0.003;
+/// Rivers don't flow through the highlands
+const RIVER_MAX_ELEVATION: f32 = 0.12;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Water {
+    Shallow,
+    Deep,
+}
+
+impl Water {
+    /// Lakes are in the lowlands and rivers are winding along the zero line of their own noise
+    pub fn at(noise: &FastNoise, climate: Climate, pos: Point) -> Option<Self> {
+        if climate.elevation < LAKE_DEPTH {
+            return Some(Water::Deep);
+        }
+        if climate.elevation < LAKE_SHORE {
+            return Some(Water::Shallow);
+        }
+        if climate.elevation > RIVER_MAX_ELEVATION {
+            return None;
+        }
+
+        let x = pos.x as f32 / Chunk::NOISE_SIZE;
+        let y = pos.y as f32 / Chunk::NOISE_SIZE;
+        let river = noise.get_noise(x / 2.0 + 300.5, y / 2.0 + 300.5).abs();
+        if river < RIVER_DEPTH {
+            Some(Water::Deep)
+        } else if river < RIVER_BANK {
+            Some(Water::Shallow)
+        } else {
+            None
+        }
+    }
+
+    pub fn terrain(self, rng: &mut StdRng) -> Terrain {
+        match self {
+            Water::Shallow => DataTerrain::new("shallow_water", rng).into(),
+            Water::Deep => DataTerrain::new("deep_water", rng).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::Point;
+
+    use super::super::super::{default_noise, ChunkPos, TerrainInteract};
+    use super::super::{biomes::Climate, generate};
+    use super::Water;
+
+    #[test]
+    fn test_there_are_lakes_and_rivers() {
+        let noise = default_noise(42);
+        let mut shallow = 0;
+        let mut deep = 0;
+        for x in -100..100 {
+            for y in -100..100 {
+                let pos = Point::new(x * 10, y * 10);
+                match Water::at(&noise, Climate::at(&noise, pos), pos) {
+                    Some(Water::Shallow) => shallow += 1,
+                    Some(Water::Deep) => deep += 1,
+                    None => {}
+                }
+            }
+        }
+        assert!(shallow > 100, "{shallow}");
+        assert!(deep > 100, "{deep}");
+        assert!(shallow + deep < 40_000 / 5, "{shallow} {deep}");
+    }
+
+    #[test]
+    fn test_water_in_generated_chunks() {
+        let noise = default_noise(42);
+        let wet = (0..100).any(|x| {
+            generate(42, &noise, ChunkPos::new(x, x))
+                .surface
+                .iter()
+                .any(|tile| tile.terrain.is_deep_water())
+        });
+        assert!(wet);
+    }
+}
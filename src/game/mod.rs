@@ -1,30 +1,44 @@
 #![allow(unused_imports)]
 
 pub use actions::{Action, ActionType};
-pub use ai::AI;
+pub use ai::{AIManager, AI};
+pub use calendar::{DateTime, Season, TimeOfDay};
+pub use detection::Detection;
+pub use events::{EventBus, EventSubscriber, GameEvent};
 pub use game_data::{
-    AmmoType, DamageValue, GameData, ItemPrototype, ItemQuality, ItemSize, Material,
+    AmmoType, CreaturePrototype, DamageValue, GameData, ItemPrototype, ItemQuality, ItemSize,
+    LootContext, LootTable, Material, Prefab, Recipe, TerrainId, TerrainPrototype,
 };
 pub use log::{Log, LogEvent};
 pub use map::{
     Chunk, ChunkPos, Item, Map, Terrain, TerrainInteract, TerrainInteractAction, TerrainView, Tile,
     TilePos,
 };
+pub use population::Population;
 pub use races::{BodySlot, Race};
 pub use savage::{
     AttackType, AttrLevel, Attribute, CharSheet, Damage, DamageDice, DamageRollResult, DamageType,
     Dice, DiceStack, RangedDistance, RollResult, Skill, SkillLevel, Wound,
 };
+pub use scheduler::Scheduler;
 pub use units::{Avatar, Fighter};
-pub use world::World;
+pub use weather::{Weather, WeatherKind};
+pub use world::{World, WorldRng};
 
 pub mod actions;
 mod ai;
+pub mod calendar;
+mod detection;
+pub mod events;
 mod game_data;
+pub mod light;
 pub mod log;
 pub mod map;
+mod population;
 pub mod races;
 mod savage;
+mod scheduler;
 pub mod traits;
 pub mod units;
+pub mod weather;
 pub mod world;
@@ -0,0 +1,32 @@
+use super::calendar::TICKS_PER_MINUTE;
+
+/// Wildlife isn't spawned if that many creatures are alive around the player
+pub const POPULATION_CAP: usize = 24;
+/// How often new creatures come to the places around the player
+pub const MIGRATION_PERIOD: u128 = TICKS_PER_MINUTE * 30;
+/// Migrants appear out of sight and not closer than that
+pub const MIGRATION_MIN_DISTANCE: u32 = 20;
+
+/// Keeps the loaded bubble alive but not crowded
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct Population {
+    /// Maximum number of the alive loaded units besides the player
+    pub cap: usize,
+    /// Tick of the next migration
+    pub next_migration: u128,
+}
+
+impl Population {
+    #[must_use]
+    pub fn new(current_tick: u128) -> Self {
+        Self {
+            cap: POPULATION_CAP,
+            next_migration: current_tick + MIGRATION_PERIOD,
+        }
+    }
+
+    #[must_use]
+    pub fn is_full(&self, population: usize) -> bool {
+        population >= self.cap
+    }
+}
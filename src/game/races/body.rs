@@ -14,6 +14,7 @@ pub enum BodySlot {
 }
 
 impl BodySlot {
+    #[must_use]
     pub fn name(self) -> &'static str {
         match self {
             BodySlot::Head => "head",
@@ -3,9 +3,8 @@ use std::fmt::{Display, Formatter};
 use rand::distr::{Distribution, StandardUniform};
 use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
 
-use crate::colors::Colors;
+use crate::colors::{Color, Colors};
 use crate::game::traits::Name;
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
@@ -33,6 +32,7 @@ pub enum BodyColor {
 }
 
 impl BodyColor {
+    #[must_use]
     pub fn text_color(self) -> Color {
         match self {
             BodyColor::Albino
@@ -121,6 +121,10 @@ impl Display for BodyColor {
     }
 }
 
+/// # Panics
+///
+/// Panics if `current` is not one of the `colors`
+#[must_use]
 pub fn next_color(current: BodyColor, colors: &[BodyColor], forward: bool) -> BodyColor {
     let max_i = colors.len() - 1;
     let mut i = colors.iter().position(|&c| c == current).unwrap();
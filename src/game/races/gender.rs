@@ -15,6 +15,7 @@ pub enum Gender {
 }
 
 impl Gender {
+    #[must_use]
     pub fn pronouns(&self) -> Pronouns {
         match self {
             Gender::Male => Pronouns::HeHim,
@@ -11,18 +11,22 @@ pub enum Pronouns {
 }
 
 impl Pronouns {
+    #[must_use]
     pub fn verb_ends_with_s(self) -> bool {
         self.third_person() && !self.plural()
     }
 
+    #[must_use]
     pub fn plural(self) -> bool {
         self == Pronouns::Plural
     }
 
+    #[must_use]
     pub fn third_person(self) -> bool {
         self != Pronouns::YouYour
     }
 
+    #[must_use]
     pub fn is_are(self) -> &'static str {
         if self.plural() || self == Pronouns::YouYour {
             "are"
@@ -32,6 +36,7 @@ impl Pronouns {
     }
 
     /// Returns the pronoun's subjective form, like in "*She* went to Grit Gate."
+    #[must_use]
     pub fn subjective(self) -> &'static str {
         match self {
             Pronouns::YouYour => "you",
@@ -44,6 +49,7 @@ impl Pronouns {
     }
 
     /// Returns the pronoun's objective form, like in "You are water-bonded with *him*."
+    #[must_use]
     pub fn objective(self) -> &'static str {
         match self {
             Pronouns::YouYour => "you",
@@ -56,6 +62,7 @@ impl Pronouns {
     }
 
     /// Returns the pronoun's possessive form, like in "*Her* Issachar rifle rusted."
+    #[must_use]
     pub fn possessive_adjective(self) -> &'static str {
         match self {
             Pronouns::YouYour => "your",
@@ -68,6 +75,7 @@ impl Pronouns {
     }
 
     /// Returns the pronoun's possessive form, like in "The rifle is *hers*."
+    #[must_use]
     pub fn substantive_possessive(self) -> &'static str {
         match self {
             Pronouns::YouYour => "yours",
@@ -80,6 +88,7 @@ impl Pronouns {
     }
 
     /// Returns the pronoun's reflexive form, like in "He can only blame *himself* for eating the Cloaca Surprise."
+    #[must_use]
     pub fn reflexive(self) -> &'static str {
         match self {
             Pronouns::YouYour => "yourself",
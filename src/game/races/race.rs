@@ -5,13 +5,13 @@ use rand::distr::{Distribution, StandardUniform};
 use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
 
-use crate::assets::Sprite;
 use crate::game::{
     savage::{DamageDice, DamageType, Skill},
     traits::{LooksLike, Name},
     units::{Inventory, Weapon},
     DamageValue, SkillLevel,
 };
+use crate::sprite::Sprite;
 
 use super::BodyColor;
 
@@ -26,6 +26,7 @@ pub enum Race {
 }
 
 impl Race {
+    #[must_use]
     pub fn is_intelligent(self) -> bool {
         match self {
             Self::Gazan | Self::Lagnam | Self::Totik | Self::Nyarnik => true,
@@ -33,10 +34,12 @@ impl Race {
         }
     }
 
+    #[must_use]
     pub fn has_custom_colors(self) -> bool {
         !self.custom_colors().is_empty()
     }
 
+    #[must_use]
     pub fn custom_colors(self) -> Vec<BodyColor> {
         match self {
             Self::Gazan => vec![
@@ -81,6 +84,7 @@ impl Race {
         }
     }
 
+    #[must_use]
     pub fn free_skills(self) -> HashMap<Skill, SkillLevel> {
         HashMap::from_iter(match self {
             Race::Gazan => vec![(Skill::Climbing, SkillLevel::D6)],
@@ -89,6 +93,7 @@ impl Race {
         })
     }
 
+    #[must_use]
     pub fn natural_armor(self) -> i8 {
         match self {
             Race::Nyarnik => 2,
@@ -96,6 +101,7 @@ impl Race {
         }
     }
 
+    #[must_use]
     pub fn walk_koeff(self) -> f32 {
         match self {
             Race::Nyarnik => 1.2,
@@ -108,6 +114,7 @@ impl Race {
         enum_iterator::all()
     }
 
+    #[must_use]
     pub fn natural_weapon(self) -> Weapon {
         // TODO: consts for names
         match self {
@@ -130,6 +137,7 @@ impl Race {
         }
     }
 
+    #[must_use]
     pub fn inventory(self) -> Inventory {
         match self {
             Race::Gazan | Race::Nyarnik | Race::Totik | Race::Lagnam => Inventory::humanoid(),
@@ -177,10 +185,12 @@ pub enum PlayableRace {
 }
 
 impl PlayableRace {
+    #[must_use]
     pub fn next(self) -> Self {
         next_cycle(&self)
     }
 
+    #[must_use]
     pub fn prev(self) -> Self {
         previous_cycle(&self)
     }
@@ -1,9 +1,8 @@
 use rand::distr::{Distribution, StandardUniform};
 use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
 
-use crate::colors::Colors;
+use crate::colors::{Color, Colors};
 
 use super::{AttrLevel, Dice};
 
@@ -78,6 +77,7 @@ impl Attribute {
     const STRENGTH_COLOR: Color = Colors::ORANGE_RED;
     const VIGOR_COLOR: Color = Colors::VIOLET;
 
+    #[must_use]
     pub fn color(self) -> Color {
         match self {
             Attribute::Agility => Self::AGILITY_COLOR,
@@ -88,6 +88,7 @@ impl Attribute {
         }
     }
 
+    #[must_use]
     pub fn name(self) -> &'static str {
         match self {
             Attribute::Agility => "Agility",
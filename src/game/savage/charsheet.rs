@@ -1,13 +1,26 @@
 use rand::{Rng, RngExt};
 
-use crate::game::RollResult;
+use crate::game::{
+    calendar::{TICKS_PER_DAY, TICKS_PER_MINUTE},
+    RollResult,
+};
 
 use super::{
     super::Race, Attribute, Attributes, Dice, DiceWithModifier, HitResult, Skill, SkillLevel,
-    Skills, Wound,
+    Skills, Wound, ELEVATED_SIGHT_BONUS,
 };
 
+/// Fatigued gives -1 to all rolls and Exhausted gives -2, there is nothing worse
+pub const FATIGUE_LIMIT: u8 = 2;
+/// Time to get rid of one level of Fatigue
+pub const FATIGUE_RECOVERY: u128 = TICKS_PER_MINUTE;
+/// Ticks to go from a full stomach to starving
+pub const NUTRITION_MAX: u32 = (TICKS_PER_DAY * 3) as u32;
+/// Ticks to go from a quenched thirst to dehydration
+pub const HYDRATION_MAX: u32 = TICKS_PER_DAY as u32;
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct CharSheet {
     #[serde(default)]
     pub wild_card: bool,
@@ -19,9 +32,41 @@ pub struct CharSheet {
     pub shock: bool,
     #[serde(default)]
     pub last_shock_out_roll: u128,
+    /// Moving slowly and quietly
+    #[serde(default)]
+    pub sneaking: bool,
+    /// Standing on top of a boulder or up a tree
+    #[serde(default)]
+    pub elevated: bool,
+    /// Levels of Fatigue, from swimming and such
+    #[serde(default)]
+    pub fatigue: u8,
+    #[serde(default)]
+    pub last_fatigue: u128,
+    /// `None` until the first swim
+    #[serde(default)]
+    pub last_swimming_roll: Option<u128>,
+    /// Ticks left until starving
+    #[serde(default = "full_nutrition")]
+    pub nutrition: u32,
+    /// Ticks left until dehydration
+    #[serde(default = "full_hydration")]
+    pub hydration: u32,
+    /// `None` until the first meal is digested, old saves start from now too
+    #[serde(default)]
+    pub last_metabolism: Option<u128>,
+}
+
+fn full_nutrition() -> u32 {
+    NUTRITION_MAX
+}
+
+fn full_hydration() -> u32 {
+    HYDRATION_MAX
 }
 
 impl CharSheet {
+    #[must_use]
     pub fn new(wild_card: bool, race: Race, attributes: Attributes, skills: Skills) -> Self {
         Self {
             wild_card,
@@ -31,9 +76,18 @@ impl CharSheet {
             wounds: vec![],
             shock: false,
             last_shock_out_roll: 0,
+            sneaking: false,
+            elevated: false,
+            fatigue: 0,
+            last_fatigue: 0,
+            last_swimming_roll: None,
+            nutrition: NUTRITION_MAX,
+            hydration: HYDRATION_MAX,
+            last_metabolism: None,
         }
     }
 
+    #[must_use]
     pub fn default(wild_card: bool, race: Race) -> Self {
         let attributes = Attributes::default();
         let skills = Skills::default(race);
@@ -44,6 +98,12 @@ impl CharSheet {
         self.wounds.clear();
         self.shock = false;
         self.last_shock_out_roll = 0;
+        self.fatigue = 0;
+        self.last_fatigue = 0;
+        self.last_swimming_roll = None;
+        self.nutrition = NUTRITION_MAX;
+        self.hydration = HYDRATION_MAX;
+        self.last_metabolism = None;
         self.attributes = Attributes::default();
         self.skills = Skills::default(self.race);
     }
@@ -54,10 +114,12 @@ impl CharSheet {
         Self::new(wild_card, race, attributes, skills)
     }
 
+    #[must_use]
     pub fn calc_skill_points(&self) -> i8 {
         self.skills.calc_skill_points(&self.attributes, self.race)
     }
 
+    #[must_use]
     pub fn parry(&self) -> u8 {
         let fighting_skill = self.skills.get_skill(Skill::Fighting);
         2 + if fighting_skill > SkillLevel::None {
@@ -67,10 +129,12 @@ impl CharSheet {
         }
     }
 
+    #[must_use]
     pub fn toughness(&self) -> u8 {
         2 + self.attributes.get_attribute(Attribute::Vigor).value() / 2
     }
 
+    #[must_use]
     pub fn walk_koeff(&self) -> f32 {
         // TODO: write tests for moving for different races and wounds
         let mut speed = self.race.walk_koeff();
@@ -86,6 +150,7 @@ impl CharSheet {
         speed.clamp(0.0, 10.0)
     }
 
+    #[must_use]
     pub fn get_attribute_with_modifiers(&self, attribute: Attribute) -> DiceWithModifier {
         let mut attr_level = self.attributes.get_attribute(attribute);
         match attribute {
@@ -112,22 +177,28 @@ impl CharSheet {
             Attribute::Spirit => {}
         }
         let mut dice = DiceWithModifier::from(attr_level);
-        dice.1 -= self.wounds.len() as i8;
+        dice.1 -= self.wounds.len() as i8 + self.fatigue_penalty() as i8;
         dice
     }
 
+    #[must_use]
     pub fn get_skill_with_modifiers(&self, skill: Skill) -> DiceWithModifier {
         DiceWithModifier::from(self.skills.get_skill(skill))
-            .with_modifier(-(self.wounds.len() as i8))
+            .with_modifier(-(self.wounds.len() as i8 + self.fatigue_penalty() as i8))
     }
 
-    pub fn roll_skill(&self, skill: Skill, modifier: i8) -> RollResult {
+    pub fn roll_skill<R: Rng + ?Sized>(
+        &self,
+        skill: Skill,
+        modifier: i8,
+        rng: &mut R,
+    ) -> RollResult {
         let skill_dice = self.get_skill_with_modifiers(skill).with_modifier(modifier);
-        let roll = skill_dice.roll_explosive();
+        let roll = skill_dice.roll_explosive(rng);
 
         if self.wild_card && roll.natural != 1 {
             let wild_dice = DiceWithModifier::new(Dice::D6, skill_dice.modifier());
-            let wild_roll = wild_dice.roll_explosive();
+            let wild_roll = wild_dice.roll_explosive(rng);
             if wild_roll.total > roll.total {
                 wild_roll
             } else {
@@ -146,26 +217,28 @@ impl CharSheet {
         self.wounds.append(&mut hit.consequences.wounds);
     }
 
+    #[must_use]
     pub fn is_dead(&self) -> bool {
         let wounds_limit = if self.wild_card { 3 } else { 0 };
         self.wounds.len() > wounds_limit
     }
 
+    #[must_use]
     pub fn can_try_to_shock_out(&self, current_tick: u128) -> bool {
         self.shock && (current_tick - self.last_shock_out_roll) >= 10
     }
 
-    pub fn try_to_shock_out(&mut self, current_tick: u128) -> bool {
+    pub fn try_to_shock_out<R: Rng + ?Sized>(&mut self, current_tick: u128, rng: &mut R) -> bool {
         if !self.can_try_to_shock_out(current_tick) {
             return false;
         }
         self.last_shock_out_roll = current_tick;
         let mut roll = self
             .get_attribute_with_modifiers(Attribute::Spirit)
-            .roll_explosive();
+            .roll_explosive(rng);
         if self.wild_card && roll.natural != 1 {
             let wild_roll =
-                DiceWithModifier::new(Dice::D6, -(self.wounds.len() as i8)).roll_explosive();
+                DiceWithModifier::new(Dice::D6, -(self.wounds.len() as i8)).roll_explosive(rng);
             if wild_roll.total > roll.total {
                 roll = wild_roll;
             }
@@ -178,9 +251,81 @@ impl CharSheet {
         }
     }
 
+    /// Adds a level of Fatigue, returns `false` if the unit is already Exhausted
+    pub fn add_fatigue(&mut self, current_tick: u128) -> bool {
+        self.last_fatigue = current_tick;
+        if self.fatigue >= FATIGUE_LIMIT {
+            return false;
+        }
+        self.fatigue += 1;
+        true
+    }
+
+    /// Recovers from one level of Fatigue if rested enough
+    pub fn try_to_recover(&mut self, current_tick: u128) -> bool {
+        if self.fatigue == 0 || current_tick - self.last_fatigue < FATIGUE_RECOVERY {
+            return false;
+        }
+        self.fatigue -= 1;
+        self.last_fatigue = current_tick;
+        true
+    }
+
+    /// Hungry gives a level of Fatigue and starving gives two
+    #[must_use]
+    pub fn hunger(&self) -> u8 {
+        meter_level(self.nutrition, NUTRITION_MAX)
+    }
+
+    /// Thirsty gives a level of Fatigue and dehydrated gives two
+    #[must_use]
+    pub fn thirst(&self) -> u8 {
+        meter_level(self.hydration, HYDRATION_MAX)
+    }
+
+    /// Levels of Fatigue from all the sources, they add up to Exhausted at most
+    #[must_use]
+    pub fn fatigue_penalty(&self) -> u8 {
+        (self.fatigue + self.hunger() + self.thirst()).min(FATIGUE_LIMIT)
+    }
+
+    /// Drains nutrition and hydration for the time passed
+    pub fn metabolize(&mut self, current_tick: u128) {
+        if let Some(last) = self.last_metabolism {
+            let passed = u32::try_from(current_tick - last).unwrap_or(u32::MAX);
+            self.exert(passed);
+        }
+        self.last_metabolism = Some(current_tick);
+    }
+
+    /// Drains nutrition and hydration for the hard work
+    pub fn exert(&mut self, ticks: u32) {
+        self.nutrition = self.nutrition.saturating_sub(ticks);
+        self.hydration = self.hydration.saturating_sub(ticks);
+    }
+
+    pub fn eat(&mut self, nutrition: u32, hydration: u32) {
+        self.nutrition = (self.nutrition + nutrition).min(NUTRITION_MAX);
+        self.hydration = (self.hydration + hydration).min(HYDRATION_MAX);
+    }
+
+    #[must_use]
     pub fn sight_range(&self) -> u32 {
         // TODO: traits
         let notice = self.get_skill_with_modifiers(Skill::Notice);
-        notice.value() as u32 * 5 + if self.wild_card { 10 } else { 5 }
+        let mut range = notice.value() as u32 * 5 + if self.wild_card { 10 } else { 5 };
+        if self.elevated {
+            range += ELEVATED_SIGHT_BONUS;
+        }
+        range
+    }
+}
+
+/// Empty meter is two levels of Fatigue, and the last third of it is one
+fn meter_level(value: u32, max: u32) -> u8 {
+    if value == 0 {
+        2
+    } else {
+        u8::from(value < max / 3)
     }
 }
@@ -0,0 +1,52 @@
+use rand::Rng;
+
+use super::{CharSheet, Dice, DiceStack, Skill};
+
+/// Bonus to ranged attacks from above
+pub const ELEVATED_ATTACK_BONUS: i8 = 1;
+/// Elevated units can see further, in tiles
+pub const ELEVATED_SIGHT_BONUS: u32 = 5;
+
+/// Climbing roll to get on top of the terrain, unskilled ones are rolling d4-2
+pub fn climbing_roll<R: Rng + ?Sized>(char_sheet: &CharSheet, penalty: i8, rng: &mut R) -> bool {
+    char_sheet
+        .roll_skill(Skill::Climbing, penalty, rng)
+        .success()
+}
+
+/// Damage of the fall from a couple of yards
+pub fn fall_damage<R: Rng + ?Sized>(rng: &mut R) -> u8 {
+    DiceStack::from((Dice::D6, Dice::D6)).roll_total(rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::{CharSheet, Race, WorldRng};
+
+    use super::{climbing_roll, fall_damage};
+
+    #[test]
+    fn test_gazans_are_good_climbers() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let gazan = CharSheet::default(false, Race::Gazan);
+        let totik = CharSheet::default(false, Race::Totik);
+
+        let gazan_climbs = (0..100)
+            .filter(|_| climbing_roll(&gazan, 0, &mut rng))
+            .count();
+        let totik_climbs = (0..100)
+            .filter(|_| climbing_roll(&totik, 0, &mut rng))
+            .count();
+        assert!(gazan_climbs > totik_climbs);
+    }
+
+    #[test]
+    fn test_fall_damage() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        for _ in 0..100 {
+            assert!((2..=12).contains(&fall_damage(&mut rng)));
+        }
+    }
+}
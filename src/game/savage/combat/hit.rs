@@ -1,6 +1,8 @@
+use rand::Rng;
+
 use crate::game::{Attribute, Avatar, BodySlot, Fighter, Wound};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HitResult {
     pub params: HitParams,
     pub consequences: HitConsequences,
@@ -14,7 +16,13 @@ impl HitResult {
         }
     }
 
-    pub fn calculate(damage: u8, penetration: u8, target: &dyn Fighter, critical: bool) -> Self {
+    pub fn calculate<R: Rng + ?Sized>(
+        damage: u8,
+        penetration: u8,
+        target: &dyn Fighter,
+        critical: bool,
+        rng: &mut R,
+    ) -> Self {
         let toughness = target.toughness() as i8;
 
         // TODO: attack random parts of the body
@@ -45,20 +53,28 @@ impl HitResult {
 
         Self::new(
             HitParams::new(damage, penetration, critical),
-            HitConsequences::random_wounds(shock, wounds),
+            HitConsequences::random_wounds(shock, wounds, rng),
+        )
+    }
+
+    /// Single wound without an attacker, like from drowning
+    pub fn wound<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(
+            HitParams::new(0, 0, false),
+            HitConsequences::random_wounds(true, 1, rng),
         )
     }
 
     #[cfg(test)]
-    pub fn ultra_damage() -> Self {
+    pub fn ultra_damage<R: Rng + ?Sized>(rng: &mut R) -> Self {
         Self::new(
             HitParams::new(100, 100, true),
-            HitConsequences::random_wounds(true, 4),
+            HitConsequences::random_wounds(true, 4, rng),
         )
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HitParams {
     pub damage: u8,
     pub penetration: u8,
@@ -75,7 +91,7 @@ impl HitParams {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HitConsequences {
     pub shock: bool,
     pub wounds: Vec<Wound>,
@@ -89,10 +105,10 @@ impl HitConsequences {
         }
     }
 
-    pub fn random_wounds(shock: bool, wounds: u8) -> Self {
+    pub fn random_wounds<R: Rng + ?Sized>(shock: bool, wounds: u8, rng: &mut R) -> Self {
         Self {
             shock,
-            wounds: (0..wounds).map(|_| Wound::random()).collect(),
+            wounds: (0..wounds).map(|_| Wound::random(rng)).collect(),
         }
     }
 }
@@ -130,7 +146,8 @@ mod tests {
         let armor = avatar.armor(BodySlot::Torso);
         assert_eq!(armor, 2);
 
-        let hit_result = HitResult::calculate(damage, penetration, &avatar, false);
+        let hit_result =
+            HitResult::calculate(damage, penetration, &avatar, false, &mut rand::rng());
         assert_eq!(hit_result.consequences.shock, shock);
         assert_eq!(hit_result.consequences.wounds.len(), wounds);
     }
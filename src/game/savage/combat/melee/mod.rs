@@ -1,12 +1,14 @@
 pub use terrain::{melee_smash_terrain, TerrainMeleeAttackResult};
 pub use unit_simple::{melee_attack_unit, UnitMeleeAttackResult};
 
+use rand::Rng;
+
 use crate::game::{AttackType, Avatar, Fighter, Skill};
 
 mod terrain;
 mod unit_simple;
 
-fn fighting_roll(attacker: &dyn Fighter) -> i8 {
+fn fighting_roll<R: Rng + ?Sized>(attacker: &dyn Fighter, rng: &mut R) -> i8 {
     attacker
         .as_avatar()
         .char_sheet()
@@ -17,6 +19,7 @@ fn fighting_roll(attacker: &dyn Fighter) -> i8 {
                 .unwrap()
                 .damage
                 .attack_modifier,
+            rng,
         )
         .total
 }
@@ -1,13 +1,19 @@
+use rand::Rng;
+
 use crate::game::{AttackType, Avatar, Fighter};
 
 use super::{super::HitResult, fighting_roll};
 
 // TODO: special attacks: both hands, kick, agressive, etc.
-pub fn melee_attack_unit(attacker: &dyn Fighter, defender: &dyn Fighter) -> UnitMeleeAttackResult {
+pub fn melee_attack_unit<R: Rng + ?Sized>(
+    attacker: &dyn Fighter,
+    defender: &dyn Fighter,
+    rng: &mut R,
+) -> UnitMeleeAttackResult {
     // TODO: add +1 to hit for every ally
     // TODO: Attack of unarmed enemy while attacker is armed causes +2 to Fighting skill rolls
     // TODO: some traits make some avatars armed even if they don't have weapons
-    let hit_roll = fighting_roll(attacker);
+    let hit_roll = fighting_roll(attacker, rng);
     let parry = defender.parry() as i8;
 
     if hit_roll >= parry {
@@ -15,13 +21,14 @@ pub fn melee_attack_unit(attacker: &dyn Fighter, defender: &dyn Fighter) -> Unit
         let critical = delta >= 4;
 
         let melee_damage = attacker.weapon(AttackType::Melee).unwrap().damage;
-        let damage = melee_damage.roll(attacker.as_avatar().char_sheet(), critical, true);
+        let damage = melee_damage.roll(rng, attacker.as_avatar().char_sheet(), critical, true);
 
         UnitMeleeAttackResult::Hit(HitResult::calculate(
             damage.damage,
             damage.penetration,
             defender,
             critical,
+            rng,
         ))
     } else {
         UnitMeleeAttackResult::Miss
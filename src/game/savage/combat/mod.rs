@@ -18,6 +18,7 @@ pub enum AttackType {
 }
 
 impl AttackType {
+    #[must_use]
     pub fn name(self) -> &'static str {
         match self {
             AttackType::Melee => "melee",
@@ -27,6 +28,7 @@ impl AttackType {
     }
 
     /// E.g. "shoot from[ a bow]", "throw[ a spear]", "attack with[ a sword]"
+    #[must_use]
     pub fn verb_a(self, with_s: bool, weapon: &Weapon) -> String {
         let weapon_name = a(&weapon.name);
         if with_s {
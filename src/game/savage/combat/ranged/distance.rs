@@ -8,6 +8,7 @@ pub enum RangedDistance {
 }
 
 impl RangedDistance {
+    #[must_use]
     pub fn define(distance: f32, weapon_distance: u8) -> Self {
         let distance = distance.round() as u8;
         if distance <= 1 {
@@ -23,6 +24,7 @@ impl RangedDistance {
         }
     }
 
+    #[must_use]
     pub fn modifier(self) -> i8 {
         match self {
             Self::Close => 0,
@@ -1,10 +1,12 @@
-use rand::seq::IndexedRandom;
+use rand::{seq::IndexedRandom, Rng};
 use roguemetry::{Point, DIR8};
 use std::collections::HashMap;
 
 pub use distance::RangedDistance;
 
-use crate::game::{AttackType, Avatar, Fighter, RollResult, Skill, World};
+use crate::game::{units::Units, AttackType, Avatar, Fighter, Map, RollResult, Skill};
+
+use super::super::ELEVATED_ATTACK_BONUS;
 
 use super::HitResult;
 
@@ -20,13 +22,15 @@ pub enum UnitRangedAttackResult {
     Impossible,
 }
 
-pub fn ranged_attack_unit(
+pub fn ranged_attack_unit<R: Rng + ?Sized>(
     attack_type: AttackType,
     attacker: &dyn Fighter,
     defender: &dyn Fighter,
-    world: &World,
+    map: &Map,
+    units: &Units,
+    rng: &mut R,
 ) -> UnitRangedAttackResult {
-    let throw_roll = attack_roll(attack_type, attacker, defender);
+    let throw_roll = attack_roll(attack_type, attacker, defender, rng);
     if throw_roll.is_none() {
         return UnitRangedAttackResult::Impossible;
     }
@@ -36,9 +40,7 @@ pub fn ranged_attack_unit(
         let units_to_hit = DIR8
             .into_iter()
             .filter_map(|dir| {
-                world
-                    .map
-                    .get_tile_opt(defender.pos() + dir)
+                map.get_tile_opt(defender.pos() + dir)
                     .map(|t| t.units.clone())
             })
             .flatten()
@@ -46,15 +48,16 @@ pub fn ranged_attack_unit(
         if units_to_hit.is_empty() {
             return UnitRangedAttackResult::Miss;
         }
-        let random_target = *units_to_hit.choose(&mut rand::rng()).unwrap();
+        let random_target = *units_to_hit.choose(rng).unwrap();
 
         UnitRangedAttackResult::InnocentBystander(
             random_target,
             calculate_hit(
                 attack_type,
                 attacker,
-                world.units.get_unit(random_target).as_fighter(),
+                units.get_unit(random_target).as_fighter(),
                 false,
+                rng,
             ),
         )
     } else if throw_roll.total < 4 {
@@ -65,14 +68,16 @@ pub fn ranged_attack_unit(
             attacker,
             defender,
             (throw_roll.total - 4) >= 4,
+            rng,
         ))
     }
 }
 
-fn attack_roll(
+fn attack_roll<R: Rng + ?Sized>(
     attack_type: AttackType,
     attacker: &dyn Fighter,
     defender: &dyn Fighter,
+    rng: &mut R,
 ) -> Option<RollResult> {
     let distance = attacker.pos().distance_to(defender.pos());
     let damage_value = attacker.weapon(attack_type).unwrap().damage;
@@ -81,6 +86,13 @@ fn attack_roll(
     if distance == RangedDistance::Unreachable {
         return None;
     }
+    let elevation_modifier = if attacker.as_avatar().char_sheet().elevated
+        && !defender.as_avatar().char_sheet().elevated
+    {
+        ELEVATED_ATTACK_BONUS
+    } else {
+        0
+    };
 
     Some(attacker.as_avatar().char_sheet().roll_skill(
         match attack_type {
@@ -88,18 +100,20 @@ fn attack_roll(
             AttackType::Shoot => Skill::Shooting,
             AttackType::Melee => Skill::Fighting,
         },
-        damage_value.attack_modifier + distance.modifier(),
+        damage_value.attack_modifier + distance.modifier() + elevation_modifier,
+        rng,
     ))
 }
 
-fn calculate_hit(
+fn calculate_hit<R: Rng + ?Sized>(
     attack_type: AttackType,
     attacker: &dyn Fighter,
     defender: &dyn Fighter,
     critical: bool,
+    rng: &mut R,
 ) -> HitResult {
     let damage_value = attacker.weapon(attack_type).unwrap().damage;
-    let damage = damage_value.roll(attacker.as_avatar().char_sheet(), critical, true);
+    let damage = damage_value.roll(rng, attacker.as_avatar().char_sheet(), critical, true);
 
-    HitResult::calculate(damage.damage, damage.penetration, defender, critical)
+    HitResult::calculate(damage.damage, damage.penetration, defender, critical, rng)
 }
@@ -1,3 +1,4 @@
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::game::CharSheet;
@@ -24,33 +25,33 @@ pub enum DamageDice {
 }
 
 impl DamageDice {
-    pub fn roll(self) -> u8 {
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
         match self {
-            DamageDice::D4Half => Dice::D4.roll() / 2,
-            DamageDice::D4 => Dice::D4.roll(),
-            DamageDice::D6Half => Dice::D6.roll() / 2,
-            DamageDice::D6 => Dice::D6.roll(),
-            DamageDice::D8Half => Dice::D8.roll() / 2,
-            DamageDice::D8 => Dice::D8.roll(),
-            DamageDice::D10Half => Dice::D10.roll() / 2,
-            DamageDice::D10 => Dice::D10.roll(),
-            DamageDice::D12Half => Dice::D12.roll() / 2,
-            DamageDice::D12 => Dice::D12.roll(),
+            DamageDice::D4Half => Dice::D4.roll(rng) / 2,
+            DamageDice::D4 => Dice::D4.roll(rng),
+            DamageDice::D6Half => Dice::D6.roll(rng) / 2,
+            DamageDice::D6 => Dice::D6.roll(rng),
+            DamageDice::D8Half => Dice::D8.roll(rng) / 2,
+            DamageDice::D8 => Dice::D8.roll(rng),
+            DamageDice::D10Half => Dice::D10.roll(rng) / 2,
+            DamageDice::D10 => Dice::D10.roll(rng),
+            DamageDice::D12Half => Dice::D12.roll(rng) / 2,
+            DamageDice::D12 => Dice::D12.roll(rng),
         }
     }
 
-    pub fn roll_explosive(self) -> u8 {
+    pub fn roll_explosive<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
         match self {
-            DamageDice::D4Half => Dice::D4.roll_explosive() / 2,
-            DamageDice::D4 => Dice::D4.roll_explosive(),
-            DamageDice::D6Half => Dice::D6.roll_explosive() / 2,
-            DamageDice::D6 => Dice::D6.roll_explosive(),
-            DamageDice::D8Half => Dice::D8.roll_explosive() / 2,
-            DamageDice::D8 => Dice::D8.roll_explosive(),
-            DamageDice::D10Half => Dice::D10.roll_explosive() / 2,
-            DamageDice::D10 => Dice::D10.roll_explosive(),
-            DamageDice::D12Half => Dice::D12.roll_explosive() / 2,
-            DamageDice::D12 => Dice::D12.roll_explosive(),
+            DamageDice::D4Half => Dice::D4.roll_explosive(rng) / 2,
+            DamageDice::D4 => Dice::D4.roll_explosive(rng),
+            DamageDice::D6Half => Dice::D6.roll_explosive(rng) / 2,
+            DamageDice::D6 => Dice::D6.roll_explosive(rng),
+            DamageDice::D8Half => Dice::D8.roll_explosive(rng) / 2,
+            DamageDice::D8 => Dice::D8.roll_explosive(rng),
+            DamageDice::D10Half => Dice::D10.roll_explosive(rng) / 2,
+            DamageDice::D10 => Dice::D10.roll_explosive(rng),
+            DamageDice::D12Half => Dice::D12.roll_explosive(rng) / 2,
+            DamageDice::D12 => Dice::D12.roll_explosive(rng),
         }
     }
 }
@@ -88,6 +89,7 @@ pub struct DamageRollResult {
 }
 
 impl DamageRollResult {
+    #[must_use]
     pub fn new(damage: u8, damage_type: DamageType, penetration: u8) -> Self {
         Self {
             damage,
@@ -96,6 +98,7 @@ impl DamageRollResult {
         }
     }
 
+    #[must_use]
     pub fn empty() -> Self {
         Self {
             damage: 0,
@@ -123,8 +126,9 @@ pub struct Damage {
 }
 
 impl Damage {
-    pub fn roll(
+    pub fn roll<R: Rng + ?Sized>(
         &self,
+        rng: &mut R,
         char_sheet: &CharSheet,
         critical: bool,
         explosive: bool,
@@ -152,26 +156,26 @@ impl Damage {
                 }
             }
             result += if explosive {
-                dice.roll_explosive()
+                dice.roll_explosive(rng)
             } else {
-                dice.roll()
+                dice.roll(rng)
             } as i8;
         }
         if let Some(attribute) = self.attribute {
             let attribute_dice = char_sheet.get_attribute_with_modifiers(attribute);
             result += if explosive {
-                attribute_dice.roll_explosive()
+                attribute_dice.roll_explosive(rng)
             } else {
-                attribute_dice.roll()
+                attribute_dice.roll(rng)
             }
             .total;
         }
         if critical {
             let crit_dice = self.crit_dice.unwrap_or(Dice::D6);
             result += if explosive {
-                crit_dice.roll_explosive()
+                crit_dice.roll_explosive(rng)
             } else {
-                crit_dice.roll()
+                crit_dice.roll(rng)
             } as i8;
         }
         result.max(0) as u8
@@ -1,8 +1,8 @@
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 use enum_iterator::{next, previous, Sequence};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use tetra::math::num_traits::Signed;
 
 use crate::game::traits::Name;
 use crate::game::SkillLevel;
@@ -66,6 +66,7 @@ impl Name for AttrLevel {
 }
 
 impl AttrLevel {
+    #[must_use]
     pub fn value(self) -> u8 {
         match self {
             AttrLevel::D4 => 4,
@@ -78,18 +79,20 @@ impl AttrLevel {
         }
     }
 
-    pub fn roll(self) -> u8 {
-        Dice::from(self).roll()
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        Dice::from(self).roll(rng)
     }
 
-    pub fn roll_explosive(self) -> u8 {
-        Dice::from(self).roll_explosive()
+    pub fn roll_explosive<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        Dice::from(self).roll_explosive(rng)
     }
 
+    #[must_use]
     pub fn next(self) -> Option<Self> {
         next(&self)
     }
 
+    #[must_use]
     pub fn prev(self) -> Option<Self> {
         previous(&self)
     }
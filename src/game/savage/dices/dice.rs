@@ -1,14 +1,12 @@
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 use enum_iterator::{next, previous, Sequence};
-use rand::RngExt;
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
-use tetra::math::num_traits::Signed;
 
 use crate::game::traits::Name;
 
 // TODO: it's getting a bit messy here, maybe it's time to split this file
-// TODO: use world's rng instead of thread_rng
 
 #[derive(Serialize, Deserialize, Sequence, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Dice {
@@ -38,6 +36,7 @@ impl Name for Dice {
 }
 
 impl Dice {
+    #[must_use]
     pub fn value(self) -> u8 {
         match self {
             Dice::D4 => 4,
@@ -48,27 +47,28 @@ impl Dice {
         }
     }
 
-    pub fn roll(self) -> u8 {
-        // TODO: Use world's rng instead of thread_rng
-        rand::rng().random::<u8>() % self.value() + 1
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
+        rng.random::<u8>() % self.value() + 1
     }
 
     /// Roll a die that explodes on the maximum value.
-    pub fn roll_explosive(self) -> u8 {
+    pub fn roll_explosive<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
         let mut total = 0u8;
-        let mut roll = self.roll();
+        let mut roll = self.roll(rng);
         while roll == self.value() {
             // probably u8 here is not an intelligent choice but who cares
             total = total.saturating_add(roll);
-            roll = self.roll();
+            roll = self.roll(rng);
         }
         total + roll
     }
 
+    #[must_use]
     pub fn next(self) -> Option<Self> {
         next(&self)
     }
 
+    #[must_use]
     pub fn prev(self) -> Option<Self> {
         previous(&self)
     }
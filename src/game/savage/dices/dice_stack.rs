@@ -1,13 +1,17 @@
 #![allow(dead_code)]
+use rand::Rng;
+
 use super::Dice;
 
 pub struct DiceStack(Vec<Dice>);
 
 impl DiceStack {
+    #[must_use]
     pub fn new(dices: Vec<Dice>) -> Self {
         Self(dices)
     }
 
+    #[must_use]
     pub fn from_dice(dice: Dice) -> Self {
         Self(vec![dice])
     }
@@ -20,36 +24,39 @@ impl DiceStack {
         self.0.pop()
     }
 
+    #[must_use]
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    #[must_use]
     pub fn len(&self) -> usize {
         self.0.len()
     }
 
+    #[must_use]
     pub fn new_2d6() -> Self {
         Self::new(vec![Dice::D6, Dice::D6])
     }
 
-    pub fn roll_all(&self) -> Vec<u8> {
-        self.0.iter().map(|d| d.roll()).collect()
+    pub fn roll_all<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
+        self.0.iter().map(|d| d.roll(rng)).collect()
     }
 
-    pub fn roll_all_explosive(&self) -> Vec<u8> {
-        self.0.iter().map(|d| d.roll_explosive()).collect()
+    pub fn roll_all_explosive<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
+        self.0.iter().map(|d| d.roll_explosive(rng)).collect()
     }
 
-    pub fn roll_total(&self) -> u8 {
-        self.0.iter().map(|d| d.roll()).sum()
+    pub fn roll_total<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+        self.0.iter().map(|d| d.roll(rng)).sum()
     }
 
-    pub fn roll_total_explosive(&self) -> u8 {
-        self.0.iter().map(|d| d.roll_explosive()).sum()
+    pub fn roll_total_explosive<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+        self.0.iter().map(|d| d.roll_explosive(rng)).sum()
     }
 
-    pub fn damage(&self) -> u8 {
-        self.roll_total_explosive()
+    pub fn damage<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+        self.roll_total_explosive(rng)
     }
 }
 
@@ -77,23 +84,24 @@ mod tests {
 
     #[test]
     fn test_dice_stack() {
+        let mut rng = rand::rng();
         let mut stack = DiceStack::new_2d6();
 
         assert_eq!(stack.len(), 2);
         assert!(!stack.is_empty());
 
-        let roll = stack.roll_all();
+        let roll = stack.roll_all(&mut rng);
         assert_eq!(roll.len(), 2);
         for r in roll {
             assert!(r >= 1 && r <= 6);
         }
 
-        let total = stack.roll_total();
+        let total = stack.roll_total(&mut rng);
         assert!(total >= 2 && total <= 12);
 
         stack.push(Dice::D4);
         assert_eq!(stack.len(), 3);
-        let total = stack.roll_total();
+        let total = stack.roll_total(&mut rng);
         assert!(total >= 3 && total <= 16);
 
         let pop = stack.pop();
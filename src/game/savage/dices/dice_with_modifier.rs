@@ -1,3 +1,4 @@
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::game::traits::Name;
@@ -16,13 +17,13 @@ impl DiceWithModifier {
         DiceWithModifier(dice, 0)
     }
 
-    pub fn roll(self) -> RollResult {
-        let natural = self.0.roll();
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> RollResult {
+        let natural = self.0.roll(rng);
         RollResult::new(natural, natural as i8 + self.1)
     }
 
-    pub fn roll_explosive(self) -> RollResult {
-        let natural = self.0.roll_explosive();
+    pub fn roll_explosive<R: Rng + ?Sized>(self, rng: &mut R) -> RollResult {
+        let natural = self.0.roll_explosive(rng);
         RollResult::new(natural, natural as i8 + self.1)
     }
 
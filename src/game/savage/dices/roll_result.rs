@@ -7,14 +7,17 @@ pub struct RollResult {
 }
 
 impl RollResult {
+    #[must_use]
     pub fn new(natural: u8, total: i8) -> Self {
         RollResult { natural, total }
     }
 
+    #[must_use]
     pub fn success(self) -> bool {
         self.total >= 4
     }
 
+    #[must_use]
     pub fn successes(self) -> u8 {
         self.total.max(0) as u8 / 4
     }
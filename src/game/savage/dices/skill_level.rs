@@ -1,8 +1,8 @@
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 use enum_iterator::{next, previous, Sequence};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use tetra::math::num_traits::Signed;
 
 use crate::game::traits::Name;
 
@@ -61,6 +61,7 @@ impl Name for SkillLevel {
 }
 
 impl SkillLevel {
+    #[must_use]
     pub fn value(self) -> u8 {
         match self {
             SkillLevel::None => 2,
@@ -72,26 +73,30 @@ impl SkillLevel {
         }
     }
 
-    pub fn roll(self) -> RollResult {
-        DiceWithModifier::from(self).roll()
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> RollResult {
+        DiceWithModifier::from(self).roll(rng)
     }
 
-    pub fn roll_explosive(self) -> RollResult {
-        DiceWithModifier::from(self).roll_explosive()
+    pub fn roll_explosive<R: Rng + ?Sized>(self, rng: &mut R) -> RollResult {
+        DiceWithModifier::from(self).roll_explosive(rng)
     }
 
+    #[must_use]
     pub fn next(self) -> Option<Self> {
         next(&self)
     }
 
+    #[must_use]
     pub fn prev(self) -> Option<Self> {
         previous(&self)
     }
 
+    #[must_use]
     pub fn steps_above(self, other: Self) -> i8 {
         self as i8 - other as i8
     }
 
+    #[must_use]
     pub fn steps_above_attr(self, attr: AttrLevel) -> i8 {
         self as i8 - (attr as i8 + 1)
     }
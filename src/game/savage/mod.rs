@@ -1,5 +1,6 @@
 pub use attributes::{Attribute, Attributes};
-pub use charsheet::CharSheet;
+pub use charsheet::{CharSheet, FATIGUE_LIMIT, FATIGUE_RECOVERY, HYDRATION_MAX, NUTRITION_MAX};
+pub use climbing::{climbing_roll, fall_damage, ELEVATED_ATTACK_BONUS, ELEVATED_SIGHT_BONUS};
 pub use combat::{
     melee_attack_unit, melee_smash_terrain, ranged_attack_unit, AttackType, HitResult,
     RangedDistance, TerrainMeleeAttackResult, UnitMeleeAttackResult, UnitRangedAttackResult,
@@ -7,14 +8,19 @@ pub use combat::{
 pub use damage::{Damage, DamageDice, DamageRollResult, DamageType};
 pub use dices::{AttrLevel, Dice, DiceStack, DiceWithModifier, RollResult, SkillLevel};
 pub use skills::{Skill, Skills};
+pub use stealth::{notice_roll, SNEAKING_BONUS, SNEAKING_SLOWDOWN};
+pub use swimming::{swimming_roll, HEAVY_ITEM_PENALTY, SWIMMING_TURN};
 pub use wound::Wound;
 
 mod attributes;
 mod charsheet;
+mod climbing;
 mod combat;
 mod damage;
 mod dices;
 mod skills;
+mod stealth;
+mod swimming;
 mod wound;
 
 /// Default attack cost in ticks
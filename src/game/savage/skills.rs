@@ -139,7 +139,8 @@ impl Skills {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Skill {
     // TODO: probably rename to Throwing
     Athletics,
@@ -160,6 +161,7 @@ pub enum Skill {
 }
 
 impl Skill {
+    #[must_use]
     pub fn name(self) -> &'static str {
         match self {
             Self::Athletics => "Athletics",
@@ -180,6 +182,7 @@ impl Skill {
         }
     }
 
+    #[must_use]
     pub fn attribute(self) -> Attribute {
         match self {
             Self::Athletics
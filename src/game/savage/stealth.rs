@@ -0,0 +1,47 @@
+use rand::Rng;
+
+use super::{CharSheet, Skill};
+
+/// Bonus to Stealth rolls for sneaking units
+pub const SNEAKING_BONUS: i8 = 2;
+/// Sneaking units are walking that much slower
+pub const SNEAKING_SLOWDOWN: f32 = 2.0;
+
+/// Opposed roll of the observer's Notice against the hider's Stealth, ties go to the observer
+pub fn notice_roll<R: Rng + ?Sized>(
+    observer: &CharSheet,
+    hider: &CharSheet,
+    notice_modifier: i8,
+    rng: &mut R,
+) -> bool {
+    let stealth_modifier = if hider.sneaking { SNEAKING_BONUS } else { 0 };
+    let notice = observer.roll_skill(Skill::Notice, notice_modifier, rng);
+    let stealth = hider.roll_skill(Skill::Stealth, stealth_modifier, rng);
+    notice.total >= stealth.total
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::{CharSheet, Race, SkillLevel, WorldRng};
+
+    use super::{notice_roll, Skill};
+
+    #[test]
+    fn test_sneaking_helps_to_hide() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let observer = CharSheet::default(false, Race::Bug);
+        let mut hider = CharSheet::default(true, Race::Gazan);
+        hider.skills.set_skill(Skill::Stealth, SkillLevel::D12);
+
+        let noticed = (0..100)
+            .filter(|_| notice_roll(&observer, &hider, 0, &mut rng))
+            .count();
+        hider.sneaking = true;
+        let noticed_sneaking = (0..100)
+            .filter(|_| notice_roll(&observer, &hider, -4, &mut rng))
+            .count();
+        assert!(noticed_sneaking < noticed);
+    }
+}
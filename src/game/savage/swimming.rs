@@ -0,0 +1,58 @@
+use rand::Rng;
+
+use super::{CharSheet, Skill};
+
+/// Penalty to Swimming rolls for every wielded two-handed item
+pub const HEAVY_ITEM_PENALTY: i8 = 2;
+/// Ticks between Swimming rolls, as long as it takes to cross a tile of the deep water
+pub const SWIMMING_TURN: u128 = 30;
+
+/// Swimming roll for a turn in the deep water, unskilled ones are rolling d4-2
+pub fn swimming_roll<R: Rng + ?Sized>(
+    char_sheet: &CharSheet,
+    heavy_items: usize,
+    rng: &mut R,
+) -> bool {
+    let modifier = -(heavy_items as i8) * HEAVY_ITEM_PENALTY;
+    char_sheet
+        .roll_skill(Skill::Swimming, modifier, rng)
+        .success()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::{CharSheet, Race, WorldRng};
+
+    use super::swimming_roll;
+
+    #[test]
+    fn test_totiks_are_good_swimmers() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let totik = CharSheet::default(false, Race::Totik);
+        let gazan = CharSheet::default(false, Race::Gazan);
+
+        let totik_swims = (0..100)
+            .filter(|_| swimming_roll(&totik, 0, &mut rng))
+            .count();
+        let gazan_swims = (0..100)
+            .filter(|_| swimming_roll(&gazan, 0, &mut rng))
+            .count();
+        assert!(totik_swims > gazan_swims);
+    }
+
+    #[test]
+    fn test_heavy_items_are_sinking() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let totik = CharSheet::default(false, Race::Totik);
+
+        let light = (0..100)
+            .filter(|_| swimming_roll(&totik, 0, &mut rng))
+            .count();
+        let heavy = (0..100)
+            .filter(|_| swimming_roll(&totik, 1, &mut rng))
+            .count();
+        assert!(heavy < light);
+    }
+}
@@ -1,4 +1,4 @@
-use rand::RngExt;
+use rand::{Rng, RngExt};
 
 use crate::game::savage::dices::DiceStack;
 use crate::game::Dice;
@@ -20,6 +20,7 @@ pub enum Wound {
 }
 
 impl Wound {
+    #[must_use]
     pub fn name(self) -> &'static str {
         match self {
             Wound::Groin => "groin",
@@ -37,27 +38,26 @@ impl Wound {
         }
     }
 
-    pub fn random() -> Self {
-        // TODO: use world's rng instead of thread_rng
-        let roll = DiceStack::from((Dice::D6, Dice::D6)).roll_total();
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let roll = DiceStack::from((Dice::D6, Dice::D6)).roll_total(rng);
         match roll {
             2 => Wound::Groin,
             3 => Wound::LeftArm,
             4 => Wound::RightArm,
-            5..=9 => match Dice::D6.roll() {
+            5..=9 => match Dice::D6.roll(rng) {
                 1..=2 => Wound::BrokenGuts,
                 3..=4 => Wound::BatteredGuts,
                 5..=6 => Wound::BustedGuts,
                 _ => unreachable!(),
             },
             10 => {
-                if rand::rng().random_bool(0.5) {
+                if rng.random_bool(0.5) {
                     Wound::LeftLeg
                 } else {
                     Wound::RightLeg
                 }
             }
-            11..=12 => match Dice::D6.roll() {
+            11..=12 => match Dice::D6.roll(rng) {
                 1..=2 => Wound::HideousScar,
                 3 => Wound::LeftEye,
                 4 => Wound::RightEye,
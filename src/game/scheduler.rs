@@ -0,0 +1,111 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Queue of ticks when units should be woken up: to finish their actions,
+/// to plan a new one or to try to get out of the shock.
+/// Every unit has at most one actual wake-up, outdated entries are skipped lazily.
+#[derive(Debug, Default)]
+pub struct Scheduler {
+    queue: BinaryHeap<Reverse<(u128, usize)>>,
+    wake_at: HashMap<usize, u128>,
+    /// Finish ticks of actions `on_start` was already called for
+    started: HashMap<usize, u128>,
+}
+
+impl Scheduler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces previous wake-up of the unit
+    pub fn schedule(&mut self, unit_id: usize, tick: u128) {
+        if self.wake_at.insert(unit_id, tick) != Some(tick) {
+            self.queue.push(Reverse((tick, unit_id)));
+        }
+    }
+
+    #[must_use]
+    pub fn wake_at(&self, unit_id: usize) -> Option<u128> {
+        self.wake_at.get(&unit_id).copied()
+    }
+
+    fn is_actual(&self, tick: u128, unit_id: usize) -> bool {
+        self.wake_at(unit_id) == Some(tick)
+    }
+
+    fn drop_outdated(&mut self) {
+        while let Some(&Reverse((tick, unit_id))) = self.queue.peek() {
+            if self.is_actual(tick, unit_id) {
+                break;
+            }
+            self.queue.pop();
+        }
+    }
+
+    /// Tick of the nearest wake-up
+    pub fn next_tick(&mut self) -> Option<u128> {
+        self.drop_outdated();
+        self.queue.peek().map(|Reverse((tick, _))| *tick)
+    }
+
+    /// Next unit that should be woken up not later than `tick`
+    pub fn pop_due(&mut self, tick: u128) -> Option<usize> {
+        if self.next_tick()? > tick {
+            return None;
+        }
+        let Reverse((_, unit_id)) = self.queue.pop()?;
+        self.wake_at.remove(&unit_id);
+        Some(unit_id)
+    }
+
+    #[must_use]
+    pub fn is_started(&self, unit_id: usize, finish: u128) -> bool {
+        self.started.get(&unit_id) == Some(&finish)
+    }
+
+    pub fn mark_started(&mut self, unit_id: usize, finish: u128) {
+        self.started.insert(unit_id, finish);
+    }
+
+    pub fn forget(&mut self, unit_id: usize) {
+        self.wake_at.remove(&unit_id);
+        self.started.remove(&unit_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scheduler;
+
+    #[test]
+    fn test_units_are_woken_in_order() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(1, 20);
+        scheduler.schedule(2, 10);
+        scheduler.schedule(0, 20);
+
+        assert_eq!(Some(10), scheduler.next_tick());
+        assert_eq!(None, scheduler.pop_due(5));
+        assert_eq!(Some(2), scheduler.pop_due(10));
+        assert_eq!(None, scheduler.pop_due(10));
+        assert_eq!(Some(0), scheduler.pop_due(20));
+        assert_eq!(Some(1), scheduler.pop_due(20));
+        assert_eq!(None, scheduler.next_tick());
+    }
+
+    #[test]
+    fn test_rescheduling_replaces_wake_up() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(1, 10);
+        scheduler.schedule(1, 300);
+
+        assert_eq!(Some(300), scheduler.next_tick());
+        assert_eq!(Some(1), scheduler.pop_due(300));
+        assert_eq!(None, scheduler.pop_due(300));
+
+        scheduler.schedule(2, 5);
+        scheduler.forget(2);
+        assert_eq!(None, scheduler.next_tick());
+    }
+}
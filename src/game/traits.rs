@@ -1,4 +1,4 @@
-use crate::assets::Sprite;
+use crate::sprite::Sprite;
 
 pub trait Name {
     fn name(&self) -> String;
@@ -1,11 +1,13 @@
 use roguemetry::{Direction, OneDimensionalDirection, Point};
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
+
+use crate::colors::Color;
 
 use super::{
     super::{
-        super::assets::Sprite, ai::AI, map::items::helpers::dead_body, races::Pronouns,
-        savage::HitResult, traits::LooksLike, Action, AttackType, BodySlot, CharSheet, Item,
+        super::sprite::Sprite, ai::AI, map::items::helpers::dead_body, races::Pronouns,
+        savage::HitResult, traits::LooksLike, Action, AttackType, BodySlot, CharSheet, GameData,
+        Item, LootContext, WorldRng,
     },
     Appearance, Fighter, Inventory, Monster, Player, Weapon,
 };
@@ -19,6 +21,9 @@ pub trait Avatar {
     }
     fn pos(&self) -> Point;
     fn set_pos(&mut self, pos: Point);
+    /// Level the unit is on, 0 is the surface
+    fn z(&self) -> i32;
+    fn set_z(&mut self, z: i32);
     fn name(&self) -> &str;
     fn name_for_actions(&self) -> &str {
         self.name()
@@ -53,7 +58,16 @@ pub trait Avatar {
         None
     }
     fn as_fighter(&self) -> &dyn Fighter;
-    fn apply_hit(&mut self, hit: HitResult, current_tick: u128) -> Option<Vec<Item>> {
+    /// Loot table rolled when the unit dies
+    fn loot(&self) -> Option<&str> {
+        None
+    }
+    fn apply_hit(
+        &mut self,
+        hit: HitResult,
+        current_tick: u128,
+        rng: &mut WorldRng,
+    ) -> Option<Vec<Item>> {
         self.char_sheet_mut().apply_hit(hit, current_tick);
 
         // TODO: drop weapons if arm is wounded
@@ -65,6 +79,14 @@ pub trait Avatar {
             if let Some(inventory) = self.inventory_mut() {
                 items.append(&mut inventory.take_all());
             }
+            if let Some(loot) = self.loot() {
+                let context = LootContext {
+                    z: self.z(),
+                    race: Some(self.appearance().race),
+                    ..LootContext::default()
+                };
+                items.append(&mut GameData::instance().get_loot_table(loot).roll(context, rng));
+            }
             items.push(dead_body(self.appearance()));
             return Some(items);
         }
@@ -81,6 +103,7 @@ pub struct AvatarView {
 }
 
 impl AvatarView {
+    #[must_use]
     pub fn new(sprite: Sprite, fg: Option<Color>) -> Self {
         Self {
             direction: OneDimensionalDirection::default(),
@@ -89,6 +112,7 @@ impl AvatarView {
         }
     }
 
+    #[must_use]
     pub fn direction(&self) -> OneDimensionalDirection {
         self.direction
     }
@@ -106,6 +130,7 @@ impl AvatarView {
         false
     }
 
+    #[must_use]
     pub fn fg(&self) -> Option<Color> {
         self.fg
     }
@@ -119,6 +144,7 @@ impl LooksLike for AvatarView {
 
 #[cfg(test)]
 mod tests {
+    use rand::SeedableRng;
     use roguemetry::Point;
 
     use crate::game::map::items::helpers::{CLOAK, GOD_AXE};
@@ -160,7 +186,8 @@ mod tests {
         let mut avatar = Player::new(tester_girl(), Point::new(0, 0));
         avatar.inventory_mut().unwrap().wield(Item::new(GOD_AXE));
         avatar.inventory_mut().unwrap().wear(Item::new(CLOAK), 0);
-        let items = avatar.apply_hit(HitResult::ultra_damage(), 0);
+        let mut rng = WorldRng::seed_from_u64(42);
+        let items = avatar.apply_hit(HitResult::ultra_damage(&mut rand::rng()), 0, &mut rng);
         assert!(items.is_some());
         let items = items.unwrap();
         assert_eq!(items.len(), 3);
@@ -169,4 +196,28 @@ mod tests {
         assert_eq!(items[2].name(), "dead gazan girl");
         assert!(avatar.char_sheet().is_dead());
     }
+
+    #[test]
+    fn test_monster_drops_loot() {
+        let mut monster = Monster::new(
+            AI::Dummy,
+            Point::new(0, 0),
+            "guardian".to_string(),
+            Appearance {
+                race: Race::Bug,
+                age: 3,
+                body_color: None,
+                sex: Sex::Other,
+            },
+            Pronouns::ItIts,
+            CharSheet::default(false, Race::Bug),
+        )
+        .with_loot("shrine_guardian");
+        let mut rng = WorldRng::seed_from_u64(42);
+        let items = monster
+            .apply_hit(HitResult::ultra_damage(&mut rand::rng()), 0, &mut rng)
+            .unwrap();
+        assert!(items.iter().any(|i| i.proto().id == "demonic_chunk"));
+        assert_eq!(items.last().unwrap().name(), "dead young giant bug");
+    }
 }
@@ -1,4 +1,4 @@
-use crate::game::{AmmoType, BodySlot, Item};
+use crate::game::{AmmoType, BodySlot, Item, ItemQuality};
 
 use super::{Wear, Wield};
 
@@ -10,6 +10,7 @@ pub struct Inventory {
 }
 
 impl Inventory {
+    #[must_use]
     pub fn new(hands_count: usize) -> Self {
         Self {
             wield: Wield::new(hands_count),
@@ -17,10 +18,12 @@ impl Inventory {
         }
     }
 
+    #[must_use]
     pub fn humanoid() -> Self {
         Self::new(2)
     }
 
+    #[must_use]
     pub fn monster() -> Self {
         Self::new(0)
     }
@@ -35,10 +38,16 @@ impl Inventory {
         self.wield.wield(item);
     }
 
+    /// # Errors
+    ///
+    /// Returns the reason if there is no free hand
     pub fn can_wield_any(&self) -> Result<(), String> {
         self.wield.can_wield(false)
     }
 
+    /// # Errors
+    ///
+    /// Returns the reason if there are not enough free hands for the item
     pub fn can_wield(&self, item: &Item) -> Result<(), String> {
         self.wield.can_wield(item.is_two_handed())
     }
@@ -47,10 +56,12 @@ impl Inventory {
         self.wear.add(item, variant);
     }
 
+    #[must_use]
     pub fn can_wear(&self, item: &Item, variant: usize) -> bool {
         self.wear.can_add(item, variant)
     }
 
+    #[must_use]
     pub fn main_hand(&self) -> Option<&Item> {
         self.wield.main_hand()
     }
@@ -63,10 +74,44 @@ impl Inventory {
         self.wield.main_hand_take()
     }
 
+    #[must_use]
     pub fn second_hand(&self) -> Option<&Item> {
         self.wield.second_hand()
     }
 
+    /// All wielded items, main hand goes first
+    pub fn wielded(&self) -> impl Iterator<Item = &Item> {
+        self.wield.iter()
+    }
+
+    pub fn wielded_mut(&mut self, index: usize) -> Option<&mut Item> {
+        self.wield.get_mut(index)
+    }
+
+    pub fn take_wielded(&mut self, index: usize) -> Option<Item> {
+        self.wield.take(index)
+    }
+
+    /// Wielded item with the quality, main hand goes first
+    #[must_use]
+    pub fn tool(&self, quality: &ItemQuality) -> Option<&Item> {
+        self.main_hand()
+            .into_iter()
+            .chain(self.wield.off_hands())
+            .find(|item| item.qualities().contains(quality))
+    }
+
+    /// Wielded two-handed items, too heavy to swim with
+    #[must_use]
+    pub fn heavy_items(&self) -> usize {
+        self.main_hand()
+            .into_iter()
+            .chain(self.wield.off_hands())
+            .filter(|item| item.is_two_handed())
+            .count()
+    }
+
+    #[must_use]
     pub fn has_ammo(&self, ammo_type: AmmoType) -> bool {
         self.wear.has_ammo(ammo_type)
     }
@@ -75,6 +120,11 @@ impl Inventory {
         self.wear.iter()
     }
 
+    pub fn worn_mut(&mut self, index: usize) -> Option<&mut Item> {
+        self.wear.get_mut(index)
+    }
+
+    #[must_use]
     pub fn get_items_by_slot(&self, slot: BodySlot) -> Vec<&Item> {
         self.wear.get_items_by_slot(slot)
     }
@@ -85,6 +135,7 @@ impl Inventory {
         items
     }
 
+    #[must_use]
     pub fn get_ammo(&self, ammo_type: AmmoType) -> Option<&Item> {
         self.wear.get_ammo(ammo_type)
     }
@@ -93,6 +144,14 @@ impl Inventory {
         self.wield.swap();
     }
 
+    /// # Errors
+    ///
+    /// Returns `Err` if there is no wielded weapon that needs ammo
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wielded weapon has no container for the ammo
+    #[allow(clippy::result_unit_err)]
     pub fn reload(&mut self) -> Result<(), ()> {
         let weapon = self.main_hand().ok_or(())?;
         let need_ammo = weapon.need_ammo().ok_or(())?;
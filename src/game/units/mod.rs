@@ -1,4 +1,6 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use roguemetry::Point;
 
 pub use avatar::{Avatar, AvatarView};
 pub use fighter::Fighter;
@@ -20,36 +22,50 @@ mod weapon;
 mod wear;
 mod wield;
 
+/// Units are kept ordered by id, so they act in the same order every time
 pub struct Units {
-    units: HashMap<usize, Box<dyn Avatar>>,
-    loaded_units: HashSet<usize>,
+    units: BTreeMap<usize, Box<dyn Avatar>>,
+    loaded_units: BTreeSet<usize>,
 }
 
 impl Units {
     const BUBBLE_SQUARE_RADIUS: u32 = 128 * 128;
 
+    #[must_use]
     pub fn new(mut units: HashMap<usize, Box<dyn Avatar>>) -> Self {
         for (&id, unit) in &mut units {
             unit.set_id(id);
         }
         Self {
-            units,
-            loaded_units: HashSet::from([0]),
+            units: units.into_iter().collect(),
+            loaded_units: BTreeSet::from([0]),
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if the unit `0` is not the player
+    #[must_use]
     pub fn player(&self) -> &Player {
         self.get_unit(0).as_player().unwrap()
     }
 
+    /// # Panics
+    ///
+    /// Panics if the unit `0` is not the player
     pub fn player_mut(&mut self) -> &mut Player {
         self.get_unit_mut(0).as_player_mut().unwrap()
     }
 
+    #[must_use]
     pub fn player_as_avatar(&self) -> &dyn Avatar {
         self.get_unit(0).as_fighter().as_avatar()
     }
 
+    /// # Panics
+    ///
+    /// Panics if there is no unit with such id
+    #[must_use]
     pub fn get_unit(&self, unit_id: usize) -> &dyn Avatar {
         self.units
             .get(&unit_id)
@@ -59,6 +75,9 @@ impl Units {
             .as_ref()
     }
 
+    /// # Panics
+    ///
+    /// Panics if there is no unit with such id
     pub fn get_unit_mut(&mut self, unit_id: usize) -> &mut dyn Avatar {
         self.units
             .get_mut(&unit_id)
@@ -68,6 +87,7 @@ impl Units {
             .as_mut()
     }
 
+    #[must_use]
     pub fn next_unit_id(&self) -> usize {
         self.units.keys().copied().max().unwrap_or(0) + 1
     }
@@ -81,11 +101,26 @@ impl Units {
         id
     }
 
+    /// Units so far from the player are frozen
+    #[must_use]
+    pub fn in_bubble(&self, pos: Point) -> bool {
+        pos.square_distance_to(self.player().as_avatar().pos()) <= Self::BUBBLE_SQUARE_RADIUS
+    }
+
+    /// Alive units around the player, except the player
+    #[must_use]
+    pub fn population(&self) -> usize {
+        self.loaded_units()
+            .filter(|unit| !unit.is_player() && !unit.char_sheet().is_dead())
+            .count()
+    }
+
     pub fn load_units(&mut self) {
         self.loaded_units.clear();
         let center = self.player().as_avatar().pos();
+        let level = self.player().as_avatar().z();
         for (&i, unit) in &self.units {
-            if unit.char_sheet().is_dead() {
+            if unit.char_sheet().is_dead() || unit.z() != level {
                 continue;
             }
 
@@ -111,6 +146,11 @@ impl Units {
             .map(|&i| self.get_unit(i).as_fighter().as_avatar())
     }
 
+    #[must_use]
+    pub fn is_loaded(&self, unit_id: usize) -> bool {
+        self.loaded_units.contains(&unit_id)
+    }
+
     pub fn loaded_units_mut(&mut self) -> impl Iterator<Item = &mut Box<dyn Avatar>> {
         let loaded_units = self.loaded_units.clone();
         self.iter_mut().filter_map(move |(i, u)| {
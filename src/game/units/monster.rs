@@ -1,13 +1,14 @@
 use roguemetry::Point;
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
+
+use crate::colors::Color;
 
 use super::{
     super::{
         ai::AI,
-        races::{Pronouns, Race},
-        traits::LooksLike,
-        Action, AttackType, BodySlot, CharSheet,
+        races::{BodyColor, Pronouns, Race},
+        traits::{LooksLike, Name},
+        Action, AttackType, BodySlot, CharSheet, GameData, Item,
     },
     Appearance, Avatar, AvatarView, Fighter, Inventory, Weapon,
 };
@@ -17,12 +18,22 @@ pub struct Monster {
     id: usize,
     ai: AI,
     pos: Point,
+    #[serde(default)]
+    z: i32,
     name: String,
     appearance: Appearance,
     pronouns: Pronouns,
     char_sheet: CharSheet,
     view: AvatarView,
     action: Option<Action>,
+    /// Loot table for the drop on death
+    #[serde(default)]
+    loot: Option<String>,
+    /// Id of the creature it was spawned from
+    #[serde(default)]
+    prototype: Option<String>,
+    #[serde(default)]
+    inventory: Option<Inventory>,
 }
 
 impl Monster {
@@ -42,12 +53,72 @@ impl Monster {
             ),
             ai,
             pos,
+            z: 0,
             name,
             appearance,
             pronouns,
             char_sheet,
             action: None,
+            loot: None,
+            prototype: None,
+            inventory: None,
+        }
+    }
+
+    /// Creates a monster from the creature data with its starting equipment
+    #[must_use]
+    pub fn from_prototype(id: &str, pos: Point) -> Self {
+        let creature = GameData::instance().get_creature(id);
+        let mut monster = Self::new(
+            creature.ai,
+            pos,
+            creature.name.clone(),
+            Appearance {
+                race: creature.race,
+                age: creature.age,
+                body_color: creature.body_color,
+                sex: creature.sex,
+            },
+            creature.pronouns,
+            creature.char_sheet(),
+        );
+        monster.prototype = Some(creature.id.clone());
+        monster.loot.clone_from(&creature.loot);
+        let equipment = &creature.equipment;
+        if !equipment.wield.is_empty() || !equipment.wear.is_empty() {
+            let mut inventory = creature.race.inventory();
+            for item in &equipment.wield {
+                inventory.wield(Item::new(item));
+            }
+            for item in &equipment.wear {
+                inventory.wear(Item::new(item), 0);
+            }
+            monster.inventory = Some(inventory);
         }
+
+        monster
+    }
+
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_body_color(mut self, body_color: BodyColor) -> Self {
+        self.appearance.body_color = Some(body_color);
+        self.view = AvatarView::new(
+            self.appearance.race.looks_like(),
+            Some(Color::from(body_color)),
+        );
+        self
+    }
+
+    #[must_use]
+    pub fn with_loot(mut self, loot: impl Into<String>) -> Self {
+        self.loot = Some(loot.into());
+        self
     }
 }
 
@@ -73,6 +144,18 @@ impl Avatar for Monster {
         self.pos = pos;
     }
 
+    fn z(&self) -> i32 {
+        self.z
+    }
+
+    fn set_z(&mut self, z: i32) {
+        self.z = z;
+    }
+
+    fn loot(&self) -> Option<&str> {
+        self.loot.as_deref()
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -109,6 +192,14 @@ impl Avatar for Monster {
         self.action = action;
     }
 
+    fn inventory(&self) -> Option<&Inventory> {
+        self.inventory.as_ref()
+    }
+
+    fn inventory_mut(&mut self) -> Option<&mut Inventory> {
+        self.inventory.as_mut()
+    }
+
     fn as_monster(&self) -> Option<&Monster> {
         Some(self)
     }
@@ -127,16 +218,84 @@ impl Fighter for Monster {
         self
     }
 
-    fn armor(&self, _slot: BodySlot) -> i8 {
-        self.char_sheet.race.natural_armor()
+    fn armor(&self, slot: BodySlot) -> i8 {
+        let natural_armor = self
+            .prototype
+            .as_ref()
+            .and_then(|id| GameData::instance().creatures.get(id))
+            .and_then(|creature| creature.natural_armor)
+            .unwrap_or_else(|| self.char_sheet.race.natural_armor());
+        self.inventory.as_ref().map_or(0, |i| {
+            i.get_items_by_slot(slot)
+                .into_iter()
+                .map(|item| item.armor() as i8)
+                .sum::<i8>()
+        }) + natural_armor
     }
 
     fn weapon(&self, attack_type: AttackType) -> Option<Weapon> {
+        // TODO: ammo for the monsters' ranged weapons
+        if let Some(weapon) = self.inventory.as_ref().and_then(Inventory::main_hand) {
+            if attack_type == AttackType::Melee || weapon.need_ammo().is_none() {
+                if let Some(damage) = weapon.damage(attack_type) {
+                    return Some(Weapon {
+                        name: weapon.name(),
+                        ammo_name: None,
+                        damage,
+                    });
+                }
+            }
+        }
+
         // TODO: implement ranged natural weapons
-        if attack_type == AttackType::Melee {
-            Some(self.appearance.race.natural_weapon())
-        } else {
-            None
+        if attack_type != AttackType::Melee {
+            return None;
+        }
+        if let Some(natural_weapon) = self
+            .prototype
+            .as_ref()
+            .and_then(|id| GameData::instance().creatures.get(id))
+            .and_then(|creature| creature.natural_weapon.as_ref())
+        {
+            return Some(Weapon {
+                name: natural_weapon.name.clone(),
+                ammo_name: None,
+                damage: natural_weapon.damage.clone(),
+            });
         }
+
+        Some(self.appearance.race.natural_weapon())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::Point;
+
+    use crate::game::{AttackType, Avatar, BodySlot, Fighter};
+
+    use super::Monster;
+
+    #[test]
+    fn test_monster_from_prototype() {
+        let monster = Monster::from_prototype("shrine_guardian", Point::new(1, 2));
+        assert_eq!("shrine guardian bug", monster.name());
+        assert_eq!(Point::new(1, 2), Avatar::pos(&monster));
+        assert_eq!(Some("shrine_guardian"), monster.loot());
+        assert!(monster.inventory().is_none());
+        assert_eq!(2, monster.armor(BodySlot::Torso));
+        let weapon = monster.weapon(AttackType::Melee).unwrap();
+        assert_eq!("spiked mandibles", weapon.name);
+        assert!(monster.weapon(AttackType::Shoot).is_none());
+    }
+
+    #[test]
+    fn test_monster_starting_equipment() {
+        let monster = Monster::from_prototype("scavenger", Point::new(0, 0));
+        let inventory = monster.inventory().unwrap();
+        assert_eq!("spear_stone", inventory.main_hand().unwrap().proto().id);
+        assert_eq!(1, monster.armor(BodySlot::Torso));
+        let weapon = monster.weapon(AttackType::Melee).unwrap();
+        assert_eq!("stone spear", weapon.name);
     }
 }
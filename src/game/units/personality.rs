@@ -21,6 +21,7 @@ pub struct Appearance {
 }
 
 impl Appearance {
+    #[must_use]
     pub fn body_name(&self) -> String {
         // TODO: race-specific sexes and ages
         let race_name = self.race.name().to_lowercase();
@@ -78,6 +79,7 @@ pub struct PlayerPersonality {
 }
 
 impl PlayerPersonality {
+    #[must_use]
     pub fn new(appearance: Appearance, mind: Mind, char_sheet: CharSheet) -> Self {
         Self {
             appearance,
@@ -86,6 +88,9 @@ impl PlayerPersonality {
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if there are no names for the picked race and sex
     pub fn random_playable<R: Rng + ?Sized>(rng: &mut R) -> PlayerPersonality {
         let gender = rng.sample(StandardUniform);
         let sex = Sex::from(&gender);
@@ -8,12 +8,15 @@ use super::{
 };
 use roguemetry::Point;
 use serde::{Deserialize, Serialize};
-use tetra::graphics::Color;
+
+use crate::colors::Color;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Player {
     pub id: usize,
     pub pos: Point,
+    #[serde(default)]
+    pub z: i32,
     pub personality: PlayerPersonality,
     pub view: AvatarView,
     pub inventory: Inventory,
@@ -31,6 +34,7 @@ impl Player {
             inventory: Inventory::humanoid(),
             personality,
             pos,
+            z: 0,
             action: None,
         }
     }
@@ -54,6 +58,14 @@ impl Avatar for Player {
         self.pos = pos;
     }
 
+    fn z(&self) -> i32 {
+        self.z
+    }
+
+    fn set_z(&mut self, z: i32) {
+        self.z = z;
+    }
+
     fn name(&self) -> &str {
         &self.personality.mind.name
     }
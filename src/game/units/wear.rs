@@ -14,6 +14,10 @@ impl Wear {
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if the item has no such wearable variant
+    #[must_use]
     pub fn can_add(&self, item: &Item, variant: usize) -> bool {
         if let Some(wearable) = &item.proto().wearable {
             let layer = wearable.layer;
@@ -49,6 +53,11 @@ impl Wear {
         self.items.iter_mut().map(|(item, _)| item)
     }
 
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Item> {
+        self.items.get_mut(index).map(|(item, _)| item)
+    }
+
+    #[must_use]
     pub fn get_items_by_slot(&self, slot: BodySlot) -> Vec<&Item> {
         self.items
             .iter()
@@ -68,10 +77,12 @@ impl Wear {
         self.items.clear();
     }
 
+    #[must_use]
     pub fn has_ammo(&self, ammo_type: AmmoType) -> bool {
         self.iter().any(|wear| wear.has_ammo(ammo_type))
     }
 
+    #[must_use]
     pub fn get_ammo(&self, ammo_type: AmmoType) -> Option<&Item> {
         for item in self.iter() {
             if let Some(container) = item.container() {
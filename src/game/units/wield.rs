@@ -9,6 +9,7 @@ pub struct Wield {
 }
 
 impl Wield {
+    #[must_use]
     pub fn new(hands_count: usize) -> Self {
         Self {
             items: VecDeque::with_capacity(hands_count),
@@ -16,10 +17,12 @@ impl Wield {
         }
     }
 
+    #[must_use]
     pub fn is_empty(&self) -> bool {
         self.items.is_empty()
     }
 
+    #[must_use]
     pub fn main_hand(&self) -> Option<&Item> {
         self.items.front()
     }
@@ -28,14 +31,29 @@ impl Wield {
         self.items.front_mut()
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.items.iter()
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Item> {
+        self.items.get_mut(index)
+    }
+
+    pub fn take(&mut self, index: usize) -> Option<Item> {
+        self.items.remove(index)
+    }
+
+    #[must_use]
     pub fn off_hands(&self) -> Vec<&Item> {
         self.items.iter().skip(1).collect()
     }
 
+    #[must_use]
     pub fn second_hand(&self) -> Option<&Item> {
         self.items.get(1)
     }
 
+    #[must_use]
     pub fn has_quality(&self, quality: &ItemQuality) -> bool {
         self.items.iter().any(|i| i.qualities().contains(quality))
     }
@@ -48,6 +66,9 @@ impl Wield {
         self.items.drain(..).collect()
     }
 
+    /// # Errors
+    ///
+    /// Returns the reason if there are not enough free hands
     pub fn can_wield(&self, two_handed: bool) -> Result<(), String> {
         let free_hands = self.hands_count
             - self
@@ -0,0 +1,122 @@
+use rand::{Rng, RngExt};
+
+use super::{
+    calendar::{TICKS_PER_HOUR, TICKS_PER_MINUTE},
+    DateTime, Season,
+};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum WeatherKind {
+    Clear,
+    Rain,
+    Fog,
+}
+
+impl WeatherKind {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            WeatherKind::Clear => "clear",
+            WeatherKind::Rain => "rain",
+            WeatherKind::Fog => "fog",
+        }
+    }
+
+    /// How far one can see comparing to the clear weather
+    #[must_use]
+    pub fn visibility(self) -> f32 {
+        match self {
+            WeatherKind::Clear => 1.0,
+            WeatherKind::Rain => 0.7,
+            WeatherKind::Fog => 0.35,
+        }
+    }
+
+    /// How much of the sunlight gets through the clouds
+    #[must_use]
+    pub fn sunlight(self) -> f32 {
+        match self {
+            WeatherKind::Clear => 1.0,
+            WeatherKind::Rain => 0.6,
+            WeatherKind::Fog => 0.8,
+        }
+    }
+
+    /// Chances of rain and fog in percents
+    fn chances(season: Season) -> (u32, u32) {
+        match season {
+            Season::Spring => (25, 15),
+            Season::Summer => (15, 5),
+            Season::Autumn => (35, 25),
+            Season::Winter => (20, 20),
+        }
+    }
+
+    fn random<R: Rng + ?Sized>(season: Season, rng: &mut R) -> Self {
+        let (rain, fog) = Self::chances(season);
+        let roll = rng.random_range(0..100);
+        if roll < rain {
+            WeatherKind::Rain
+        } else if roll < rain + fog {
+            WeatherKind::Fog
+        } else {
+            WeatherKind::Clear
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Copy, Clone)]
+pub struct Weather {
+    pub kind: WeatherKind,
+    /// Tick when weather will change
+    pub until: u128,
+}
+
+impl Weather {
+    /// New worlds start with a couple of hours of clear weather
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            kind: WeatherKind::Clear,
+            until: TICKS_PER_HOUR * 2,
+        }
+    }
+
+    /// Rolls next weather if it's time to, returns `true` if weather has changed
+    pub fn update<R: Rng + ?Sized>(&mut self, current_tick: u128, rng: &mut R) -> bool {
+        if current_tick < self.until {
+            return false;
+        }
+        let season = DateTime::from_tick(current_tick).season;
+        let old = self.kind;
+        self.kind = WeatherKind::random(season, rng);
+        self.until = current_tick + rng.random_range(30..=360) * TICKS_PER_MINUTE;
+        old != self.kind
+    }
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::WorldRng;
+
+    use super::{Weather, WeatherKind, TICKS_PER_HOUR};
+
+    #[test]
+    fn test_weather_changes_only_in_time() {
+        let mut rng = WorldRng::seed_from_u64(42);
+        let mut weather = Weather::new();
+        assert!(!weather.update(TICKS_PER_HOUR, &mut rng));
+        assert_eq!(WeatherKind::Clear, weather.kind);
+
+        weather.update(TICKS_PER_HOUR * 2, &mut rng);
+        assert!(weather.until > TICKS_PER_HOUR * 2);
+    }
+}
@@ -1,9 +1,11 @@
 use std::cell::{Ref, RefCell, RefMut};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::convert::TryFrom;
 use std::rc::Rc;
 
-use roguemetry::{Direction, Point};
+use rand::{RngExt, SeedableRng};
+use rand_pcg::Pcg64;
+use roguemetry::{Direction, Point, DIR8};
 
 use crate::game::map::items::helpers::BONE_KNIFE;
 
@@ -11,88 +13,131 @@ use super::{
     super::{
         lang,
         savefile::{self, GameView, Meta},
-        settings::Settings,
     },
+    actions::{ActionImpl, ActionPossibility, ActionType},
     ai::{AIImpl, AIManager, AI},
-    map::{field_of_view_set, Fov, TerrainView},
+    detection::NOTICE_PERIOD,
+    light,
+    map::{
+        field_of_view_set,
+        terrains::{DataTerrain, Stairs},
+        ChunkMemory, Fov, Spawn, Terrain, TerrainInteract, TerrainInteractAction, TerrainView,
+    },
+    population::{MIGRATION_MIN_DISTANCE, MIGRATION_PERIOD},
     races::{BodyColor, Pronouns, Race, Sex},
-    savage::HitResult,
+    savage::{notice_roll, swimming_roll, HitResult, SWIMMING_TURN},
     traits::Name,
     units::{Appearance, Avatar, Inventory, Monster, Player, Units},
-    Action, CharSheet, Chunk, ChunkPos, Item, Log, LogEvent, Map, TilePos,
+    Action, CharSheet, Chunk, ChunkPos, DateTime, Detection, EventBus, GameData, GameEvent, Item,
+    Log, LogEvent, Map, Population, Scheduler, TilePos, Weather, WeatherKind,
 };
 
+/// RNG for everything random that happens in the world, saved with it
+pub type WorldRng = Pcg64;
+
 pub struct World {
     pub meta: Meta,
     pub game_view: GameView,
     pub units: Units,
     pub map: Map,
     fov: Fov,
+    /// Positions and radiuses of lights around the player, updated with FOV
+    light_sources: Vec<(Point, u8)>,
     pub log: Log,
-    // TODO: add Rng created with seed
+    pub rng: WorldRng,
+    pub weather: Weather,
+    pub detection: Detection,
+    pub population: Population,
+    pub events: EventBus,
+    scheduler: Scheduler,
+    /// Player can't be damaged, set from the debug settings
+    pub god_mode: bool,
 }
 
 impl World {
+    const DRY_LAND_SEARCH_RADIUS: i32 = 256;
+
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
     pub fn new(
         meta: Meta,
         game_view: GameView,
         log: Log,
+        rng: WorldRng,
+        weather: Weather,
+        detection: Detection,
+        population: Population,
         units: HashMap<usize, Box<dyn Avatar>>,
         chunks: HashMap<ChunkPos, Chunk>,
+        memory: HashMap<ChunkPos, ChunkMemory>,
     ) -> Self {
         let changed = chunks.keys().copied().collect();
         let mut world = Self {
-            map: Map::new(meta.seed, chunks, changed),
+            map: Map::new(meta.seed, chunks, changed, memory),
             meta,
             game_view,
             units: Units::new(units),
             fov: Fov::default(),
+            light_sources: Vec::new(),
             log,
+            rng,
+            weather,
+            detection,
+            population,
+            events: EventBus::new(),
+            scheduler: Scheduler::new(),
+            god_mode: false,
         };
+        world.map.level = world.units.player().z;
         world.units.load_units();
+        // actions from the savefile were started before saving
+        for (&unit_id, unit) in world.units.iter() {
+            if let Some(action) = unit.action() {
+                world.scheduler.mark_started(unit_id, action.finish);
+            }
+        }
         world.calc_fov();
         world
     }
 
+    #[must_use]
     pub fn create(meta: Meta, avatar: Player) -> Self {
         let units = HashMap::from([(0, Box::new(avatar) as Box<dyn Avatar>)]);
-        let mut world = Self::new(meta, GameView::default(), Log::new(), units, HashMap::new());
+        let rng = WorldRng::seed_from_u64(meta.seed);
+        let population = Population::new(meta.current_tick);
+        let mut world = Self::new(
+            meta,
+            GameView::default(),
+            Log::new(),
+            rng,
+            Weather::new(),
+            Detection::new(),
+            population,
+            units,
+            HashMap::new(),
+            HashMap::new(),
+        );
+
+        let start = world.dry_land_near(world.units.player().pos());
+        if start != world.units.player().pos() {
+            world.units.player_mut().set_pos(start);
+            world.units.load_units();
+            world.calc_fov();
+        }
 
         // TODO: don't forget to remove
         world
             .map
-            .get_tile_mut(Point::new(0, 0))
+            .get_tile_mut(start)
             .items
             .push(Item::new(BONE_KNIFE));
-        world.add_unit(Box::new(Monster::new(
-            AI::BasicMonster,
-            Point::new(0, 5),
-            "green bug".to_string(),
-            Appearance {
-                race: Race::Bug,
-                age: 1,
-                body_color: Some(BodyColor::Lime),
-                sex: Sex::Other,
-            },
-            Pronouns::ItIts,
-            CharSheet::default(false, Race::Bug),
-        )));
-        world.add_unit(Box::new(Monster::new(
-            AI::BasicMonster,
-            Point::new(0, 7),
-            "mutant bug queen".to_string(),
-            Appearance {
-                race: Race::Bug,
-                age: 2,
-                body_color: Some(BodyColor::Red),
-                sex: Sex::Female,
-            },
-            Pronouns::SheHer,
-            CharSheet::default(false, Race::Bug),
-        )));
+        world.spawn_monsters();
 
         world.units.iter().for_each(|(&i, unit)| {
-            world.map.get_tile_mut(unit.pos()).on_step(i);
+            world
+                .map
+                .get_tile_mut(unit.pos())
+                .on_step(i, &mut world.rng);
         });
 
         world
@@ -100,15 +145,121 @@ impl World {
 
     pub fn calc_fov(&mut self) {
         let center = self.units.player().pos();
-        // TODO: weather and outside lighting system
-        // TODO: add light sources
         // TODO: add periodic Notice roll
-        // TODO: add memory
-        let vision_range = self.units.player().char_sheet().sight_range();
-        self.fov
-            .set_visible(field_of_view_set(center, vision_range as i32, &self.map));
+        let vision_range = light::vision_range(
+            self.units.player().char_sheet().sight_range(),
+            self.local_weather(),
+        );
+        self.light_sources = self.find_light_sources(center, vision_range as i32);
+        let mut visible = field_of_view_set(center, vision_range as i32, &self.map);
+        visible.retain(|&pos| {
+            light::is_lit_enough(
+                pos.square_distance_to(center),
+                vision_range,
+                self.light_level(pos),
+            )
+        });
+        for &pos in &visible {
+            self.map.remember(pos);
+        }
+        self.fov.set_visible(visible);
+    }
+
+    /// Nearest passable tile out of the deep water, so nobody starts in the middle of a lake
+    fn dry_land_near(&mut self, center: Point) -> Point {
+        for radius in 0..Self::DRY_LAND_SEARCH_RADIUS {
+            for dx in -radius..=radius {
+                for dy in -radius..=radius {
+                    if dx.abs() != radius && dy.abs() != radius {
+                        continue;
+                    }
+                    let pos = center + (dx, dy);
+                    let terrain = &self.map.get_tile(pos).terrain;
+                    if terrain.is_passable() && !terrain.is_deep_water() {
+                        return pos;
+                    }
+                }
+            }
+        }
+        center
+    }
+
+    /// Lit terrains, dropped light sources and ones wielded by units
+    fn find_light_sources(&mut self, center: Point, vision_range: i32) -> Vec<(Point, u8)> {
+        let range = vision_range + light::MAX_LIGHT_RADIUS;
+        let left_top = center + (-range, -range);
+        let right_bottom = center + (range, range);
+        self.map.load_tiles_between(left_top, right_bottom);
+
+        let mut sources: Vec<(Point, u8)> = self
+            .map
+            .tiles_between(left_top, right_bottom)
+            .into_iter()
+            .filter_map(|(pos, tile)| {
+                let radius = tile
+                    .items
+                    .iter()
+                    .map(Item::emits_light)
+                    .chain([tile.terrain.emits_light()])
+                    .max()
+                    .unwrap_or(0);
+                (radius > 0).then_some((pos, radius))
+            })
+            .collect();
+        for unit in self.units.loaded_units() {
+            let Some(inventory) = unit.inventory() else {
+                continue;
+            };
+            let radius = [inventory.main_hand(), inventory.second_hand()]
+                .into_iter()
+                .flatten()
+                .map(Item::emits_light)
+                .max()
+                .unwrap_or(0);
+            if radius > 0 {
+                sources.push((unit.pos(), radius));
+            }
+        }
+
+        sources
+    }
+
+    /// Light level outside, depends on time of day and weather, there is no light underground
+    #[must_use]
+    pub fn ambient_light(&self) -> f32 {
+        if self.is_underground() {
+            return 0.0;
+        }
+        light::ambient_light(self.time(), self.weather.kind)
+    }
+
+    #[must_use]
+    pub fn is_underground(&self) -> bool {
+        self.map.level < 0
+    }
+
+    /// Weather doesn't affect the player underground
+    fn local_weather(&self) -> WeatherKind {
+        if self.is_underground() {
+            WeatherKind::Clear
+        } else {
+            self.weather.kind
+        }
+    }
+
+    /// Ambient light or light from the nearest sources, whichever is brighter
+    pub fn light_level(&self, pos: Point) -> f32 {
+        self.light_sources
+            .iter()
+            .map(|&(source, radius)| {
+                light::light_from_source(pos.square_distance_to(source), radius)
+            })
+            .fold(self.ambient_light(), f32::max)
     }
 
+    /// # Panics
+    ///
+    /// Panics if the savefile can't be written
     pub fn save(&mut self) {
         self.meta.update_before_save();
         savefile::save(self)
@@ -127,16 +278,31 @@ impl World {
 
     pub fn move_avatar(&mut self, unit_id: usize, dir: Direction) {
         let mut pos = self.units.get_unit(unit_id).pos();
+        let from = pos;
         let (old_chunk, _) = pos.to_chunk();
         self.map.get_tile_mut(pos).off_step(unit_id);
         pos += dir;
         let unit = self.units.get_unit_mut(unit_id);
         unit.set_pos(pos);
         unit.view_mut().try_set_direction(dir);
+        // climbing down, `Climb` puts it back up
+        unit.char_sheet_mut().elevated = false;
+        self.events.emit(GameEvent::UnitMoved {
+            unit_id,
+            from,
+            to: pos,
+        });
+        if self.map.get_tile_mut(pos).on_step(unit_id, &mut self.rng) {
+            self.events.emit(GameEvent::TerrainChanged {
+                pos,
+                terrain: self.map.get_tile(pos).terrain.clone(),
+            });
+        }
+        self.stop_walks_to(pos);
         let unit = self.units.get_unit(unit_id);
-        self.map.get_tile_mut(pos).on_step(unit_id);
         if unit.is_player() && old_chunk != pos.to_chunk().0 {
             self.units.load_units();
+            self.unload_distant_chunks(pos);
         }
         if self.units.get_unit(unit_id).is_player() {
             self.calc_fov();
@@ -147,7 +313,105 @@ impl World {
         }
     }
 
+    /// Walks of the units around to the tile somebody has just stepped into are cancelled
+    /// at once, so they don't wait for the end of the walk to find out the tile is occupied
+    fn stop_walks_to(&mut self, pos: Point) {
+        let current_tick = self.meta.current_tick;
+        let walkers: Vec<usize> = DIR8
+            .iter()
+            .filter_map(|&dir| self.map.get_tile_opt(pos + dir))
+            .flat_map(|tile| tile.units.iter().copied())
+            .collect();
+        for unit_id in walkers {
+            let Some(action) = self.units.get_unit(unit_id).action().cloned() else {
+                continue;
+            };
+            if !matches!(action.typ, ActionType::Walk(..)) {
+                continue;
+            }
+            if let ActionPossibility::No(reason) = action.typ.is_possible(unit_id, self) {
+                action.cancel_action(self, reason);
+                self.scheduler.forget(unit_id);
+                self.scheduler.schedule(unit_id, current_tick);
+            }
+        }
+    }
+
+    /// Units standing on the unloaded chunks are suspended with them
+    fn unload_distant_chunks(&mut self, center: Point) {
+        let unloaded: HashSet<ChunkPos> =
+            self.map.unload_distant_chunks(center).into_iter().collect();
+        if unloaded.is_empty() {
+            return;
+        }
+        let suspended: Vec<usize> = self
+            .units
+            .iter()
+            .filter(|(_, unit)| {
+                !unit.char_sheet().is_dead()
+                    && unloaded.contains(&unit.pos().to_chunk().0.with_z(unit.z()))
+            })
+            .map(|(&unit_id, _)| unit_id)
+            .collect();
+        for unit_id in suspended {
+            self.units.unload_unit(unit_id);
+            self.scheduler.forget(unit_id);
+        }
+    }
+
+    /// Moves unit to the level above or below, making sure there is a place to stand there
+    pub fn change_level(&mut self, unit_id: usize, dz: i32) {
+        let unit = self.units.get_unit(unit_id);
+        let (pos, from) = (unit.pos(), unit.z());
+        let to = from + dz;
+        let tile = self.map.get_tile_mut(pos);
+        let by_stairs = matches!(tile.terrain, Terrain::Stairs(..));
+        tile.off_step(unit_id);
+        self.units.get_unit_mut(unit_id).set_z(to);
+
+        if by_stairs {
+            let (way_back, stairs) = if dz < 0 {
+                (TerrainInteractAction::GoUp, Stairs::up())
+            } else {
+                (TerrainInteractAction::GoDown, Stairs::down())
+            };
+            let tile = self.map.get_tile_on_level_mut(pos, to);
+            if !tile.terrain.supports_action(way_back) {
+                tile.terrain = stairs.into();
+            }
+        } else {
+            // fell into the pit, digging out a bit of place around
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let tile = self.map.get_tile_on_level_mut(pos + (dx, dy), to);
+                    if !tile.terrain.is_passable() {
+                        tile.terrain = DataTerrain::new("dirt", &mut self.rng).into();
+                    }
+                }
+            }
+        }
+        self.map
+            .get_tile_on_level_mut(pos, to)
+            .on_step(unit_id, &mut self.rng);
+        self.events.emit(GameEvent::UnitChangedLevel {
+            unit_id,
+            pos,
+            from,
+            to,
+        });
+
+        if unit_id == 0 {
+            self.map.level = to;
+            self.units.load_units();
+            self.unload_distant_chunks(pos);
+            self.calc_fov();
+        } else {
+            self.units.unload_unit(unit_id);
+        }
+    }
+
     // TODO: move this somewhere else
+    #[must_use]
     pub fn this_is(&self, pos: Point, multiline: bool) -> String {
         let Some(tile) = self.map.get_tile_opt(pos) else {
             return "There is nothing here.".to_string();
@@ -157,7 +421,13 @@ impl World {
             this_is = this_is.replace(". ", ".\n");
         }
 
-        if !tile.items.is_empty() || !tile.units.is_empty() {
+        let units: Vec<usize> = tile
+            .units
+            .iter()
+            .copied()
+            .filter(|&i| self.detection.has_noticed(0, i))
+            .collect();
+        if !tile.items.is_empty() || !units.is_empty() {
             this_is.push(if multiline { '\n' } else { ' ' });
             this_is.push_str("Here you see: ");
             if multiline {
@@ -165,7 +435,7 @@ impl World {
             }
         }
 
-        let mut items: Vec<String> = Vec::with_capacity(tile.items.len() + tile.units.len());
+        let mut items: Vec<String> = Vec::with_capacity(tile.items.len() + units.len());
         if !tile.items.is_empty() {
             items.append(
                 &mut tile
@@ -175,12 +445,10 @@ impl World {
                     .collect(),
             );
         }
-        if !tile.units.is_empty() {
+        if !units.is_empty() {
             items.append(
-                &mut tile
-                    .units
-                    .iter()
-                    .copied()
+                &mut units
+                    .into_iter()
                     .map(|i| {
                         (if multiline { " - " } else { "" }).to_string()
                             + self.units.get_unit(i).name()
@@ -194,100 +462,482 @@ impl World {
     }
 
     pub fn add_unit(&mut self, unit: Box<dyn Avatar>) -> usize {
-        let pos = unit.pos();
+        let (pos, z) = (unit.pos(), unit.z());
         let new_id = self.units.add_unit(unit);
-        self.map.get_tile_mut(pos).units.insert(new_id);
+        self.map.get_tile_on_level_mut(pos, z).units.insert(new_id);
 
         new_id
     }
 
-    fn plan(&mut self) {
-        let mut units_to_act = HashMap::new();
-        for unit in self.units.loaded_units() {
-            if unit.action().is_some() {
+    /// Adds creatures of the chunks and migrants, wildlife without room waits in its chunk
+    fn spawn_monsters(&mut self) {
+        let level = self.map.level;
+        let mut waiting: Vec<ChunkPos> = self
+            .map
+            .chunks
+            .iter()
+            .filter(|(pos, chunk)| pos.z == level && !chunk.spawns.is_empty())
+            .map(|(&pos, _)| pos)
+            .collect();
+        // hash map order differs from run to run
+        waiting.sort_by_key(|c| (c.x, c.y));
+        let mut spawns = std::mem::take(&mut self.map.spawns);
+        for pos in waiting {
+            spawns.append(&mut self.map.get_chunk_mut(pos).spawns);
+        }
+
+        for spawn in spawns {
+            if spawn.wild && !self.has_room_for(spawn.pos, spawn.z) {
+                let (chunk, _) = spawn.pos.to_chunk();
+                self.map
+                    .get_chunk_mut(chunk.with_z(spawn.z))
+                    .spawns
+                    .push(spawn);
                 continue;
             }
-            if let Some(ai) = unit.ai() {
-                units_to_act.insert(unit.id(), ai);
-            }
+            let mut monster = Monster::from_prototype(&spawn.creature, spawn.pos);
+            monster.set_z(spawn.z);
+            self.add_unit(Box::new(monster));
         }
+    }
 
-        for (unit_id, ai) in units_to_act {
-            let action = AIManager::instance().plan(ai, unit_id, self);
-            self.units.get_unit_mut(unit_id).set_action(action);
+    /// Wildlife comes only to the free tiles of the bubble that isn't crowded yet
+    fn has_room_for(&mut self, pos: Point, z: i32) -> bool {
+        if z != self.map.level
+            || !self.units.in_bubble(pos)
+            || self.population.is_full(self.units.population())
+        {
+            return false;
         }
+        let tile = self.map.get_tile_on_level_mut(pos, z);
+        tile.is_passable() && !tile.terrain.is_deep_water() && tile.units.is_empty()
+    }
+
+    /// New creatures come to the places around from time to time, so visited ones don't stay empty
+    fn migrate(&mut self) {
+        let current_tick = self.meta.current_tick;
+        if current_tick < self.population.next_migration {
+            return;
+        }
+        self.population.next_migration = current_tick + MIGRATION_PERIOD;
+        if self.population.is_full(self.units.population()) {
+            return;
+        }
+
+        let level = self.map.level;
+        let mut chunks: Vec<ChunkPos> = self
+            .map
+            .chunks
+            .keys()
+            .filter(|c| c.z == level)
+            .copied()
+            .collect();
+        if chunks.is_empty() {
+            return;
+        }
+        // hash map order differs from run to run
+        chunks.sort_by_key(|c| (c.x, c.y));
+        let chunk = chunks[self.rng.random_range(0..chunks.len())];
+        let pos = Point::from_chunk(chunk, self.rng.random_range(0..Chunk::USIZE));
+        let player_pos = self.units.player().pos();
+        if pos.square_distance_to(player_pos) < MIGRATION_MIN_DISTANCE.pow(2)
+            || self.is_visible(pos)
+        {
+            return;
+        }
+        let Some(table) = GameData::instance()
+            .spawn_tables
+            .get(self.map.spawn_table_at(pos))
+        else {
+            return;
+        };
+        for (i, creature) in table.roll(&mut self.rng).into_iter().enumerate() {
+            self.map.spawns.push(Spawn {
+                pos: pos + Point::new(i as i32, 0),
+                z: level,
+                creature: creature.to_string(),
+                wild: true,
+            });
+        }
+        self.spawn_monsters();
+    }
+
+    /// Changes the length of the action that is just started, like after a roll on start
+    pub fn change_action_length(&mut self, unit_id: usize, length: u32) {
+        let unit = self.units.get_unit_mut(unit_id);
+        let Some(mut action) = unit.action().cloned() else {
+            return;
+        };
+        action.finish = action.finish - u128::from(action.length) + u128::from(length);
+        action.length = length;
+        self.scheduler.mark_started(unit_id, action.finish);
+        unit.set_action(Some(action));
+    }
+
+    /// Returns `true` if AI has found something to do
+    fn plan(&mut self, unit_id: usize) -> bool {
+        let Some(ai) = self.units.get_unit(unit_id).ai() else {
+            return false;
+        };
+        let action = AIManager::instance().plan(ai, unit_id, self);
+        let planned = action.is_some();
+        self.units.get_unit_mut(unit_id).set_action(action);
+        planned
     }
 
     #[cfg(test)]
     pub fn plan_test(&mut self) {
-        self.plan();
+        let idle: Vec<usize> = self
+            .units
+            .loaded_units()
+            .filter(|u| u.action().is_none())
+            .map(Avatar::id)
+            .collect();
+        for unit_id in idle {
+            self.plan(unit_id);
+        }
     }
 
-    /// Doing actions that should be done
-    fn act(&mut self) {
-        self.shock_out();
-        self.plan();
+    /// Tick when unit should be woken up next time, `None` if it's just waiting for player input
+    fn next_wake(&self, unit_id: usize) -> Option<u128> {
+        let unit = self.units.get_unit(unit_id);
+        let current_tick = self.meta.current_tick;
+        if unit.action().is_none() && unit.ai().is_some() {
+            return Some(current_tick);
+        }
 
-        let actions: Vec<Action> = self
+        let mut wake = unit.action().map(|a| a.finish);
+        let char_sheet = unit.char_sheet();
+        if char_sheet.shock {
+            let shock_out = (char_sheet.last_shock_out_roll + 10).max(current_tick);
+            wake = Some(wake.map_or(shock_out, |w| w.min(shock_out)));
+        }
+        if self
+            .map
+            .get_tile_opt(unit.pos())
+            .is_some_and(|tile| tile.terrain.is_deep_water())
+        {
+            let swim = char_sheet.last_swimming_roll.map_or(current_tick, |last| {
+                (last + SWIMMING_TURN).max(current_tick)
+            });
+            wake = Some(wake.map_or(swim, |w| w.min(swim)));
+        }
+        wake
+    }
+
+    /// Starts and schedules actions that were set outside of the world, like player input
+    fn sync_scheduler(&mut self) {
+        let units: Vec<usize> = self
             .units
             .loaded_units()
-            .filter_map(|u| u.action().cloned())
+            .filter(|u| !u.char_sheet().is_dead())
+            .map(Avatar::id)
             .collect();
-        for action in actions {
-            if self.units.get_unit(action.owner).char_sheet().is_dead() {
+        for unit_id in units {
+            if let Some(action) = self.units.get_unit(unit_id).action().cloned() {
+                if !self.scheduler.is_started(unit_id, action.finish) {
+                    self.scheduler.mark_started(unit_id, action.finish);
+                    action.start(self);
+                }
+            }
+            if let Some(wake) = self.next_wake(unit_id) {
+                if self.scheduler.wake_at(unit_id) != Some(wake) {
+                    self.scheduler.schedule(unit_id, wake);
+                }
+            }
+        }
+    }
+
+    /// Doing everything that should be done on current tick
+    fn act(&mut self) {
+        let current_tick = self.meta.current_tick;
+        if self.weather.update(current_tick, &mut self.rng) {
+            self.log.push(LogEvent::info(
+                format!("The weather is {} now.", self.weather.kind.name()),
+                self.units.player().pos(),
+            ));
+        }
+        self.update_detection();
+        self.migrate();
+        while let Some(unit_id) = self.scheduler.pop_due(current_tick) {
+            if !self.units.is_loaded(unit_id) || self.units.get_unit(unit_id).char_sheet().is_dead()
+            {
+                self.scheduler.forget(unit_id);
                 continue;
             }
+            self.wake(unit_id);
+        }
+    }
+
+    fn wake(&mut self, unit_id: usize) {
+        let current_tick = self.meta.current_tick;
+        self.shock_out(unit_id);
+        self.keep_afloat(unit_id);
+        if !self.units.is_loaded(unit_id) || self.units.get_unit(unit_id).char_sheet().is_dead() {
+            return;
+        }
+        self.rest(unit_id);
+        self.metabolize(unit_id);
+
+        if let Some(action) = self.units.get_unit(unit_id).action().cloned() {
+            if action.finish > current_tick {
+                // woken up earlier, to try to get out of the shock
+                if let Some(wake) = self.next_wake(unit_id) {
+                    self.scheduler.schedule(unit_id, wake);
+                }
+                return;
+            }
             action.act(self);
-            if self.meta.current_tick >= action.finish {
-                self.units.get_unit_mut(action.owner).set_action(None);
+            self.exert(unit_id, action.length * action.typ.exertion());
+            self.units.get_unit_mut(unit_id).set_action(None);
+            self.scheduler.forget(unit_id);
+            // dead or gone to another level
+            if !self.units.is_loaded(unit_id) || self.units.get_unit(unit_id).char_sheet().is_dead()
+            {
+                return;
             }
         }
-    }
 
-    pub const SPEND_LIMIT: u32 = 100; // TODO: probably it should be about 10-50
+        if self.units.get_unit(unit_id).ai().is_some() {
+            if self.plan(unit_id) {
+                let action = self.units.get_unit(unit_id).action().cloned().unwrap();
+                self.scheduler.mark_started(unit_id, action.finish);
+                action.start(self);
+            } else {
+                // nothing to do right now, trying again on the next tick
+                self.scheduler.schedule(unit_id, current_tick + 1);
+                return;
+            }
+        }
 
-    // TODO: move this to AI, probably
-    /// Shocked units trying to get out of the shock
-    fn shock_out(&mut self) {
+        if let Some(wake) = self.next_wake(unit_id) {
+            self.scheduler.schedule(unit_id, wake);
+        }
+    }
+
+    /// Periodic Notice rolls between the player and units around
+    fn update_detection(&mut self) {
         let current_tick = self.meta.current_tick;
-        let out_of_shock: Vec<usize> = self
+        if current_tick < self.detection.next_roll {
+            return;
+        }
+        self.detection.next_roll = current_tick + NOTICE_PERIOD;
+
+        let player_pos = self.units.player().pos();
+        let units: Vec<usize> = self
             .units
             .loaded_units()
-            .filter(|u| u.char_sheet().can_try_to_shock_out(current_tick))
+            .filter(|u| u.id() != 0 && !u.char_sheet().is_dead())
             .map(Avatar::id)
             .collect();
-        for unit_id in out_of_shock {
-            if self
-                .units
-                .get_unit_mut(unit_id)
-                .char_sheet_mut()
-                .try_to_shock_out(current_tick)
-            {
-                let unit = self.units.get_unit(unit_id);
+        // FOV from the player's tile is symmetric enough: if a unit's tile is there,
+        // nothing blocks the unit's sight of the player either
+        let max_sight_range = units
+            .iter()
+            .map(|&unit_id| self.units.get_unit(unit_id).char_sheet().sight_range())
+            .max()
+            .unwrap_or(0);
+        let line_of_sight = field_of_view_set(player_pos, max_sight_range as i32, &self.map);
+        for unit_id in units {
+            let unit = self.units.get_unit(unit_id);
+            let pos = unit.pos();
+            let player_can_see = self.is_visible(pos);
+            let unit_can_see = line_of_sight.contains(&pos)
+                && light::is_lit_enough(
+                    pos.square_distance_to(player_pos),
+                    unit.char_sheet().sight_range(),
+                    self.light_level(player_pos),
+                );
+            self.try_to_notice(0, unit_id, player_can_see);
+            self.try_to_notice(unit_id, 0, unit_can_see);
+        }
+    }
+
+    fn try_to_notice(&mut self, observer: usize, target: usize, can_see: bool) {
+        if !can_see {
+            self.detection.lose_sight(observer, target);
+            return;
+        }
+        if self.detection.has_noticed(observer, target) {
+            return;
+        }
+
+        let target_pos = self.units.get_unit(target).pos();
+        let distance = self
+            .units
+            .get_unit(observer)
+            .pos()
+            .distance_to(target_pos)
+            .floor() as i8;
+        let mut modifier = -distance / 10;
+        if self.light_level(target_pos) < 0.5 {
+            modifier -= 2;
+        }
+        if notice_roll(
+            self.units.get_unit(observer).char_sheet(),
+            self.units.get_unit(target).char_sheet(),
+            modifier,
+            &mut self.rng,
+        ) {
+            self.detection.notice(observer, target);
+            self.events
+                .emit(GameEvent::UnitNoticed { observer, target });
+            if observer == 0 {
+                let target = self.units.get_unit(target);
                 self.log.push(LogEvent::info(
-                    format!(
-                        "{} {} out of the shock!",
-                        unit.name_for_actions(),
-                        unit.pronouns().is_are()
-                    ),
-                    unit.pos(),
+                    format!("You notice {}.", target.name_for_actions()),
+                    target_pos,
                 ));
             }
         }
     }
 
+    // TODO: move this to AI, probably
+    /// Shocked unit trying to get out of the shock
+    fn shock_out(&mut self, unit_id: usize) {
+        let current_tick = self.meta.current_tick;
+        if self
+            .units
+            .get_unit_mut(unit_id)
+            .char_sheet_mut()
+            .try_to_shock_out(current_tick, &mut self.rng)
+        {
+            let unit = self.units.get_unit(unit_id);
+            self.log.push(LogEvent::info(
+                format!(
+                    "{} {} out of the shock!",
+                    unit.name_for_actions(),
+                    unit.pronouns().is_are()
+                ),
+                unit.pos(),
+            ));
+        }
+    }
+
+    /// Tired unit getting its breath back, not while swimming
+    fn rest(&mut self, unit_id: usize) {
+        let current_tick = self.meta.current_tick;
+        let pos = self.units.get_unit(unit_id).pos();
+        if self.map.get_tile(pos).terrain.is_deep_water() {
+            return;
+        }
+        self.units
+            .get_unit_mut(unit_id)
+            .char_sheet_mut()
+            .try_to_recover(current_tick);
+    }
+
+    /// Hunger and thirst, extras don't have to worry about them
+    fn metabolize(&mut self, unit_id: usize) {
+        let current_tick = self.meta.current_tick;
+        self.upkeep(unit_id, |char_sheet| char_sheet.metabolize(current_tick));
+    }
+
+    fn exert(&mut self, unit_id: usize, ticks: u32) {
+        if ticks > 0 {
+            self.upkeep(unit_id, |char_sheet| char_sheet.exert(ticks));
+        }
+    }
+
+    fn upkeep(&mut self, unit_id: usize, f: impl FnOnce(&mut CharSheet)) {
+        let unit = self.units.get_unit_mut(unit_id);
+        let char_sheet = unit.char_sheet_mut();
+        if !char_sheet.wild_card {
+            return;
+        }
+        let (hunger, thirst) = (char_sheet.hunger(), char_sheet.thirst());
+        f(char_sheet);
+        let state = if char_sheet.thirst() > thirst {
+            if char_sheet.thirst() > 1 {
+                "dehydrated"
+            } else {
+                "thirsty"
+            }
+        } else if char_sheet.hunger() > hunger {
+            if char_sheet.hunger() > 1 {
+                "starving"
+            } else {
+                "hungry"
+            }
+        } else {
+            return;
+        };
+        self.log.push(LogEvent::warning(
+            format!(
+                "{} {} {state}.",
+                unit.name_for_actions(),
+                unit.pronouns().is_are()
+            ),
+            unit.pos(),
+        ));
+    }
+
+    /// Unit in the deep water rolls Swimming every turn, whether it's moving or staying
+    pub fn keep_afloat(&mut self, unit_id: usize) {
+        let current_tick = self.meta.current_tick;
+        let unit = self.units.get_unit(unit_id);
+        let pos = unit.pos();
+        let due = unit
+            .char_sheet()
+            .last_swimming_roll
+            .is_none_or(|last| current_tick >= last + SWIMMING_TURN);
+        if due && self.map.get_tile(pos).terrain.is_deep_water() {
+            self.swim(unit_id);
+        }
+    }
+
+    /// Swimming roll for the unit in the deep water, failed ones are tiring and then drowning
+    fn swim(&mut self, unit_id: usize) {
+        let current_tick = self.meta.current_tick;
+        let unit = self.units.get_unit_mut(unit_id);
+        unit.char_sheet_mut().last_swimming_roll = Some(current_tick);
+        let pos = unit.pos();
+        let heavy_items = unit.inventory().map_or(0, Inventory::heavy_items);
+        if swimming_roll(unit.char_sheet(), heavy_items, &mut self.rng) {
+            return;
+        }
+
+        let unit = self.units.get_unit_mut(unit_id);
+        if unit.char_sheet_mut().add_fatigue(current_tick) {
+            self.log.push(LogEvent::warning(
+                format!(
+                    "{} {} struggling to stay afloat.",
+                    unit.name_for_actions(),
+                    unit.pronouns().is_are()
+                ),
+                pos,
+            ));
+        } else {
+            self.log.push(LogEvent::danger(
+                format!(
+                    "{} {} drowning!",
+                    unit.name_for_actions(),
+                    unit.pronouns().is_are()
+                ),
+                pos,
+            ));
+            let hit = HitResult::wound(&mut self.rng);
+            self.apply_damage(unit_id, hit);
+        }
+    }
+
     pub fn apply_damage(&mut self, unit_id: usize, hit: HitResult) {
-        if unit_id == 0 && Settings::instance().debug.god_mode {
+        if unit_id == 0 && self.god_mode {
             return;
         }
         let current_tick = self.meta.current_tick;
         let pos = self.units.get_unit(unit_id).pos();
-        let items_dropped = self
-            .units
-            .get_unit_mut(unit_id)
-            .apply_hit(hit, current_tick);
+        let items_dropped =
+            self.units
+                .get_unit_mut(unit_id)
+                .apply_hit(hit, current_tick, &mut self.rng);
         if let Some(items_dropped) = items_dropped {
             for item in items_dropped {
+                self.events.emit(GameEvent::ItemDropped {
+                    unit_id: Some(unit_id),
+                    item: Box::new(item.clone()),
+                    pos,
+                });
                 self.map.get_tile_mut(pos).items.push(item);
             }
         }
@@ -302,22 +952,54 @@ impl World {
                 ),
                 pos,
             ));
+            self.events.emit(GameEvent::UnitDied { unit_id, pos });
             self.map.get_tile_mut(pos).units.remove(&unit_id);
             self.units.unload_unit(unit_id);
+            self.scheduler.forget(unit_id);
+            self.detection.forget_unit(unit_id);
+        } else if let Some(wake) = self.next_wake(unit_id) {
+            // shock could make unit wake up earlier
+            self.scheduler.schedule(unit_id, wake);
         }
     }
 
+    /// Runs the world until player's action is done, jumping straight to the next scheduled event
     pub fn tick(&mut self) {
+        self.spawn_monsters();
+        self.sync_scheduler();
         self.act();
 
-        let mut spend = 0;
-        while self.units.player().action().is_some() && spend < Self::SPEND_LIMIT {
-            self.meta.current_tick += 1;
-            spend += 1;
+        while self.units.player().action().is_some() && !self.units.player().char_sheet().is_dead()
+        {
+            let Some(next_tick) = self.scheduler.next_tick() else {
+                break;
+            };
+            self.meta.current_tick = self.meta.current_tick.max(next_tick);
             self.act();
         }
+
+        // light could change while time passed
+        self.calc_fov();
+        self.events.dispatch();
+    }
+
+    /// Time and weather for the HUD, or how deep the player is
+    #[must_use]
+    pub fn time_and_weather(&self) -> String {
+        if self.is_underground() {
+            format!("{}, underground level {}", self.time(), -self.map.level)
+        } else {
+            format!("{}, {}", self.time(), self.weather.kind.name())
+        }
+    }
+
+    /// Current date and time of the world
+    #[must_use]
+    pub fn time(&self) -> DateTime {
+        DateTime::from_tick(self.meta.current_tick)
     }
 
+    #[must_use]
     pub fn player(&self) -> &Player {
         self.units.player()
     }
@@ -326,10 +1008,17 @@ impl World {
         self.units.player_mut()
     }
 
+    /// # Panics
+    ///
+    /// Panics if the player has no inventory
+    #[must_use]
     pub fn player_inventory(&self) -> &Inventory {
         self.units.player().inventory().unwrap()
     }
 
+    /// # Panics
+    ///
+    /// Panics if the player has no inventory
     pub fn player_inventory_mut(&mut self) -> &mut Inventory {
         self.units.player_mut().inventory_mut().unwrap()
     }
@@ -337,30 +1026,39 @@ impl World {
 
 #[cfg(test)]
 pub mod tests {
+    use std::cell::RefCell;
     use std::collections::HashMap;
+    use std::rc::Rc;
 
     use super::{
         super::{
-            actions::implements::{Skip, Walk},
+            actions::implements::{Dig, Skip, Walk},
             ai::AI,
-            map::terrains::{Boulder, BoulderSize, Dirt},
+            map::terrains::DataTerrain,
+            population::POPULATION_CAP,
             races::{Pronouns, Sex},
+            savage::{FATIGUE_LIMIT, FATIGUE_RECOVERY, HYDRATION_MAX, NUTRITION_MAX},
             units::{tests::helpers::tester_girl, Appearance, Avatar, Monster},
             CharSheet, Race,
         },
-        savefile::{GameView, Meta},
-        Action, Direction, Log, Player, TerrainView, World,
+        savefile::{chunks_path, ChunkStorage, GameView, Meta},
+        Action, Detection, Direction, GameEvent, HitResult, Item, Log, Player, Population, Spawn,
+        TerrainView, Weather, World, WorldRng,
     };
-    use crate::game::map::terrains::DirtVariant;
-    use crate::game::{AttrLevel, SkillLevel, Terrain};
+    use crate::game::calendar::TICKS_PER_HOUR;
+    use crate::game::map::items::helpers::{DEMONIC_CHUNK, STONE_SHOVEL, TORCH};
+    use crate::game::{
+        AttrLevel, Attribute, Dice, SkillLevel, Terrain, TerrainInteract, WeatherKind,
+    };
+    use rand::SeedableRng;
     use roguemetry::Point;
 
     pub fn boulder() -> Terrain {
-        Boulder::new(BoulderSize::Huge).into()
+        DataTerrain::with_variant("huge_boulder", 0).into()
     }
 
     pub fn dirt() -> Terrain {
-        Dirt::new(DirtVariant::Dirt1).into()
+        DataTerrain::with_variant("dirt", 0).into()
     }
 
     pub fn prepare_world() -> World {
@@ -368,20 +1066,38 @@ pub mod tests {
             Meta::new("test", 1),
             GameView::default(),
             Log::new(),
+            WorldRng::seed_from_u64(1),
+            Weather::new(),
+            Detection::new(),
+            Population::new(0),
             HashMap::from([(
                 0usize,
                 Box::new(Player::new(tester_girl(), Point::new(0, 0))) as Box<dyn Avatar>,
             )]),
             HashMap::new(),
+            HashMap::new(),
         );
         world.map.get_tile_mut(Point::new(0, 0)).terrain = dirt();
         world.map.get_tile_mut(Point::new(0, 0)).units.insert(0);
+        // no wildlife, tests add monsters they need
+        world.population.cap = 0;
+
+        world
+    }
+
+    /// World where the player holds nothing but the given tool
+    pub fn prepare_world_with_tool(tool: Option<&str>) -> World {
+        let mut world = prepare_world();
+        world.player_mut().inventory.clear();
+        if let Some(tool) = tool {
+            world.player_mut().inventory.wield(Item::new(tool));
+        }
 
         world
     }
 
     pub fn add_dummy(world: &mut World, pos: Point) -> usize {
-        world.add_unit(Box::new(Monster::new(
+        let unit_id = world.add_unit(Box::new(Monster::new(
             AI::Dummy,
             pos,
             "Dummy".to_string(),
@@ -393,7 +1109,15 @@ pub mod tests {
             },
             Pronouns::ItIts,
             CharSheet::default(false, Race::Gazan),
-        )))
+        )));
+        in_plain_sight(world, unit_id);
+        unit_id
+    }
+
+    /// Test units and the player have already noticed each other
+    fn in_plain_sight(world: &mut World, unit_id: usize) {
+        world.detection.notice(0, unit_id);
+        world.detection.notice(unit_id, 0);
     }
 
     pub fn add_monster(world: &mut World, pos: Point) -> usize {
@@ -404,7 +1128,7 @@ pub mod tests {
         charsheet.skills.fighting = SkillLevel::D6;
         charsheet.skills.shooting = SkillLevel::D8;
         charsheet.skills.notice = SkillLevel::D6;
-        world.add_unit(Box::new(Monster::new(
+        let unit_id = world.add_unit(Box::new(Monster::new(
             AI::BasicMonster,
             pos,
             "Old Bugger".to_string(),
@@ -416,7 +1140,9 @@ pub mod tests {
             },
             Pronouns::ItIts,
             charsheet,
-        )))
+        )));
+        in_plain_sight(world, unit_id);
+        unit_id
     }
 
     #[test]
@@ -438,6 +1164,21 @@ pub mod tests {
         assert_eq!(Point::new(2, 0), world.units.get_unit(monster_id).pos())
     }
 
+    #[test]
+    pub fn test_long_action_is_done_in_one_tick() {
+        let mut world = prepare_world();
+        let monster_id = add_dummy(&mut world, Point::new(3, 0));
+
+        let action = Action::new(0, Skip::new(1000), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert_eq!(1000, world.meta.current_tick);
+        assert!(world.player().action().is_none());
+        // dummy keeps planning new skips while player is resting
+        let monster_action = world.units.get_unit(monster_id).action().unwrap();
+        assert!(monster_action.finish > 1000);
+    }
+
     #[test]
     pub fn test_fov() {
         let mut world = prepare_world();
@@ -457,4 +1198,406 @@ pub mod tests {
         assert!(world.is_visible(Point::new(2, 0)));
         assert!(!world.is_visible(Point::new(3, 0)));
     }
+
+    #[test]
+    pub fn test_darkness_and_fog_limit_fov() {
+        let mut world = prepare_world();
+        for x in 1..=10 {
+            world.map.get_tile_mut(Point::new(x, 0)).terrain = dirt();
+        }
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(10, 0)));
+
+        world.meta.current_tick = TICKS_PER_HOUR * 16; // midnight
+        world.weather.until = u128::MAX;
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(1, 0)));
+        assert!(!world.is_visible(Point::new(10, 0)));
+
+        world.meta.current_tick = 0;
+        world.weather.kind = WeatherKind::Fog;
+        world.calc_fov();
+        let range = world.player().char_sheet().sight_range() as i32;
+        assert!(!world.is_visible(Point::new(range - 1, 0)));
+    }
+
+    #[test]
+    pub fn test_light_sources_at_night() {
+        let mut world = prepare_world();
+        for x in 1..=10 {
+            world.map.get_tile_mut(Point::new(x, 0)).terrain = dirt();
+        }
+        world.meta.current_tick = TICKS_PER_HOUR * 16;
+        world.weather.until = u128::MAX;
+        world
+            .map
+            .get_tile_mut(Point::new(8, 0))
+            .items
+            .push(Item::new(DEMONIC_CHUNK));
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(8, 0)));
+        assert!(!world.is_visible(Point::new(5, 0)));
+
+        world.player_inventory_mut().wield(Item::new(TORCH));
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(5, 0)));
+        assert!(world.light_level(Point::new(5, 0)) > world.ambient_light());
+    }
+
+    #[test]
+    pub fn test_terrain_light_sources_at_night() {
+        let mut world = prepare_world();
+        for x in 1..=10 {
+            world.map.get_tile_mut(Point::new(x, 0)).terrain = dirt();
+        }
+        world.meta.current_tick = TICKS_PER_HOUR * 16;
+        world.weather.until = u128::MAX;
+        world.calc_fov();
+        assert!(!world.is_visible(Point::new(6, 0)));
+
+        world.map.get_tile_mut(Point::new(8, 0)).terrain =
+            DataTerrain::new("campfire", &mut world.rng).into();
+        world.calc_fov();
+        assert!(world.is_visible(Point::new(8, 0)));
+        assert!(world.is_visible(Point::new(6, 0)));
+        assert!(world.light_level(Point::new(6, 0)) > world.ambient_light());
+    }
+
+    #[test]
+    pub fn test_map_memory() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+        world.map.get_tile_mut(Point::new(2, 0)).terrain = dirt();
+        world.calc_fov();
+        assert!(world.map.remembered(Point::new(2, 0)).is_some());
+        assert!(world.map.remembered(Point::new(100, 100)).is_none());
+
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = boulder();
+        world.calc_fov();
+        world.map.get_tile_mut(Point::new(2, 0)).terrain = boulder();
+        world.calc_fov();
+        assert!(!world.is_visible(Point::new(2, 0)));
+        let remembered = world.map.remembered(Point::new(2, 0)).unwrap();
+        assert!(remembered.terrain.is_passable());
+    }
+
+    #[test]
+    pub fn test_notice_rolls() {
+        let mut world = prepare_world();
+        for x in 1..=3 {
+            world.map.get_tile_mut(Point::new(x, 0)).terrain = dirt();
+        }
+        let dummy = add_dummy(&mut world, Point::new(3, 0));
+        world.detection.forget_unit(dummy);
+        world.calc_fov();
+        let far_away = add_dummy(&mut world, Point::new(60, 0));
+        assert!(!world.detection.has_noticed(0, dummy));
+        assert!(world.detection.has_noticed(0, far_away));
+
+        let action = Action::new(0, Skip::new(1000), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert!(world.detection.has_noticed(0, dummy));
+        assert!(!world.detection.has_noticed(0, far_away));
+        assert!(!world.this_is(Point::new(60, 0), false).contains("Dummy"));
+    }
+
+    #[test]
+    pub fn test_no_notice_through_walls() {
+        let mut world = prepare_world();
+        for x in 1..=3 {
+            world.map.get_tile_mut(Point::new(x, 0)).terrain = dirt();
+        }
+        world.map.get_tile_mut(Point::new(2, 0)).terrain = boulder();
+        let dummy = add_dummy(&mut world, Point::new(3, 0));
+        world.calc_fov();
+
+        let action = Action::new(0, Skip::new(1000), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+        assert!(!world.detection.has_noticed(dummy, 0));
+        assert!(!world.detection.has_noticed(0, dummy));
+    }
+
+    #[test]
+    pub fn test_units_are_suspended_with_chunks() {
+        let path = std::env::temp_dir().join("midia_test_suspended_units.save");
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+        let mut world = prepare_world();
+        world.map.attach_storage(ChunkStorage::open(&path));
+        let unit_id = add_dummy(&mut world, Point::new(1000, 0));
+        let action = Action::new(unit_id, Skip::new(1000), &world).unwrap();
+        world.units.get_unit_mut(unit_id).set_action(Some(action));
+        world.scheduler.schedule(unit_id, 1000);
+
+        world.unload_distant_chunks(Point::new(0, 0));
+        assert!(!world.units.is_loaded(unit_id));
+        assert!(world.units.get_unit(unit_id).action().is_none());
+        assert_eq!(None, world.scheduler.wake_at(unit_id));
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+    }
+
+    #[test]
+    pub fn test_same_seed_same_rolls() {
+        let mut first = prepare_world();
+        let mut second = prepare_world();
+        let first_rolls: Vec<u8> = (0..20).map(|_| Dice::D12.roll(&mut first.rng)).collect();
+        let second_rolls: Vec<u8> = (0..20).map(|_| Dice::D12.roll(&mut second.rng)).collect();
+        assert_eq!(first_rolls, second_rolls);
+    }
+
+    #[test]
+    pub fn test_rng_state_survives_serialization() {
+        let mut world = prepare_world();
+        Dice::D6.roll(&mut world.rng);
+        let serialized = serde_json::to_string(&world.rng).unwrap();
+        let mut restored: WorldRng = serde_json::from_str(&serialized).unwrap();
+        for _ in 0..20 {
+            assert_eq!(
+                Dice::D12.roll(&mut world.rng),
+                Dice::D12.roll(&mut restored)
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_prefab_monsters_are_spawned() {
+        let mut world = prepare_world();
+        world.map.spawns.push(Spawn {
+            pos: Point::new(3, 3),
+            z: 0,
+            creature: "giant_bug".to_string(),
+            wild: false,
+        });
+        world.tick();
+
+        let tile = world.map.get_tile(Point::new(3, 3));
+        assert_eq!(1, tile.units.len());
+        let unit_id = *tile.units.iter().next().unwrap();
+        assert_eq!("giant bug", world.units.get_unit(unit_id).name());
+        assert!(world.map.spawns.is_empty());
+    }
+
+    #[test]
+    pub fn test_wildlife_is_limited_by_population_cap() {
+        let mut world = prepare_world();
+        world.population.cap = 2;
+        for x in 2..6 {
+            world.map.get_tile_mut(Point::new(x, 3)).terrain = dirt();
+            world.map.spawns.push(Spawn {
+                pos: Point::new(x, 3),
+                z: 0,
+                creature: "green_bug".to_string(),
+                wild: true,
+            });
+        }
+        world.spawn_monsters();
+        assert_eq!(2, world.units.population());
+
+        world.map.get_tile_mut(Point::new(7, 3)).terrain = dirt();
+        world.map.spawns.push(Spawn {
+            pos: Point::new(7, 3),
+            z: 0,
+            creature: "shrine_guardian".to_string(),
+            wild: false,
+        });
+        world.spawn_monsters();
+        assert_eq!(3, world.units.population());
+    }
+
+    #[test]
+    pub fn test_wildlife_waits_for_room() {
+        let mut world = prepare_world();
+        let near = Point::new(3, 3);
+        let far = Point::new(200, 0);
+        world.map.get_tile_mut(near).terrain = dirt();
+        for pos in [near, far] {
+            world.map.spawns.push(Spawn {
+                pos,
+                z: 0,
+                creature: "green_bug".to_string(),
+                wild: true,
+            });
+        }
+        world.spawn_monsters();
+        assert_eq!(0, world.units.population());
+        let (chunk, _) = world.map.chunk_pos(near);
+        assert!(world
+            .map
+            .get_chunk(chunk)
+            .spawns
+            .iter()
+            .any(|spawn| spawn.pos == near));
+
+        world.population.cap = POPULATION_CAP;
+        world.spawn_monsters();
+        assert_eq!(1, world.map.get_tile(near).units.len());
+        let (chunk, _) = world.map.chunk_pos(far);
+        assert!(world
+            .map
+            .get_chunk(chunk)
+            .spawns
+            .iter()
+            .any(|spawn| spawn.pos == far));
+    }
+
+    #[test]
+    pub fn test_migration() {
+        let mut world = prepare_world();
+        world.population.cap = POPULATION_CAP;
+        for _ in 0..100 {
+            world.population.next_migration = world.meta.current_tick;
+            world.migrate();
+        }
+        let population = world.units.population();
+        assert!(population > 0);
+        assert!(population <= POPULATION_CAP);
+        assert!(world
+            .units
+            .loaded_units()
+            .filter(|unit| !unit.is_player())
+            .all(|unit| !world.is_visible(unit.pos())));
+    }
+
+    #[test]
+    pub fn test_move_events() {
+        let mut world = prepare_world();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        world.events.subscribe(events.clone());
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+
+        let action = Action::new(0, Walk::new(Direction::East), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+
+        assert!(events.borrow().iter().any(|e| matches!(
+            e,
+            GameEvent::UnitMoved { unit_id: 0, from, to }
+                if *from == Point::new(0, 0) && *to == Point::new(1, 0)
+        )));
+    }
+
+    #[test]
+    pub fn test_unit_died_event() {
+        let mut world = prepare_world();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        world.events.subscribe(events.clone());
+        let monster_id = add_dummy(&mut world, Point::new(1, 0));
+        world.scheduler.schedule(monster_id, 10);
+
+        let hit = HitResult::ultra_damage(&mut world.rng);
+        world.apply_damage(monster_id, hit);
+        world.events.dispatch();
+        assert_eq!(None, world.scheduler.wake_at(monster_id));
+
+        let events = events.borrow();
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, GameEvent::UnitDied { unit_id, .. } if *unit_id == monster_id)));
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::ItemDropped { unit_id: Some(unit_id), .. } if *unit_id == monster_id
+        )));
+    }
+
+    #[test]
+    pub fn test_drowning() {
+        let mut world = prepare_world();
+        let unit_id = add_dummy(&mut world, Point::new(1, 0));
+        world.map.get_tile_mut(Point::new(1, 0)).terrain =
+            DataTerrain::new("deep_water", &mut world.rng).into();
+
+        for _ in 0..100 {
+            world.swim(unit_id);
+            if world.units.get_unit(unit_id).char_sheet().is_dead() {
+                break;
+            }
+        }
+        let char_sheet = world.units.get_unit(unit_id).char_sheet();
+        assert!(char_sheet.is_dead());
+        assert_eq!(FATIGUE_LIMIT, char_sheet.fatigue);
+    }
+
+    #[test]
+    pub fn test_swimming_while_staying_in_deep_water() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(0, 0)).terrain =
+            DataTerrain::new("deep_water", &mut world.rng).into();
+        world.player_mut().char_sheet_mut().skills.swimming = SkillLevel::None;
+
+        let action = Action::new(0, Skip::new(FATIGUE_RECOVERY as u32), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+
+        let char_sheet = world.player().char_sheet();
+        assert_eq!(FATIGUE_LIMIT, char_sheet.fatigue);
+        // more than one roll, though the player didn't move
+        assert!(world
+            .log
+            .new_events()
+            .iter()
+            .any(|e| e.msg.contains("drowning")));
+    }
+
+    #[test]
+    pub fn test_resting_after_swimming() {
+        let mut world = prepare_world();
+        world.player_mut().char_sheet_mut().fatigue = FATIGUE_LIMIT;
+
+        let action = Action::new(0, Skip::new(FATIGUE_RECOVERY as u32), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+
+        assert_eq!(FATIGUE_LIMIT - 1, world.player().char_sheet().fatigue);
+    }
+
+    #[test]
+    pub fn test_thirst() {
+        let mut world = prepare_world();
+        world.player_mut().char_sheet_mut().last_metabolism = Some(0);
+        let vigor = world
+            .player()
+            .char_sheet()
+            .get_attribute_with_modifiers(Attribute::Vigor);
+
+        let action = Action::new(0, Skip::new(HYDRATION_MAX), &world).unwrap();
+        world.player_mut().set_action(Some(action));
+        world.tick();
+
+        let char_sheet = world.player().char_sheet();
+        assert_eq!(0, char_sheet.hydration);
+        assert_eq!(0, char_sheet.hunger());
+        assert_eq!(FATIGUE_LIMIT, char_sheet.fatigue_penalty());
+        assert_eq!(
+            vigor.modifier() - FATIGUE_LIMIT as i8,
+            char_sheet
+                .get_attribute_with_modifiers(Attribute::Vigor)
+                .modifier()
+        );
+        assert!(world
+            .log
+            .new_events()
+            .iter()
+            .any(|e| e.msg.contains("dehydrated")));
+    }
+
+    #[test]
+    pub fn test_hard_work_makes_hungry() {
+        let mut world = prepare_world();
+        world.map.get_tile_mut(Point::new(1, 0)).terrain = dirt();
+        world.player_mut().inventory.clear();
+        world.player_mut().inventory.wield(Item::new(STONE_SHOVEL));
+        world.player_mut().char_sheet_mut().last_metabolism = Some(0);
+
+        let action = Action::new(0, Dig::new(Direction::East), &world).unwrap();
+        let length = action.length;
+        world.player_mut().set_action(Some(action));
+        world.tick();
+
+        // ticks passed and twice as much for the digging
+        assert_eq!(
+            NUTRITION_MAX - length * 3,
+            world.player().char_sheet().nutrition
+        );
+    }
 }
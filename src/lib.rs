@@ -0,0 +1,15 @@
+//! Game rules, world and savefiles of the Midia, without any windowing or rendering.
+//! Used by the game itself and by the headless `midia-sim`.
+
+pub mod colors;
+pub mod game;
+pub mod lang;
+pub mod savefile;
+pub mod sprite;
+
+pub const NAME: &str = "Followers Of The Midia";
+pub const VERSION: &str = concat!(
+    "v",
+    env!("CARGO_PKG_VERSION"),
+    env!("MIDIA_VERSION_POSTFIX")
+);
@@ -0,0 +1,324 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::game::{map::ChunkMemory, Chunk, ChunkPos};
+
+use super::save::Error;
+
+#[must_use]
+pub fn chunks_path(save_path: &Path) -> PathBuf {
+    save_path.with_extension("chunks")
+}
+
+/// Staging folder inside the chunks folder, for chunks unloaded after the last save
+const STAGING: &str = "staging";
+
+/// What is kept in the file
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+enum Part {
+    Chunk,
+    /// Player's memory of the chunk, goes with it
+    Memory,
+}
+
+type Key = (Part, ChunkPos);
+
+/// Folder with chunks unloaded from the memory, one file per chunk and one per its
+/// explored tiles.
+///
+/// Chunks are unloaded to the staging folder first and moved to the main one only when
+/// the world is saved, so the main folder always matches the savefile.
+#[derive(Debug)]
+pub struct ChunkStorage {
+    dir: PathBuf,
+    /// Files in the main folder, saved with the world
+    committed: HashSet<Key>,
+    /// Files in the staging folder, unloaded after the last save
+    staged: HashSet<Key>,
+    /// Files that are on the disk and not in the memory
+    stored: HashSet<Key>,
+}
+
+impl ChunkStorage {
+    /// Opens storage of the savefile, chunks that are there already are found by file names.
+    /// Chunks that were unloaded but never saved with the world are thrown away.
+    #[must_use]
+    pub fn open(save_path: &Path) -> Self {
+        let dir = chunks_path(save_path);
+        std::fs::remove_dir_all(dir.join(STAGING)).ok();
+        let committed: HashSet<Key> = dir
+            .read_dir()
+            .map(|read_dir| {
+                read_dir
+                    .filter_map(|entry| {
+                        let name = entry.ok()?.file_name();
+                        file_name_to_key(name.to_str()?)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            dir,
+            stored: committed.clone(),
+            committed,
+            staged: HashSet::new(),
+        }
+    }
+
+    /// Chunk was loaded from the savefile itself, so the one on the disk is outdated
+    pub fn forget(&mut self, pos: ChunkPos) {
+        self.stored.remove(&(Part::Chunk, pos));
+    }
+
+    /// Memory of the chunk was loaded from the savefile itself
+    pub fn forget_memory(&mut self, pos: ChunkPos) {
+        self.stored.remove(&(Part::Memory, pos));
+    }
+
+    /// Unloads the chunk to the staging folder
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk file can't be written
+    pub fn save(&mut self, chunk: &Chunk) -> Result<(), Error> {
+        self.store((Part::Chunk, chunk.pos), &serde_json::to_string(chunk)?)
+    }
+
+    /// Unloads explored tiles of the chunk to the staging folder
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the memory file can't be written
+    pub fn save_memory(&mut self, memory: &ChunkMemory) -> Result<(), Error> {
+        self.store((Part::Memory, memory.pos), &serde_json::to_string(memory)?)
+    }
+
+    /// Loads chunk back to the memory. Staged file is deleted right away, the saved one
+    /// is kept until the chunk is saved with the world.
+    pub fn take(&mut self, pos: ChunkPos) -> Option<Chunk> {
+        serde_json::from_str(&self.take_data((Part::Chunk, pos))?).ok()
+    }
+
+    /// Loads explored tiles of the chunk back, the same way as the chunk itself
+    pub fn take_memory(&mut self, pos: ChunkPos) -> Option<ChunkMemory> {
+        serde_json::from_str(&self.take_data((Part::Memory, pos))?).ok()
+    }
+
+    fn store(&mut self, key: Key, data: &str) -> Result<(), Error> {
+        let staging = self.dir.join(STAGING);
+        if !staging.exists() {
+            std::fs::create_dir_all(&staging).map_err(Error::from)?;
+        }
+        let mut file = File::create(staging.join(key_to_file_name(key)))?;
+        file.write_all(data.as_bytes())?;
+        self.staged.insert(key);
+        self.stored.insert(key);
+        Ok(())
+    }
+
+    fn take_data(&mut self, key: Key) -> Option<String> {
+        if !self.stored.remove(&key) {
+            return None;
+        }
+        let path = self.path(key);
+        let data = std::fs::read_to_string(&path).ok();
+        if self.staged.remove(&key) {
+            std::fs::remove_file(path).ok();
+        }
+        data
+    }
+
+    /// Called when the world is saved: staged chunks are moved to the main folder and chunks
+    /// that are in the savefile now are deleted from it.
+    /// If the world is saved to another file, stored chunks are copied there instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk files can't be moved or copied
+    pub fn commit(&mut self, save_path: &Path) -> Result<(), Error> {
+        let dir = chunks_path(save_path);
+        if dir != self.dir {
+            return self.copy_to(&dir);
+        }
+
+        if !self.staged.is_empty() {
+            std::fs::create_dir_all(&self.dir).map_err(Error::from)?;
+        }
+        for key in std::mem::take(&mut self.staged) {
+            let name = key_to_file_name(key);
+            std::fs::rename(self.dir.join(STAGING).join(&name), self.dir.join(&name))
+                .map_err(Error::from)?;
+            self.committed.insert(key);
+        }
+        for &key in self.committed.difference(&self.stored) {
+            std::fs::remove_file(self.dir.join(key_to_file_name(key))).map_err(Error::from)?;
+        }
+        self.committed.clone_from(&self.stored);
+        Ok(())
+    }
+
+    /// Stored file, the staged one is newer
+    fn path(&self, key: Key) -> PathBuf {
+        let name = key_to_file_name(key);
+        if self.staged.contains(&key) {
+            self.dir.join(STAGING).join(name)
+        } else {
+            self.dir.join(name)
+        }
+    }
+
+    fn copy_to(&self, dir: &Path) -> Result<(), Error> {
+        // leftovers of the world that was saved there before
+        std::fs::remove_dir_all(dir).ok();
+        if self.stored.is_empty() {
+            return Ok(());
+        }
+        std::fs::create_dir_all(dir).map_err(Error::from)?;
+        for &key in &self.stored {
+            std::fs::copy(self.path(key), dir.join(key_to_file_name(key))).map_err(Error::from)?;
+        }
+        Ok(())
+    }
+}
+
+fn key_to_file_name((part, pos): Key) -> String {
+    match part {
+        Part::Chunk => format!("{}_{}_{}.json", pos.x, pos.y, pos.z),
+        Part::Memory => format!("{}_{}_{}.memory.json", pos.x, pos.y, pos.z),
+    }
+}
+
+fn file_name_to_key(name: &str) -> Option<Key> {
+    if let Some(name) = name.strip_suffix(".memory.json") {
+        return Some((Part::Memory, file_name_to_pos(name)?));
+    }
+    Some((Part::Chunk, file_name_to_pos(name.strip_suffix(".json")?)?))
+}
+
+fn file_name_to_pos(name: &str) -> Option<ChunkPos> {
+    let mut coords = name.split('_');
+    let x = coords.next()?.parse().ok()?;
+    let y = coords.next()?.parse().ok()?;
+    let z = coords.next()?.parse().ok()?;
+    Some(ChunkPos::new(x, y).with_z(z))
+}
+
+#[cfg(test)]
+mod tests {
+    use roguemetry::Point;
+
+    use crate::game::world::tests::{boulder, prepare_world};
+    use crate::game::{ChunkPos, TerrainInteract};
+
+    use super::{chunks_path, file_name_to_key, key_to_file_name, ChunkStorage, Part, STAGING};
+
+    #[test]
+    fn test_file_names() {
+        let pos = ChunkPos::new(-3, 12).with_z(-2);
+        for part in [Part::Chunk, Part::Memory] {
+            let key = (part, pos);
+            assert_eq!(Some(key), file_name_to_key(&key_to_file_name(key)));
+        }
+        assert_eq!(None, file_name_to_key("1_2.json"));
+    }
+
+    #[test]
+    fn test_distant_chunks_are_unloaded_and_streamed_back() {
+        let path = std::env::temp_dir().join("midia_test_chunks.save");
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+        let mut world = prepare_world();
+        world.map.attach_storage(ChunkStorage::open(&path));
+        let far = Point::new(1000, 0);
+        let (far_chunk, _) = world.map.chunk_pos(far);
+        world.map.get_tile_mut(far).terrain = boulder();
+        world.map.get_tile(Point::new(1000, 1000));
+
+        world.map.unload_distant_chunks(Point::new(0, 0));
+        assert!(!world.map.chunks.contains_key(&far_chunk));
+        assert!(!world.map.changed.contains(&far_chunk));
+        let staged = chunks_path(&path)
+            .join(STAGING)
+            .join(key_to_file_name((Part::Chunk, far_chunk)));
+        assert!(staged.is_file());
+
+        assert!(!world.map.get_tile(far).terrain.is_passable());
+        assert!(world.map.changed.contains(&far_chunk));
+        assert!(!staged.exists());
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+    }
+
+    #[test]
+    fn test_memory_is_unloaded_with_chunk() {
+        let path = std::env::temp_dir().join("midia_test_chunks_memory.save");
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+        let mut world = prepare_world();
+        world.map.attach_storage(ChunkStorage::open(&path));
+        let far = Point::new(1000, 0);
+        let (far_chunk, _) = world.map.chunk_pos(far);
+        world.map.get_tile(far);
+        world.map.remember(far);
+
+        world.map.unload_distant_chunks(Point::new(0, 0));
+        assert!(!world.map.memory.contains_key(&far_chunk));
+        assert!(world.map.remembered(far).is_none());
+        let staged = chunks_path(&path)
+            .join(STAGING)
+            .join(key_to_file_name((Part::Memory, far_chunk)));
+        assert!(staged.is_file());
+
+        world.map.get_tile(far);
+        assert!(world.map.remembered(far).is_some());
+        assert!(!staged.exists());
+
+        world.map.unload_distant_chunks(Point::new(0, 0));
+        world.map.storage.as_mut().unwrap().commit(&path).unwrap();
+        let mut storage = ChunkStorage::open(&path);
+        assert!(storage.take(far_chunk).is_none());
+        assert!(storage.take_memory(far_chunk).is_some());
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+    }
+
+    #[test]
+    fn test_chunks_are_committed_only_on_save() {
+        let path = std::env::temp_dir().join("midia_test_chunks_commit.save");
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+        let mut world = prepare_world();
+        world.map.attach_storage(ChunkStorage::open(&path));
+        let far = Point::new(1000, 0);
+        let (far_chunk, _) = world.map.chunk_pos(far);
+        let saved = chunks_path(&path).join(key_to_file_name((Part::Chunk, far_chunk)));
+        world.map.get_tile_mut(far).terrain = boulder();
+        world.map.unload_distant_chunks(Point::new(0, 0));
+        assert!(!saved.exists());
+        world.map.storage.as_mut().unwrap().commit(&path).unwrap();
+        assert!(saved.is_file());
+
+        // quitting without saving
+        world.map.get_tile_mut(far).terrain = boulder();
+        world.map.unload_distant_chunks(Point::new(0, 0));
+        let mut storage = ChunkStorage::open(&path);
+        assert!(!chunks_path(&path).join(STAGING).exists());
+
+        assert!(storage.take(far_chunk).is_some());
+        // it's in the savefile now
+        storage.commit(&path).unwrap();
+        assert!(!saved.exists());
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+    }
+
+    #[test]
+    fn test_changed_chunks_stay_without_storage() {
+        let mut world = prepare_world();
+        let far = Point::new(1000, 0);
+        let (far_chunk, _) = world.map.chunk_pos(far);
+        world.map.get_tile_mut(far).terrain = boulder();
+        let (untouched, _) = world.map.chunk_pos(Point::new(1000, 1000));
+        world.map.get_tile(Point::new(1000, 1000));
+
+        world.map.unload_distant_chunks(Point::new(0, 0));
+        assert!(world.map.chunks.contains_key(&far_chunk));
+        assert!(!world.map.chunks.contains_key(&untouched));
+    }
+}
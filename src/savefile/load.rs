@@ -1,12 +1,16 @@
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Lines};
 use std::path::Path;
 
-use crate::game::{map::Chunk, Avatar, World};
+use crate::game::{
+    map::{Chunk, ChunkMemory},
+    Avatar, World,
+};
 
-use super::{Meta, SAVEFILES_FOLDER};
+use super::{ChunkStorage, Meta, SAVEFILES_FOLDER};
 
+#[must_use]
 pub fn savefiles_exists() -> bool {
     let path = Path::new(SAVEFILES_FOLDER);
     path.read_dir().is_ok_and(|mut read_dir| {
@@ -19,6 +23,10 @@ pub fn savefiles_exists() -> bool {
     })
 }
 
+/// # Panics
+///
+/// Panics if the savefiles folder can't be read
+#[must_use]
 pub fn savefiles() -> Vec<Meta> {
     let path = Path::new(SAVEFILES_FOLDER);
     let mut files = Vec::new();
@@ -41,6 +49,8 @@ pub fn savefiles() -> Vec<Meta> {
 pub enum WorldLoadError {
     SystemError(String),
     DeserializeError(String),
+    /// Savefile of an older format, with the number of it
+    UnsupportedFormat(u32),
 }
 
 impl From<serde_json::Error> for WorldLoadError {
@@ -55,6 +65,7 @@ impl From<std::io::Error> for WorldLoadError {
     }
 }
 
+#[must_use]
 pub fn load(path: &Path) -> Option<Meta> {
     let file = File::open(path).ok()?;
     let mut lines = BufReader::new(&file).lines();
@@ -64,6 +75,7 @@ pub fn load(path: &Path) -> Option<Meta> {
         .map(|s: Meta| s.with_path(path))
 }
 
+#[must_use]
 pub fn has_avatar(path: &Path) -> bool {
     if let Ok(file) = File::open(path) {
         BufReader::new(&file).lines().nth(2).is_some()
@@ -72,46 +84,167 @@ pub fn has_avatar(path: &Path) -> bool {
     }
 }
 
-pub fn load_world(path: &Path) -> Result<World, WorldLoadError> {
-    let file = File::open(path)?;
-    let mut lines = BufReader::new(&file).lines();
-    let meta = lines.next().unwrap()?;
-    let game_view = lines.next().unwrap()?;
-    let log = lines.next().unwrap()?;
-    let mut units_data = Vec::new();
+fn next_line(lines: &mut Lines<BufReader<&File>>) -> Result<String, WorldLoadError> {
+    lines
+        .next()
+        .ok_or_else(|| WorldLoadError::DeserializeError("Savefile is truncated".to_string()))?
+        .map_err(Into::into)
+}
+
+/// Reads lines until the closing one, like `/units`
+fn section(lines: &mut Lines<BufReader<&File>>, end: &str) -> Result<Vec<String>, WorldLoadError> {
+    let mut data = Vec::new();
     loop {
-        let unit = lines.next().unwrap()?;
-        if unit.eq("/units") {
-            break;
+        let line = next_line(lines)?;
+        if line == end {
+            return Ok(data);
         }
-        units_data.push(unit);
+        data.push(line);
     }
-    let mut chunks_data = Vec::new();
-    loop {
-        let chunk = lines.next().unwrap()?;
-        if chunk.eq("/chunks") {
-            break;
-        }
-        chunks_data.push(chunk);
+}
+
+/// # Errors
+///
+/// Returns an error if the savefile can't be read or parsed, or it's of an older format
+pub fn load_world(path: &Path) -> Result<World, WorldLoadError> {
+    let file = File::open(path)?;
+    let mut lines = BufReader::new(&file).lines();
+    let meta: Meta = serde_json::from_str(next_line(&mut lines)?.as_str())?;
+    if !meta.is_supported() {
+        return Err(WorldLoadError::UnsupportedFormat(meta.format));
     }
+    let game_view = next_line(&mut lines)?;
+    let log = next_line(&mut lines)?;
+    let rng = next_line(&mut lines)?;
+    let weather = next_line(&mut lines)?;
+    let detection = next_line(&mut lines)?;
+    let population = next_line(&mut lines)?;
+    let units_data = section(&mut lines, "/units")?;
+    let chunks_data = section(&mut lines, "/chunks")?;
+    let memory_data = section(&mut lines, "/memory")?;
 
     let mut units = HashMap::with_capacity(units_data.len());
     for unit in units_data {
-        let unit: Box<dyn Avatar> = serde_json::from_str(&unit).unwrap();
+        let unit: Box<dyn Avatar> = serde_json::from_str(&unit)?;
         units.insert(unit.id(), unit);
     }
 
     let mut chunks = HashMap::with_capacity(chunks_data.len());
     for chunk in chunks_data {
-        let chunk: Chunk = serde_json::from_str(&chunk).unwrap();
+        let chunk: Chunk = serde_json::from_str(&chunk)?;
         chunks.insert(chunk.pos, chunk);
     }
 
-    Ok(World::new(
-        serde_json::from_str(meta.as_str()).map(|s: Meta| s.with_path(path))?,
+    let mut memory = HashMap::with_capacity(memory_data.len());
+    for chunk_memory in memory_data {
+        let chunk_memory: ChunkMemory = serde_json::from_str(&chunk_memory)?;
+        memory.insert(chunk_memory.pos, chunk_memory);
+    }
+
+    let mut world = World::new(
+        meta.with_path(path),
         serde_json::from_str(game_view.as_str())?,
         serde_json::from_str(log.as_str())?,
+        serde_json::from_str(rng.as_str())?,
+        serde_json::from_str(weather.as_str())?,
+        serde_json::from_str(detection.as_str())?,
+        serde_json::from_str(population.as_str())?,
         units,
         chunks,
-    ))
+        memory,
+    );
+    world.map.attach_storage(ChunkStorage::open(path));
+
+    Ok(world)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use roguemetry::Point;
+
+    use crate::game::world::tests::{add_dummy, prepare_world};
+    use crate::game::{Avatar, World};
+
+    use super::super::{chunks_path, save};
+    use super::{load_world, WorldLoadError};
+
+    fn test_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("midia_test_{name}.save"))
+    }
+
+    /// Saves the world and loads it back
+    fn reload(mut world: World, name: &str) -> World {
+        let path = test_path(name);
+        world.meta.path.clone_from(&path);
+        save(&mut world).unwrap();
+        let loaded = load_world(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        std::fs::remove_dir_all(chunks_path(&path)).ok();
+        loaded
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut world = prepare_world();
+        world.meta.current_tick = 42;
+        let world = reload(world, "save_and_load");
+        assert_eq!(world.meta.current_tick, 42);
+        assert_eq!(world.player().pos(), Point::new(0, 0));
+    }
+
+    #[test]
+    fn test_detection_is_saved() {
+        let mut world = prepare_world();
+        let monster = add_dummy(&mut world, Point::new(1, 0));
+        // the monster is sneaking up on the player
+        world.detection.lose_sight(0, monster);
+        world.detection.next_roll = 100;
+        let world = reload(world, "detection");
+        assert!(world.detection.has_noticed(monster, 0));
+        assert!(!world.detection.has_noticed(0, monster));
+        assert_eq!(world.detection.next_roll, 100);
+    }
+
+    #[test]
+    fn test_population_is_saved() {
+        let mut world = prepare_world();
+        world.population.cap = 5;
+        world.population.next_migration = 1000;
+        let world = reload(world, "population");
+        assert_eq!(world.population.cap, 5);
+        assert_eq!(world.population.next_migration, 1000);
+    }
+
+    #[test]
+    fn test_old_format_is_refused() {
+        let path = test_path("old_format");
+        std::fs::write(
+            &path,
+            r#"{"name":"old","seed":1,"version":"0.1.0","time":{"secs_since_epoch":0,"nanos_since_epoch":0},"current_tick":0}
+{}
+[]
+/units"#,
+        )
+        .unwrap();
+        let result = load_world(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(result, Err(WorldLoadError::UnsupportedFormat(0))));
+    }
+
+    #[test]
+    fn test_truncated_savefile_is_an_error() {
+        let path = test_path("truncated");
+        let mut world = prepare_world();
+        world.meta.path.clone_from(&path);
+        save(&mut world).unwrap();
+        let data = std::fs::read_to_string(&path).unwrap();
+        let truncated: Vec<&str> = data.lines().take(6).collect();
+        std::fs::write(&path, truncated.join("\n")).unwrap();
+
+        let result = load_world(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(result, Err(WorldLoadError::DeserializeError(_))));
+    }
 }
@@ -4,6 +4,9 @@ use std::time::SystemTime;
 
 use crate::VERSION;
 
+/// Bumped on every change of the savefile layout, older savefiles can't be loaded
+pub const SAVE_FORMAT: u32 = 1;
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct Meta {
     #[serde(skip)]
@@ -11,6 +14,9 @@ pub struct Meta {
     pub name: String,
     pub seed: u64,
     pub version: String,
+    /// Savefiles from before the format was tracked have zero here
+    #[serde(default)]
+    pub format: u32,
     pub time: SystemTime,
     pub current_tick: u128,
 }
@@ -21,17 +27,24 @@ impl Meta {
             path: PathBuf::default(),
             name: name.into(),
             version: VERSION.to_string(),
+            format: SAVE_FORMAT,
             time: SystemTime::now(),
             current_tick: 0,
             seed,
         }
     }
 
+    #[must_use]
     pub fn with_path(mut self, path: &Path) -> Self {
         self.path = path.into();
         self
     }
 
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        self.format == SAVE_FORMAT
+    }
+
     pub fn update_before_save(&mut self) {
         self.time = SystemTime::now();
         self.version = VERSION.to_string();
@@ -1,13 +1,17 @@
 use std::path::Path;
 
+pub use chunks::{chunks_path, ChunkStorage};
 pub use game_view::GameView;
-pub use load::{has_avatar, load, load_world, savefiles, savefiles_exists};
-pub use meta::Meta;
+pub use load::{has_avatar, load, load_world, savefiles, savefiles_exists, WorldLoadError};
+pub use meta::{Meta, SAVE_FORMAT};
+pub use replay::{create_replay, record_action, replay_path, Replay, ReplayAction};
 pub use save::{create, save, Error as SaveError};
 
+mod chunks;
 mod game_view;
 mod load;
 mod meta;
+mod replay;
 mod save;
 
 const SAVEFILES_FOLDER: &str = "save";
@@ -16,4 +20,12 @@ pub fn delete(path: &Path) {
     if path.exists() {
         std::fs::remove_file(path).ok();
     }
+    let replay = replay_path(path);
+    if replay.exists() {
+        std::fs::remove_file(replay).ok();
+    }
+    let chunks = chunks_path(path);
+    if chunks.exists() {
+        std::fs::remove_dir_all(chunks).ok();
+    }
 }
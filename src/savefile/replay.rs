@@ -0,0 +1,230 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::game::{units::Player, Action, ActionType, Avatar, World};
+
+use super::{load::WorldLoadError, save::Error, Meta};
+
+/// Player action with the tick it was issued at
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct ReplayAction {
+    pub tick: u128,
+    pub owner: usize,
+    pub typ: ActionType,
+}
+
+#[must_use]
+pub fn replay_path(save_path: &Path) -> PathBuf {
+    save_path.with_extension("replay")
+}
+
+/// Starts a replay for a just created world, first line is the starting avatar
+///
+/// # Errors
+///
+/// Returns an error if the replay file can't be written
+pub fn create_replay(meta: &Meta, avatar: &Player) -> Result<(), Error> {
+    let mut file = File::create(replay_path(&meta.path)).map_err(Error::from)?;
+    file.write_all(
+        serde_json::to_string(avatar)
+            .map_err(Error::from)?
+            .as_bytes(),
+    )
+    .map_err(Into::into)
+}
+
+/// Appends action to the replay, worlds created without a replay are not recorded
+///
+/// # Errors
+///
+/// Returns an error if the replay file can't be written
+pub fn record_action(meta: &Meta, action: &Action) -> Result<(), Error> {
+    let mut file = match OpenOptions::new()
+        .append(true)
+        .open(replay_path(&meta.path))
+    {
+        Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    let record = ReplayAction {
+        tick: meta.current_tick,
+        owner: action.owner,
+        typ: action.typ.clone(),
+    };
+    file.write_all(format!("\n{}", serde_json::to_string(&record).map_err(Error::from)?).as_bytes())
+        .map_err(Into::into)
+}
+
+/// Fresh world with recorded actions to re-feed into it
+pub struct Replay {
+    pub world: World,
+    actions: VecDeque<ReplayAction>,
+}
+
+impl Replay {
+    /// Creates a new world from the same meta as the recorded one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the replay file can't be read or parsed
+    pub fn load(meta: &Meta) -> Result<Self, WorldLoadError> {
+        let file = File::open(replay_path(&meta.path))?;
+        let mut lines = BufReader::new(&file).lines();
+        let avatar = lines
+            .next()
+            .ok_or_else(|| WorldLoadError::DeserializeError("Replay is empty".to_string()))??;
+        let avatar: Player = serde_json::from_str(avatar.as_str())?;
+        let mut actions = VecDeque::new();
+        for line in lines {
+            actions.push_back(serde_json::from_str(line?.as_str())?);
+        }
+
+        let mut meta = meta.clone();
+        meta.current_tick = 0;
+        Ok(Self {
+            world: World::create(meta, avatar),
+            actions,
+        })
+    }
+
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.actions.is_empty() && self.world.player().action().is_none()
+    }
+
+    /// Plays one player's turn: issues next recorded action and ticks the world until it's done
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason if the recorded action is not possible anymore
+    pub fn step(&mut self) -> Result<(), String> {
+        if self.world.player().action().is_none() {
+            let Some(record) = self.actions.pop_front() else {
+                return Ok(());
+            };
+            if record.tick != self.world.meta.current_tick {
+                return Err(format!(
+                    "Replay is out of sync: action was recorded on tick {} but world is on tick {}",
+                    record.tick, self.world.meta.current_tick
+                ));
+            }
+            let action = Action::new(record.owner, record.typ, &self.world)?;
+            self.world
+                .units
+                .get_unit_mut(record.owner)
+                .set_action(Some(action));
+        }
+
+        while self.world.player().action().is_some() && !self.world.player().char_sheet().is_dead()
+        {
+            self.world.tick();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use roguemetry::{Direction, Point};
+
+    use crate::game::actions::implements::{Read, Skip, Walk};
+    use crate::game::map::items::helpers::book_debug;
+    use crate::game::units::{Player, PlayerPersonality};
+    use crate::game::{Action, ActionType, Avatar, World, WorldRng};
+
+    use super::{create_replay, record_action, replay_path, Meta, Replay};
+
+    fn player() -> Player {
+        let personality = PlayerPersonality::random_playable(&mut WorldRng::seed_from_u64(3));
+        Player::new(personality, Point::new(0, 0))
+    }
+
+    fn play(world: &mut World, actions: Vec<ActionType>) {
+        for typ in actions {
+            let Ok(action) = Action::new(0, typ, world) else {
+                continue;
+            };
+            record_action(&world.meta, &action).unwrap();
+            world.player_mut().set_action(Some(action));
+            while world.player().action().is_some() {
+                world.tick();
+            }
+        }
+    }
+
+    fn put_book(world: &mut World) {
+        let pos = world.player().pos() + Direction::East;
+        world.map.get_tile_mut(pos).items.push(book_debug());
+    }
+
+    #[test]
+    fn test_replay_repeats_the_game() {
+        let path = std::env::temp_dir().join("midia_test_replay.save");
+        let meta = Meta::new("replay test", 42).with_path(&path);
+        create_replay(&meta, &player()).unwrap();
+        let mut world = World::create(meta.clone(), player());
+
+        play(
+            &mut world,
+            vec![
+                Walk::new(Direction::East),
+                Skip::new(10),
+                Walk::new(Direction::South),
+                Walk::new(Direction::South),
+                Skip::new(25),
+            ],
+        );
+
+        let mut replay = Replay::load(&meta).unwrap();
+        while !replay.is_over() {
+            replay.step().unwrap();
+        }
+        std::fs::remove_file(replay_path(&path)).ok();
+
+        assert_eq!(world.meta.current_tick, replay.world.meta.current_tick);
+        assert_eq!(world.player().pos(), replay.world.player().pos());
+        assert_eq!(
+            serde_json::to_string(&world.rng).unwrap(),
+            serde_json::to_string(&replay.world.rng).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_replay_with_failed_reading() {
+        let path = std::env::temp_dir().join("midia_test_replay_reading.save");
+        let meta = Meta::new("replay reading test", 42).with_path(&path);
+        create_replay(&meta, &player()).unwrap();
+        let mut world = World::create(meta.clone(), player());
+        put_book(&mut world);
+
+        // nothing to read at the west, the roll happens only for the book
+        play(
+            &mut world,
+            vec![
+                Read::new(Direction::West),
+                Read::new(Direction::East),
+                Skip::new(10),
+                Read::new(Direction::West),
+                Read::new(Direction::East),
+            ],
+        );
+
+        let mut replay = Replay::load(&meta).unwrap();
+        put_book(&mut replay.world);
+        while !replay.is_over() {
+            replay.step().unwrap();
+        }
+        std::fs::remove_file(replay_path(&path)).ok();
+
+        assert_eq!(world.meta.current_tick, replay.world.meta.current_tick);
+        assert_eq!(
+            serde_json::to_string(&world.rng).unwrap(),
+            serde_json::to_string(&replay.world.rng).unwrap()
+        );
+    }
+}
@@ -32,6 +32,9 @@ fn world_seed(seed: &str) -> u64 {
     hasher.finish()
 }
 
+/// # Errors
+///
+/// Returns an error if the savefile can't be created
 pub fn create(name: &str, seed: &str) -> Result<PathBuf, Error> {
     make_dir()?;
     let name = name.trim().replace('\n', "");
@@ -64,6 +67,30 @@ fn serialize_world(world: &World) -> Result<String, Error> {
             .map_err(Error::from)?
             .as_str(),
     );
+    data.push('\n');
+    data.push_str(
+        serde_json::to_string(&world.rng)
+            .map_err(Error::from)?
+            .as_str(),
+    );
+    data.push('\n');
+    data.push_str(
+        serde_json::to_string(&world.weather)
+            .map_err(Error::from)?
+            .as_str(),
+    );
+    data.push('\n');
+    data.push_str(
+        serde_json::to_string(&world.detection)
+            .map_err(Error::from)?
+            .as_str(),
+    );
+    data.push('\n');
+    data.push_str(
+        serde_json::to_string(&world.population)
+            .map_err(Error::from)?
+            .as_str(),
+    );
     for (_, unit) in world.units.iter() {
         data.push('\n');
         data.push_str(serde_json::to_string(unit).map_err(Error::from)?.as_str());
@@ -75,15 +102,27 @@ fn serialize_world(world: &World) -> Result<String, Error> {
         data.push_str(serde_json::to_string(chunk).map_err(Error::from)?.as_str());
     }
     data.push_str("\n/chunks");
+    for memory in world.map.memory.values() {
+        data.push('\n');
+        data.push_str(serde_json::to_string(memory).map_err(Error::from)?.as_str());
+    }
+    data.push_str("\n/memory");
 
     Ok(data)
 }
 
-pub fn save(world: &World) -> Result<(), Error> {
+/// # Errors
+///
+/// Returns an error if the savefile can't be written
+pub fn save(world: &mut World) -> Result<(), Error> {
     make_dir()?;
     let mut file = File::create(&world.meta.path).map_err(Error::from)?;
     file.write_all(serialize_world(world)?.as_bytes())
-        .map_err(Into::into)
+        .map_err(Error::from)?;
+    if let Some(storage) = &mut world.map.storage {
+        storage.commit(&world.meta.path)?;
+    }
+    Ok(())
 }
 
 fn make_dir() -> Result<(), Error> {
@@ -1,7 +1,7 @@
 use roguemetry::Point;
-use tetra::graphics::Color;
 
 use crate::assets::Sprite;
+use crate::colors::Color;
 use crate::game::traits::LooksLike;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -6,7 +6,7 @@ use crate::scenes::game_modes::Cursor;
 use crate::scenes::{implements::GameScene, Transition};
 
 use super::implements::{
-    Interacting, MeleeAttack, Observing, PikeAttack, Shooting, Throwing, Walking,
+    Crafting, Interacting, MeleeAttack, Observing, PikeAttack, Shooting, Throwing, Walking,
 };
 
 #[enum_dispatch]
@@ -30,4 +30,5 @@ pub enum GameMode {
     Throwing,
     Shooting,
     Interacting,
+    Crafting,
 }
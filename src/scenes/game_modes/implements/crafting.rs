@@ -0,0 +1,142 @@
+use roguemetry::Vec2;
+use tetra::{
+    graphics::{
+        mesh::{Mesh, ShapeStyle},
+        Rectangle,
+    },
+    input::Key,
+    Context,
+};
+
+use super::super::{
+    super::{helpers::window_size, implements::GameScene, Transition},
+    GameModeImpl,
+};
+use crate::{
+    colors::Colors,
+    game::{actions::implements::Craft, Action, GameData, World},
+    input,
+    ui::{Colorize, Draw, JustMesh, Label, Position, Positionable, Vertical},
+};
+
+const LIST_WIDTH: f32 = 300.0;
+const LIST_TOP: f32 = 80.0;
+
+struct CraftingSprites {
+    mesh: JustMesh,
+    labels: Vec<Label>,
+}
+
+/// List of the recipes that can be made right now
+pub struct Crafting {
+    /// Ids of the available recipes and their names
+    recipes: Vec<(String, String)>,
+    selected: usize,
+    sprites: Option<CraftingSprites>,
+}
+
+impl Crafting {
+    pub fn new(world: &World) -> Self {
+        let recipes = GameData::instance()
+            .recipes
+            .iter()
+            .filter(|(id, _)| Action::new(0, Craft::new(id.as_str()), world).is_ok())
+            .map(|(id, recipe)| (id.clone(), recipe.name()))
+            .collect();
+        Self {
+            recipes,
+            selected: 0,
+            sprites: None,
+        }
+    }
+
+    fn create_sprites(&self, ctx: &mut Context, game: &GameScene) -> CraftingSprites {
+        let font = game.assets.fonts.default.clone();
+        let line_height = font.line_height;
+        let height = line_height * (self.recipes.len() + 1) as f32 + 10.0;
+        let mesh = JustMesh::new(
+            Mesh::rectangle(
+                ctx,
+                ShapeStyle::Fill,
+                Rectangle::new(0.0, 0.0, LIST_WIDTH, height),
+            )
+            .unwrap(),
+            Some(Colors::BLACK.with_alpha(0.7)),
+            Vec2::new(LIST_WIDTH, height),
+            Position::horizontal_center(Vertical::TopByTop, Vec2::new(0.0, LIST_TOP)),
+        );
+        let labels = std::iter::once("Craft:".to_string())
+            .chain(self.recipes.iter().map(|(_, name)| name.clone()))
+            .enumerate()
+            .map(|(i, text)| {
+                Label::new(
+                    text,
+                    font.clone(),
+                    Colors::WHITE_SMOKE,
+                    Position::horizontal_center(
+                        Vertical::TopByTop,
+                        Vec2::new(0.0, LIST_TOP + 5.0 + line_height * i as f32),
+                    ),
+                )
+            })
+            .collect();
+
+        CraftingSprites { mesh, labels }
+    }
+
+    fn update_colors(&mut self) {
+        if let Some(sprites) = &mut self.sprites {
+            for (i, label) in sprites.labels.iter_mut().skip(1).enumerate() {
+                label.set_color(if i == self.selected {
+                    Colors::LIME
+                } else {
+                    Colors::WHITE_SMOKE
+                });
+            }
+        }
+    }
+}
+
+impl GameModeImpl for Crafting {
+    fn can_push(&self, _world: &World) -> Result<(), String> {
+        if self.recipes.is_empty() {
+            Err("You have nothing to craft!".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn draw(&mut self, ctx: &mut Context, game: &mut GameScene) {
+        if self.sprites.is_none() {
+            self.sprites = Some(self.create_sprites(ctx, game));
+            self.update_colors();
+        }
+        let window_size = window_size(ctx);
+        if let Some(sprites) = &mut self.sprites {
+            sprites.mesh.update_position(ctx, window_size);
+            sprites.mesh.draw(ctx);
+            for label in &mut sprites.labels {
+                label.update_position(ctx, window_size);
+                label.draw(ctx);
+            }
+        }
+    }
+
+    fn update(&mut self, ctx: &mut Context, game: &mut GameScene) -> Transition {
+        if input::is_key_pressed(ctx, Key::Escape) {
+            game.modes.pop();
+        } else if input::is_some_of_keys_pressed(ctx, &[Key::Up, Key::NumPad8]) {
+            self.selected = self.selected.saturating_sub(1);
+            self.update_colors();
+        } else if input::is_some_of_keys_pressed(ctx, &[Key::Down, Key::NumPad2]) {
+            self.selected = (self.selected + 1).min(self.recipes.len() - 1);
+            self.update_colors();
+        } else if input::is_some_of_keys_pressed(ctx, &[Key::Enter, Key::Space]) {
+            if let Some((recipe, _)) = self.recipes.get(self.selected) {
+                game.try_start_action(Craft::new(recipe.as_str()));
+            }
+            game.modes.pop();
+        }
+        Transition::None
+    }
+}
@@ -3,7 +3,9 @@ use tetra::input::Key;
 use tetra::Context;
 
 use crate::colors::Colors;
-use crate::game::actions::implements::{Close, DropMainHand, Open, Read, WieldFromGround};
+use crate::game::actions::implements::{
+    Butcher, Chop, Climb, Close, Dig, DropMainHand, Eat, Open, Read, WieldFromGround,
+};
 use crate::game::World;
 use crate::input;
 use crate::scenes::game_modes::{Cursor, CursorType};
@@ -65,9 +67,14 @@ impl GameModeImpl for Interacting {
                 let action = match self.command {
                     PlayerCommand::Open => Open { dir }.into(),
                     PlayerCommand::Close => Close { dir }.into(),
-                    PlayerCommand::Read => Read::new(dir, game.world.player()),
+                    PlayerCommand::Read => Read::new(dir),
                     PlayerCommand::Drop => DropMainHand { dir }.into(),
                     PlayerCommand::WieldFromGround => WieldFromGround { dir }.into(),
+                    PlayerCommand::Climb => Climb::new(dir),
+                    PlayerCommand::Dig => Dig::new(dir),
+                    PlayerCommand::Chop => Chop::new(dir),
+                    PlayerCommand::Butcher => Butcher::new(dir),
+                    PlayerCommand::Eat => Eat::new(dir),
                     PlayerCommand::Examine => unreachable!(),
                 };
                 game.try_start_action(action);
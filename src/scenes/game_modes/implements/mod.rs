@@ -1,3 +1,4 @@
+pub use crafting::Crafting;
 pub use interacting::Interacting;
 pub use melee_attack::MeleeAttack;
 pub use observing::Observing;
@@ -6,6 +7,7 @@ pub use shooting::Shooting;
 pub use throwing::Throwing;
 pub use walking::Walking;
 
+mod crafting;
 mod interacting;
 mod melee_attack;
 mod observing;
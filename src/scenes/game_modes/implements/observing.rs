@@ -18,11 +18,7 @@ use super::super::{
 use crate::ui::HasLayout;
 use crate::{
     colors::Colors,
-    game::{
-        races::{BugColorDistribution, Pronouns, Sex},
-        units::{Appearance, Monster},
-        CharSheet, Race, World, AI,
-    },
+    game::{races::BugColorDistribution, units::Monster, World},
     input,
     settings::Settings,
     ui::{Draw, JustMesh, Label, Position, Positionable, Stringify},
@@ -88,6 +84,8 @@ impl Observing {
         let pos = game.world.player().pos + game.shift_of_view() + self.mouse_moved_pos;
         let msg = if game.world.is_visible(pos) {
             game.world.this_is(pos, true)
+        } else if let Some(remembered) = game.world.map.remembered(pos) {
+            remembered.description()
         } else {
             "???".to_string()
         };
@@ -180,20 +178,12 @@ impl GameModeImpl for Observing {
             && Settings::instance().debug.god_mode
         {
             let pos = game.world.player().pos + game.shift_of_view() + self.mouse_moved_pos;
-            let color = rand::rng().sample(BugColorDistribution {});
-            game.world.add_unit(Box::new(Monster::new(
-                AI::BasicMonster,
-                pos,
-                format!("giant {color} bug").to_lowercase(),
-                Appearance {
-                    race: Race::Bug,
-                    age: 99,
-                    body_color: Some(color),
-                    sex: Sex::Other,
-                },
-                Pronouns::ItIts,
-                CharSheet::default(false, Race::Bug),
-            )));
+            let color = game.world.rng.sample(BugColorDistribution {});
+            game.world.add_unit(Box::new(
+                Monster::from_prototype("giant_bug", pos)
+                    .with_name(format!("giant {color} bug").to_lowercase())
+                    .with_body_color(color),
+            ));
             self.update_sprite(ctx, game);
         }
 
@@ -6,9 +6,9 @@ use tetra::{input::KeyModifier, Context};
 use crate::{
     colors::Colors,
     game::{
-        actions::implements::{DropMainHand, Reload, Skip, Walk, Wear},
+        actions::implements::{ChangeLevel, DropMainHand, Reload, Skip, Sneak, Walk, Wear},
         traits::Name,
-        BodySlot, Fighter, LogEvent,
+        Avatar, BodySlot, Fighter, LogEvent,
     },
     input,
     settings::{KeyBindingAction, Settings},
@@ -16,7 +16,7 @@ use crate::{
 
 use super::super::{
     super::{implements::GameScene, SceneKind, Transition},
-    implements::{Interacting, MeleeAttack, Observing, PikeAttack, Shooting, Throwing},
+    implements::{Crafting, Interacting, MeleeAttack, Observing, PikeAttack, Shooting, Throwing},
     GameModeImpl, PlayerCommand,
 };
 
@@ -126,6 +126,34 @@ impl GameModeImpl for Walking {
                     KeyBindingAction::Skip => {
                         game.try_start_action(Skip::one());
                     }
+                    KeyBindingAction::Sneak => {
+                        let sneaking = game.world.player().char_sheet().sneaking;
+                        game.try_start_action(Sneak::new(!sneaking));
+                    }
+                    KeyBindingAction::GoDown => {
+                        game.try_start_action(ChangeLevel::new(true));
+                    }
+                    KeyBindingAction::GoUp => {
+                        game.try_start_action(ChangeLevel::new(false));
+                    }
+                    KeyBindingAction::Climb => {
+                        game.push_mode(Interacting::new(PlayerCommand::Climb).into());
+                    }
+                    KeyBindingAction::Dig => {
+                        game.push_mode(Interacting::new(PlayerCommand::Dig).into());
+                    }
+                    KeyBindingAction::Chop => {
+                        game.push_mode(Interacting::new(PlayerCommand::Chop).into());
+                    }
+                    KeyBindingAction::Butcher => {
+                        game.push_mode(Interacting::new(PlayerCommand::Butcher).into());
+                    }
+                    KeyBindingAction::Craft => {
+                        game.push_mode(Crafting::new(&game.world).into());
+                    }
+                    KeyBindingAction::Eat => {
+                        game.push_mode(Interacting::new(PlayerCommand::Eat).into());
+                    }
                 }
             }
         }
@@ -1,4 +1,4 @@
-use crate::game::{TerrainInteract, TerrainInteractAction, Tile, World};
+use crate::game::{ItemQuality, TerrainInteract, TerrainInteractAction, Tile, World};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlayerCommand {
@@ -8,6 +8,11 @@ pub enum PlayerCommand {
     Drop,
     WieldFromGround,
     Examine,
+    Climb,
+    Dig,
+    Chop,
+    Butcher,
+    Eat,
 }
 
 impl PlayerCommand {
@@ -15,6 +20,11 @@ impl PlayerCommand {
         match self {
             Self::Examine => false,
             Self::WieldFromGround => !tile.items.is_empty(),
+            Self::Butcher => tile.items.iter().any(|item| item.corpse_of().is_some()),
+            Self::Eat => {
+                tile.items.iter().any(|item| item.edible().is_some())
+                    || tile.terrain.supports_action(TerrainInteractAction::Drink)
+            }
             _ => tile.terrain.supports_action(self.into()),
         }
     }
@@ -27,6 +37,15 @@ impl PlayerCommand {
             Self::WieldFromGround => {
                 return world.player().inventory.can_wield_any();
             }
+            Self::Dig if world.player().inventory.tool(&ItemQuality::Dig).is_none() => {
+                return Err("You have nothing to dig with!".to_string());
+            }
+            Self::Chop if world.player().inventory.tool(&ItemQuality::Chop).is_none() => {
+                return Err("You have nothing to chop with!".to_string());
+            }
+            Self::Butcher if world.player().inventory.tool(&ItemQuality::Butch).is_none() => {
+                return Err("You have nothing to butcher with!".to_string());
+            }
             _ => {}
         }
 
@@ -43,6 +62,11 @@ impl From<PlayerCommand> for TerrainInteractAction {
             PlayerCommand::Drop => TerrainInteractAction::Drop,
             PlayerCommand::Examine => TerrainInteractAction::Examine,
             PlayerCommand::WieldFromGround => TerrainInteractAction::WieldFromGround,
+            PlayerCommand::Climb => TerrainInteractAction::Climb,
+            PlayerCommand::Dig => TerrainInteractAction::Dig,
+            PlayerCommand::Chop => TerrainInteractAction::Chop,
+            PlayerCommand::Butcher => TerrainInteractAction::Butcher,
+            PlayerCommand::Eat => TerrainInteractAction::Drink,
         }
     }
 }
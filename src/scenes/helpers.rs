@@ -1,6 +1,5 @@
 use roguemetry::Vec2;
 use tetra::{
-    graphics::Color,
     input::{Key, KeyModifier, MouseButton},
     Context, Event,
 };
@@ -8,7 +7,7 @@ use tetra::{
 use crate::ui::{ButtonBuilder, UiSprite};
 use crate::{
     assets::{Assets, Sprite},
-    colors::Colors,
+    colors::{Color, Colors},
     ui::{Button, HasLayout, HasSize, Image, Label, Position, TextInput, Vertical},
 };
 
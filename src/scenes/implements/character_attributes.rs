@@ -12,9 +12,10 @@ use crate::{
     assets::Assets,
     colors::Colors,
     game::{
+        log::{LogCategory, LogEvent},
         traits::Name,
         units::{Player, PlayerPersonality},
-        AttrLevel, Attribute, CharSheet, Skill, SkillLevel, World,
+        AttrLevel, Attribute, Avatar, CharSheet, Skill, SkillLevel, World,
     },
     savefile::{self, Meta},
     scenes::{
@@ -541,7 +542,15 @@ impl CharacterAttributes {
         // TODO: find available starting pos in the world
         let mut avatar = Player::new(self.personality.clone(), Point::new(0, 0));
         avatar.inventory.wield.wield(Item::new(STONE_SPEAR));
+        let replay = savefile::create_replay(&self.meta, &avatar);
         let mut world = World::create(self.meta.clone(), avatar);
+        if let Err(e) = replay {
+            world.log.push(LogEvent::new(
+                format!("Can't record replay: {e:?}"),
+                world.player().pos(),
+                LogCategory::Warning,
+            ));
+        }
         world.save();
 
         Transition::Push(SceneKind::Game(self.meta.path.clone()))
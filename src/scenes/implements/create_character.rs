@@ -402,7 +402,7 @@ impl CreateCharacter {
 
         Transition::Push(SceneKind::CharacterAttributes(
             self.meta.path.clone(),
-            PlayerPersonality::new(
+            Box::new(PlayerPersonality::new(
                 Appearance {
                     body_color: self.body_color,
                     sex: Sex::from(&gender),
@@ -411,7 +411,7 @@ impl CreateCharacter {
                 },
                 Mind { name, gender },
                 CharSheet::default(true, race),
-            ),
+            )),
         ))
     }
 
@@ -20,9 +20,9 @@ use crate::{
     game::{
         log::LogCategory,
         traits::{LooksLike, Name},
-        Action, ActionType, Avatar, Item, World,
+        Action, ActionType, Avatar, EventSubscriber, GameEvent, Item, World,
     },
-    input,
+    input, savefile,
     settings::Settings,
     ui::{
         draw_sprites, Colorize, GameLog, Horizontal, Label, Position, TilesetSprite,
@@ -30,6 +30,21 @@ use crate::{
     },
 };
 
+/// Reasons why the player's actions were cancelled, shown in the log after the tick
+#[derive(Default)]
+struct CancelledActions(Vec<String>);
+
+impl EventSubscriber for CancelledActions {
+    fn on_event(&mut self, event: &GameEvent) {
+        if let GameEvent::ActionCancelled {
+            unit_id: 0, reason, ..
+        } = event
+        {
+            self.0.push(reason.clone());
+        }
+    }
+}
+
 pub struct GameScene {
     // TODO: Use struct instead of array for better readability
     sprites: [Box<dyn UiSprite>; 5],
@@ -40,12 +55,14 @@ pub struct GameScene {
     pub assets: Rc<Assets>,
     need_redraw: bool,
     map_canvas: Option<Canvas>,
+    cancelled_actions: Rc<RefCell<CancelledActions>>,
 }
 
 impl GameScene {
     // TODO: refactor this method
     #[allow(clippy::too_many_lines)]
-    pub fn new(app: &App, world: World) -> Self {
+    pub fn new(app: &App, mut world: World) -> Self {
+        world.god_mode = Settings::instance().debug.god_mode;
         let player = world.player();
         let name_label = Box::new(Label::new(
             player.personality.mind.name.as_str(),
@@ -53,9 +70,8 @@ impl GameScene {
             Colors::WHITE_SMOKE,
             Position::by_left_top(Vec2::new(55.0, 8.0)),
         ));
-        // TODO: custom calendar
         let current_time_label = Box::new(Label::new(
-            format!("{}", world.meta.current_tick),
+            world.time_and_weather(),
             app.assets.fonts.default.clone(),
             Colors::WHITE_SMOKE,
             Position::horizontal_center(Vertical::TopByTop, Vec2::new(0.0, 5.0)),
@@ -93,6 +109,9 @@ impl GameScene {
             ),
         ));
 
+        let cancelled_actions = Rc::new(RefCell::new(CancelledActions::default()));
+        world.events.subscribe(cancelled_actions.clone());
+
         Self {
             sprites: [
                 name_label,
@@ -108,6 +127,7 @@ impl GameScene {
             world,
             need_redraw: true,
             map_canvas: None,
+            cancelled_actions,
         }
     }
 
@@ -147,6 +167,10 @@ impl GameScene {
         let action = Action::new(0, typ, &self.world);
         match action {
             Ok(action) => {
+                if let Err(e) = savefile::record_action(&self.world.meta, &action) {
+                    self.log
+                        .log(format!("Can't record replay: {e:?}"), Colors::LIGHT_CORAL);
+                }
                 self.world.player_mut().set_action(Some(action));
                 self.need_redraw = true;
             }
@@ -203,9 +227,13 @@ impl GameScene {
             }
             self.log.log(event.msg.as_str(), event.category.into());
         }
+        let cancelled = std::mem::take(&mut self.cancelled_actions.borrow_mut().0);
+        for reason in cancelled {
+            self.cancel_action_msg(reason);
+        }
 
         let window_size = window_size(ctx);
-        let current_time = format!("{}", self.world.meta.current_tick);
+        let current_time = self.world.time_and_weather();
         self.current_time_label()
             .update(current_time, ctx, window_size);
 
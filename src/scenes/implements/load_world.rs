@@ -90,7 +90,7 @@ impl LoadWorld {
         } else {
             Colors::TRANSPARENT
         };
-        let version_color = if savefile.version.as_str() == VERSION {
+        let version_color = if savefile.version.as_str() == VERSION && savefile.is_supported() {
             Colors::GREEN
         } else {
             Colors::RED
@@ -223,14 +223,17 @@ impl Scene for LoadWorld {
 }
 
 fn load_savefile(path: &Path) -> Transition {
-    if savefile::load(path).is_some() {
-        if savefile::has_avatar(path) {
-            Transition::Push(SceneKind::Game(path.to_path_buf()))
-        } else {
-            Transition::Switch(SceneKind::CreateCharacter(path.to_path_buf()))
+    match savefile::load(path) {
+        // savefiles of older formats are only shown in red to be deleted
+        Some(meta) if !meta.is_supported() => Transition::None,
+        Some(_) => {
+            if savefile::has_avatar(path) {
+                Transition::Push(SceneKind::Game(path.to_path_buf()))
+            } else {
+                Transition::Switch(SceneKind::CreateCharacter(path.to_path_buf()))
+            }
         }
-    } else {
-        panic!("Can't load savefile: {}", path.display());
+        None => panic!("Can't load savefile: {}", path.display()),
     }
 }
 
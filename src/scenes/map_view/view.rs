@@ -9,10 +9,14 @@ use crate::{
     colors::Colors,
     game::{map::TerrainView, traits::LooksLike, Avatar, Tile, World},
     scenes::game_modes::Cursor,
+    ui::TetraColor,
 };
 
 // TODO: refactor this shit
 
+/// Shadow on the visible tiles in the pitch darkness, so they are still readable
+const MAX_SHADOW: f32 = 0.6;
+
 #[allow(clippy::too_many_lines)]
 pub fn draw(
     ctx: &mut Context,
@@ -28,7 +32,7 @@ pub fn draw(
     )
     .unwrap();
     tetra::graphics::set_canvas(ctx, &canvas);
-    tetra::graphics::clear(ctx, Colors::BLACK);
+    tetra::graphics::clear(ctx, Colors::BLACK.tetra());
 
     let scale = world.game_view.zoom.as_scale();
     let zoom = world.game_view.zoom.as_view();
@@ -57,7 +61,16 @@ pub fn draw(
         let delta = Vec2::from(pos - center_tile);
         let position = center + delta * tile_size;
 
-        let this_tile_size = Tileset::get_size(tile.terrain.looks_like());
+        // unexplored tiles are left black
+        let (terrain, top_item) = if is_visible {
+            (&tile.terrain, tile.top_item())
+        } else if let Some(remembered) = world.map.remembered(pos) {
+            (&remembered.terrain, remembered.top_item.as_ref())
+        } else {
+            continue;
+        };
+
+        let this_tile_size = Tileset::get_size(terrain.looks_like());
         let asset_tile_size = Vec2::new(
             assets.tileset.tile_size as f32,
             assets.tileset.tile_size as f32,
@@ -65,46 +78,45 @@ pub fn draw(
         if this_tile_size == asset_tile_size {
             assets.tileset.draw_sprite(
                 ctx,
-                tile.terrain.looks_like(),
+                terrain.looks_like(),
                 DrawParams::new()
                     .position(position)
                     .scale(scale)
-                    .color(tile.terrain.color().unwrap_or(Colors::WHITE)),
+                    .color(terrain.color().unwrap_or(Colors::WHITE).tetra()),
             );
-            if !world.is_visible(pos) {
-                assets.tileset.draw_sprite(
-                    ctx,
-                    Sprite::Fill,
-                    DrawParams::new()
-                        .position(position)
-                        .scale(scale)
-                        .color(Colors::BLACKED_OUT),
-                );
-            }
         }
-        if is_visible {
-            if let Some(item) = tile.top_item() {
-                let this_tile_size = Tileset::get_size(item.looks_like());
-                let mut correction = -(this_tile_size - asset_tile_size) * zoom;
-                correction.x /= 2.0;
+        if let Some(item) = top_item {
+            let this_tile_size = Tileset::get_size(item.looks_like());
+            let mut correction = -(this_tile_size - asset_tile_size) * zoom;
+            correction.x /= 2.0;
 
+            assets.tileset.draw_sprite(
+                ctx,
+                item.looks_like(),
+                DrawParams::new()
+                    .position(position + correction)
+                    .scale(scale)
+                    .color(item.color().tetra()),
+            );
+            if is_visible && tile.items.len() > 1 {
                 assets.tileset.draw_sprite(
                     ctx,
-                    item.looks_like(),
-                    DrawParams::new()
-                        .position(position + correction)
-                        .scale(scale)
-                        .color(item.color()),
+                    Sprite::Highlight,
+                    DrawParams::new().position(position).scale(scale),
                 );
-                if tile.items.len() > 1 {
-                    assets.tileset.draw_sprite(
-                        ctx,
-                        Sprite::Highlight,
-                        DrawParams::new().position(position).scale(scale),
-                    );
-                }
             }
         }
+        // remembered tiles are dimmed
+        if !is_visible {
+            assets.tileset.draw_sprite(
+                ctx,
+                Sprite::Fill,
+                DrawParams::new()
+                    .position(position)
+                    .scale(scale)
+                    .color(Colors::BLACKED_OUT.tetra()),
+            );
+        }
     }
     for &(pos, tile, is_visible) in &tiles {
         if !is_visible {
@@ -113,6 +125,9 @@ pub fn draw(
         let position = center + Vec2::from(pos - center_tile) * tile_size;
 
         for i in tile.units.iter().copied() {
+            if !world.detection.has_noticed(0, i) {
+                continue;
+            }
             draw_unit(
                 ctx,
                 &assets.tileset,
@@ -132,8 +147,15 @@ pub fn draw(
     }
     for &(pos, tile, is_visible) in &tiles {
         let position = center + Vec2::from(pos - center_tile) * tile_size;
+        let terrain = if is_visible {
+            &tile.terrain
+        } else if let Some(remembered) = world.map.remembered(pos) {
+            &remembered.terrain
+        } else {
+            continue;
+        };
 
-        let this_tile_size = Tileset::get_size(tile.terrain.looks_like());
+        let this_tile_size = Tileset::get_size(terrain.looks_like());
         let asset_tile_size = Vec2::new(
             assets.tileset.tile_size as f32,
             assets.tileset.tile_size as f32,
@@ -142,18 +164,36 @@ pub fn draw(
             let mut correction = -(this_tile_size - asset_tile_size) * zoom;
             correction.x /= 2.0;
             let color = if is_visible {
-                tile.terrain.color().unwrap_or(Colors::WHITE)
+                terrain.color().unwrap_or(Colors::WHITE)
             } else {
                 Colors::DARK_GRAY
             };
 
             assets.tileset.draw_sprite(
                 ctx,
-                tile.terrain.looks_like(),
+                terrain.looks_like(),
                 DrawParams::new()
                     .position(position + correction)
                     .scale(scale)
-                    .color(color),
+                    .color(color.tetra()),
+            );
+        }
+    }
+
+    for &(pos, _, is_visible) in &tiles {
+        if !is_visible {
+            continue;
+        }
+        let shadow = (1.0 - world.light_level(pos)) * MAX_SHADOW;
+        if shadow > 0.0 {
+            let position = center + Vec2::from(pos - center_tile) * tile_size;
+            assets.tileset.draw_sprite(
+                ctx,
+                Sprite::Fill,
+                DrawParams::new()
+                    .position(position)
+                    .scale(scale)
+                    .color(Colors::BLACK.with_alpha(shadow).tetra()),
             );
         }
     }
@@ -188,7 +228,7 @@ pub fn draw_cursors(
             let params = DrawParams::new()
                 .position(position)
                 .scale(scale)
-                .color(color);
+                .color(color.tetra());
             assets.tileset.draw_sprite(ctx, typ.looks_like(), params);
         }
     }
@@ -210,7 +250,7 @@ pub fn draw_unit(
     };
     let mut draw_params = DrawParams::new().position(position).scale(scale);
     if let Some(color) = avatar.view().fg() {
-        draw_params = draw_params.color(color);
+        draw_params = draw_params.color(color.tetra());
     }
     tileset.draw_sprite(ctx, avatar.view().looks_like(), draw_params);
 
@@ -231,7 +271,7 @@ pub fn draw_unit(
             item.looks_like(),
             DrawParams::new()
                 .position(position + Vec2::new(offset_x, offset_y))
-                .color(item.color())
+                .color(item.color().tetra())
                 .scale(scale * Vec2::new(-0.7, 0.7)),
         );
     }
@@ -249,7 +289,7 @@ pub fn draw_unit(
         item.looks_like(),
         DrawParams::new()
             .position(position + Vec2::new(offset_x, offset_y))
-            .color(item.color())
+            .color(item.color().tetra())
             .scale(scale * 0.7),
     );
 }
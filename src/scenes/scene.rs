@@ -20,7 +20,7 @@ pub enum SceneKind {
     CreateWorld,
     LoadWorld,
     CreateCharacter(PathBuf),
-    CharacterAttributes(PathBuf, PlayerPersonality),
+    CharacterAttributes(PathBuf, Box<PlayerPersonality>),
     GameMenu,
     Game(PathBuf),
 }
@@ -36,7 +36,7 @@ impl SceneKind {
             SceneKind::LoadWorld => Box::new(LoadWorld::new(app, ctx)),
             SceneKind::CreateCharacter(path) => Box::new(CreateCharacter::new(&path, app, ctx)),
             SceneKind::CharacterAttributes(path, personality) => {
-                Box::new(CharacterAttributes::new(&path, personality, app, ctx))
+                Box::new(CharacterAttributes::new(&path, *personality, app, ctx))
             }
             SceneKind::GameMenu => Box::new(GameMenu::new(app)),
             SceneKind::Game(path) => Box::new(GameScene::new(
@@ -27,6 +27,15 @@ pub enum KeyBindingAction {
     Throw,
     SwapHands,
     Inventory,
+    Sneak,
+    GoDown,
+    GoUp,
+    Climb,
+    Dig,
+    Chop,
+    Butcher,
+    Craft,
+    Eat,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
@@ -59,6 +68,15 @@ impl Default for InputSettings {
                 (Key::F.into(), KeyBindingAction::RangeAttack),
                 (Key::R.into(), KeyBindingAction::Reload),
                 (Key::Period.into(), KeyBindingAction::Skip),
+                (Key::S.into(), KeyBindingAction::Sneak),
+                (KeyWithMod::shift(Key::Period), KeyBindingAction::GoDown),
+                (KeyWithMod::shift(Key::Comma), KeyBindingAction::GoUp),
+                (KeyWithMod::shift(Key::C), KeyBindingAction::Climb),
+                (KeyWithMod::ctrl(Key::D), KeyBindingAction::Dig),
+                (Key::H.into(), KeyBindingAction::Chop),
+                (Key::B.into(), KeyBindingAction::Butcher),
+                (Key::M.into(), KeyBindingAction::Craft),
+                (KeyWithMod::shift(Key::E), KeyBindingAction::Eat),
             ]
             .into(),
         }
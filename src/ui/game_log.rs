@@ -2,10 +2,12 @@ use std::collections::VecDeque;
 
 use roguemetry::Vec2;
 use tetra::graphics::text::{Font, Text};
-use tetra::graphics::{Color, DrawParams, Rectangle};
+use tetra::graphics::{DrawParams, Rectangle};
 use tetra::Context;
 
-use crate::colors::Colors;
+use crate::colors::{Color, Colors};
+
+use super::TetraColor;
 
 #[derive(Debug)]
 pub struct LogMessageText {
@@ -32,11 +34,15 @@ impl LogMessageText {
                 text_bounds.height.round() as i32,
             ),
         );
-        tetra::graphics::clear(ctx, Colors::BLACK);
+        tetra::graphics::clear(ctx, Colors::BLACK.tetra());
         tetra::graphics::reset_scissor(ctx);
 
-        self.text
-            .draw(ctx, DrawParams::new().position(position).color(self.color));
+        self.text.draw(
+            ctx,
+            DrawParams::new()
+                .position(position)
+                .color(self.color.tetra()),
+        );
     }
 }
 
@@ -4,7 +4,7 @@ use std::rc::Rc;
 
 use roguemetry::{Rect, Vec2};
 use tetra::{
-    graphics::{text::Text, Color, DrawParams},
+    graphics::{text::Text, DrawParams},
     Context,
 };
 
@@ -13,10 +13,10 @@ use super::super::{
     UiSprite, Update,
 };
 use crate::assets::Sprite;
-use crate::ui::UpdateContext;
+use crate::ui::{TetraColor, UpdateContext};
 use crate::{
     assets::{Button as ButtonAsset, PreparedFont, Tileset},
-    colors::Colors,
+    colors::{Color, Colors},
     input::{self, KeyWithMod, MouseButton},
     scenes::Transition,
 };
@@ -176,7 +176,7 @@ impl Draw for Button {
                 } else {
                     Colors::LIGHT_YELLOW
                 };
-                text.draw(ctx, DrawParams::new().position(vec).color(color));
+                text.draw(ctx, DrawParams::new().position(vec).color(color.tetra()));
             }
             ButtonContent::Icon {
                 sprite,
@@ -187,10 +187,10 @@ impl Draw for Button {
                 vec.y -= 1.0;
                 let mut params = DrawParams::new().position(vec).scale(*scale);
                 if let Some(color) = color {
-                    params = params.color(*color);
+                    params = params.color(color.tetra());
                 }
                 if self.state == ButtonState::Disabled {
-                    params = params.color(Colors::GRAY);
+                    params = params.color(Colors::GRAY.tetra());
                 }
                 tileset.draw_sprite(ctx, *sprite, params);
             }
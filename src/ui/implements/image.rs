@@ -4,14 +4,16 @@ use std::rc::Rc;
 
 use roguemetry::{Rect, Vec2};
 use tetra::{
-    graphics::{Color, DrawParams, NineSlice, Rectangle, Texture},
+    graphics::{DrawParams, NineSlice, Rectangle, Texture},
     window, Context,
 };
 
 use crate::assets::{Sprite, Tileset};
+use crate::colors::{Color, Colors};
 
 use super::super::{
-    Colorize, Draw, Focus, HasLayout, HasSize, Layout, Position, Positionable, UiSprite, Update,
+    Colorize, Draw, Focus, HasLayout, HasSize, Layout, Position, Positionable, TetraColor,
+    UiSprite, Update,
 };
 
 pub struct Image {
@@ -74,7 +76,7 @@ impl Draw for Image {
         let params = DrawParams::new()
             .position(Vec2::new(rect.x, rect.y))
             .scale(self.scale)
-            .color(self.color.unwrap_or(Color::WHITE));
+            .color(self.color.unwrap_or(Colors::WHITE).tetra());
         if let Some((nine_slice, width, height)) = &self.nine_slice {
             self.texture
                 .draw_nine_slice(ctx, nine_slice, *width, *height, params);
@@ -140,7 +142,7 @@ impl Positionable for Image {}
 
 impl Colorize for Image {
     fn color(&self) -> Color {
-        self.color.unwrap_or(Color::WHITE)
+        self.color.unwrap_or(Colors::WHITE)
     }
 
     fn set_color<C: Into<Color>>(&mut self, value: C) {
@@ -197,7 +199,7 @@ impl Draw for TilesetSprite {
             .position(Vec2::new(rect.x, rect.y))
             .scale(self.scale);
         if let Some(color) = self.color {
-            params = params.color(color);
+            params = params.color(color.tetra());
         }
         self.tileset.draw_sprite(ctx, self.sprite, params);
     }
@@ -232,7 +234,7 @@ impl Positionable for TilesetSprite {}
 
 impl Colorize for TilesetSprite {
     fn color(&self) -> Color {
-        self.color.unwrap_or(Color::WHITE)
+        self.color.unwrap_or(Colors::WHITE)
     }
 
     fn set_color<C: Into<Color>>(&mut self, value: C) {
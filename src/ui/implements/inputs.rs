@@ -5,7 +5,7 @@ use tetra::{
     graphics::{
         mesh::{BorderRadii, Mesh, ShapeStyle},
         text::Text,
-        Color, DrawParams, Rectangle,
+        DrawParams, Rectangle,
     },
     input::{Key, KeyModifier, MouseButton},
     Context,
@@ -13,11 +13,11 @@ use tetra::{
 
 use super::super::{
     Disable, Draw, Focus, HasLayout, HasSize, Hover, Layout, Position, Positionable, Press,
-    Stringify, UiSprite, Update, UpdateContext,
+    Stringify, TetraColor, UiSprite, Update, UpdateContext,
 };
 use crate::{
     assets::PreparedFont,
-    colors::Colors,
+    colors::{Color, Colors},
     input::{self, KeyWithMod},
     scenes::Transition,
 };
@@ -237,14 +237,14 @@ impl Draw for TextInput {
                 ctx,
                 DrawParams::new()
                     .position(Vec2::new(rect.x, rect.y))
-                    .color(bg_color),
+                    .color(bg_color.tetra()),
             );
         }
         self.border.as_ref().unwrap().draw(
             ctx,
             DrawParams::new()
                 .position(Vec2::new(rect.x, rect.y))
-                .color(self.border_color()),
+                .color(self.border_color().tetra()),
         );
         let text_width = self
             .value
@@ -259,9 +259,10 @@ impl Draw for TextInput {
         };
         // TODO: horizontal scroll if text width is bigger than sprite width
         let color = self.text_color();
-        self.value
-            .text()
-            .draw(ctx, DrawParams::new().position(text_pos).color(color));
+        self.value.text().draw(
+            ctx,
+            DrawParams::new().position(text_pos).color(color.tetra()),
+        );
         if self.blink.state && self.state == InputState::Focused {
             self.cursor.as_ref().unwrap().draw(
                 ctx,
@@ -270,7 +271,7 @@ impl Draw for TextInput {
                         rect.x + text_width + 10.0,
                         rect.y + rect.h / 2.0 - self.geometry.line_height.midpoint(8.0),
                     ))
-                    .color(color),
+                    .color(color.tetra()),
             );
         }
     }
@@ -2,11 +2,12 @@ use std::rc::Rc;
 
 use roguemetry::{Rect, Vec2};
 use tetra::{
-    graphics::{text::Text, Color, DrawParams},
+    graphics::{text::Text, DrawParams},
     Context,
 };
 
 use crate::assets::Sprite;
+use crate::colors::Color;
 use crate::game::traits::{LooksLike, Name};
 use crate::{
     assets::{PreparedFont, Tileset},
@@ -14,8 +15,8 @@ use crate::{
 };
 
 use super::super::{
-    Colorize, Draw, Focus, HasLayout, HasSize, Layout, Position, Positionable, Stringify, UiSprite,
-    Update,
+    Colorize, Draw, Focus, HasLayout, HasSize, Layout, Position, Positionable, Stringify,
+    TetraColor, UiSprite, Update,
 };
 
 pub struct Label {
@@ -67,7 +68,7 @@ impl Draw for Label {
             ctx,
             DrawParams::new()
                 .position(Vec2::new(rect.x, rect.y))
-                .color(self.color),
+                .color(self.color.tetra()),
         );
     }
 
@@ -210,8 +211,12 @@ impl Draw for ItemDisplay {
         } else {
             Vec2::new(rect.x, rect.y)
         };
-        self.text
-            .draw(ctx, DrawParams::new().position(text_pos).color(self.color));
+        self.text.draw(
+            ctx,
+            DrawParams::new()
+                .position(text_pos)
+                .color(self.color.tetra()),
+        );
     }
 
     fn visible(&self) -> bool {
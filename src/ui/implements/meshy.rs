@@ -2,7 +2,7 @@
 
 use roguemetry::{Rect, Vec2};
 use tetra::{
-    graphics::{mesh::Mesh, Color, DrawParams},
+    graphics::{mesh::Mesh, DrawParams},
     input, Context,
 };
 
@@ -10,8 +10,9 @@ use super::super::{
     Colorize, Draw, Focus, HasLayout, HasSize, Hover, Layout, Position, Positionable, UiSprite,
     Update,
 };
+use crate::colors::{Color, Colors};
 use crate::scenes::Transition;
-use crate::ui::UpdateContext;
+use crate::ui::{TetraColor, UpdateContext};
 
 pub struct JustMesh {
     layout: Layout,
@@ -43,7 +44,7 @@ impl Draw for JustMesh {
             DrawParams::new()
                 .position(Vec2::new(rect.x, rect.y))
                 .scale(self.scale)
-                .color(self.color.unwrap_or(Color::WHITE)),
+                .color(self.color.unwrap_or(Colors::WHITE).tetra()),
         );
     }
 
@@ -76,7 +77,7 @@ impl Positionable for JustMesh {}
 
 impl Colorize for JustMesh {
     fn color(&self) -> Color {
-        self.color.unwrap_or(Color::WHITE)
+        self.color.unwrap_or(Colors::WHITE)
     }
 
     fn set_color<C: Into<Color>>(&mut self, value: C) {
@@ -127,15 +128,16 @@ impl HoverableMesh {
 impl Draw for HoverableMesh {
     fn draw(&mut self, ctx: &mut Context) {
         let rect = self.layout.rect();
+        let color = if self.is_hovered {
+            self.bg_color_hover
+        } else {
+            self.bg_color
+        };
         self.mesh.draw(
             ctx,
             DrawParams::new()
                 .position(Vec2::new(rect.x, rect.y))
-                .color(if self.is_hovered {
-                    self.bg_color_hover
-                } else {
-                    self.bg_color
-                }),
+                .color(color.tetra()),
         );
     }
 
@@ -13,7 +13,7 @@ pub use self::layout::Layout;
 pub use self::position::{Horizontal, Position, Vertical};
 pub use self::traits::{
     Colorize, Disable, Draw, Focus, HasLayout, HasSize, Hover, Positionable, Press, Stringify,
-    UiSprite, Update, UpdateContext, UpdateContextState,
+    TetraColor, UiSprite, Update, UpdateContext, UpdateContextState,
 };
 use tetra::Context;
 
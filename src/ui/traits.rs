@@ -1,9 +1,10 @@
 #![allow(dead_code)]
 
 use super::{Alert, Button, JustMesh, Label, Layout, Position, TextInput, TilesetSprite};
+use crate::colors::Color;
 use crate::scenes::Transition;
 use roguemetry::{Point, Rect, Vec2};
-use tetra::graphics::Color;
+use tetra::graphics;
 use tetra::input::MouseButton;
 use tetra::{input, Context};
 
@@ -105,6 +106,17 @@ pub trait Colorize {
     fn set_color<C: Into<Color>>(&mut self, value: C);
 }
 
+/// Game colors don't know anything about tetra, so they are converted right before drawing
+pub trait TetraColor {
+    fn tetra(self) -> graphics::Color;
+}
+
+impl TetraColor for Color {
+    fn tetra(self) -> graphics::Color {
+        graphics::Color::rgba(self.r, self.g, self.b, self.a)
+    }
+}
+
 pub trait Stringify {
     fn value(&self) -> String;
     fn set_value<C: Into<String>>(&mut self, value: C);